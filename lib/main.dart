@@ -2,9 +2,12 @@ import 'package:flutter/foundation.dart';
 import 'package:flutter/material.dart';
 import 'package:flutter_riverpod/flutter_riverpod.dart';
 import 'package:logging/logging.dart';
+import 'package:path_provider/path_provider.dart';
 import 'package:spy_on_your_work/src/app/app.dart';
 import 'package:spy_on_your_work/src/app_info.dart';
+import 'package:spy_on_your_work/src/common/logger.dart';
 import 'package:spy_on_your_work/src/isar/database.dart';
+import 'package:spy_on_your_work/src/rust/api/spy_api.dart' as api;
 import 'package:spy_on_your_work/src/rust/frb_generated.dart';
 import 'package:toastification/toastification.dart';
 import 'package:window_manager/window_manager.dart';
@@ -19,6 +22,16 @@ Future<void> main() async {
       print('${record.level.name}: ${record.time}: ${record.message}');
     }
   });
+  // 事件日志必须在 startSpy 之前打开：只有还没有会话时才按日志重建会话
+  final supportDir = await getApplicationSupportDirectory();
+  try {
+    final recovery = api.initJournal(path: "${supportDir.path}/journal.log");
+    logger.info(
+      "journal recovered: ${recovery.records} records, ${recovery.sessions} sessions",
+    );
+  } catch (e) {
+    logger.severe("打开事件日志失败: $e");
+  }
   IsarDatabase database = IsarDatabase();
   await database.initialDatabase();
 
@@ -18,8 +18,10 @@ class ApplicationNotifier extends Notifier<ApplicationState> {
     // 初始化状态，稍后从数据库加载当天数据
     _initializeFromDatabase();
 
-    // 监听应用信息流
-    api.applicationInfoStream().listen((event) async {
+    // 监听应用信息流，热重启后按 clientId 补发断开期间错过的采样
+    api.applicationInfoStream(clientId: 'application_notifier').listen((
+      event,
+    ) async {
       logger.info(
         "running app: ${event.name}, title: ${event.title}, path: ${event.path}",
       );
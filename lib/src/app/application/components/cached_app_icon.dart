@@ -2,23 +2,32 @@ import 'dart:convert';
 import 'dart:typed_data';
 import 'package:flutter/material.dart';
 import 'package:spy_on_your_work/src/common/logger.dart';
+import 'package:spy_on_your_work/src/rust/api/spy_api.dart' as api;
 
 /// 图标缓存管理器
 class IconCacheManager {
   static final Map<String, Uint8List> _cache = {};
 
-  static Uint8List? getDecodedIcon(String? base64Data) {
-    if (base64Data == null) return null;
+  /// [iconData] 为 rust 返回的图标 id，旧记录中可能仍是 base64 图片
+  static Uint8List? getDecodedIcon(String? iconData, int size) {
+    if (iconData == null) return null;
 
+    final key = '$iconData@$size';
     // 如果缓存中存在，直接返回
-    if (_cache.containsKey(base64Data)) {
-      return _cache[base64Data];
+    if (_cache.containsKey(key)) {
+      return _cache[key];
     }
 
-    // 解码并缓存
+    final icon = api.getIcon(id: iconData, size: size);
+    if (icon != null) {
+      _cache[key] = icon;
+      return icon;
+    }
+
+    // 兼容旧版本保存的 base64 图标
     try {
-      final decoded = base64Decode(base64Data);
-      _cache[base64Data] = decoded;
+      final decoded = base64Decode(iconData);
+      _cache[key] = decoded;
       return decoded;
     } catch (e) {
       logger.warning('Failed to decode icon: $e');
@@ -92,7 +101,11 @@ class _IconContainerState extends State<_IconContainer> {
   }
 
   void _loadIcon() {
-    _cachedImageData = IconCacheManager.getDecodedIcon(widget.iconData);
+    // 按 2 倍尺寸获取，兼顾高分屏
+    _cachedImageData = IconCacheManager.getDecodedIcon(
+      widget.iconData,
+      (widget.size * 2).toInt(),
+    );
   }
 
   @override
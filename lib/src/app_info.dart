@@ -22,5 +22,6 @@ class AppInfo {
     screenshotPath = "${dir.path}/screen";
     Directory(screenshotPath).createSync(recursive: true);
     initSavePath(path: screenshotPath);
+    initIconCachePath(path: "${dir.path}/icons");
   }
 }
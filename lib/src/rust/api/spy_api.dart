@@ -4,11 +4,63 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import '../spy/bus.dart';
+import '../spy/catalog.dart';
+import '../spy/event.dart';
+import '../spy/git.dart';
+import '../spy/heartbeat.dart';
+import '../spy/input.dart';
+import '../spy/issue.dart';
+import '../spy/journal.dart';
+import '../spy/lifecycle.dart';
+import '../spy/media.dart';
+import '../spy/meeting.dart';
 import '../spy/model.dart';
+import '../spy/monitor.dart';
+import '../spy/process.dart';
+import '../spy/resource.dart';
+import '../spy/shell.dart';
+import '../spy/visibility.dart';
+import '../spy/window_state.dart';
+import '../spy/workspace.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-Stream<Application> applicationInfoStream() =>
-    RustLib.instance.api.crateApiSpyApiApplicationInfoStream();
+// These functions are ignored because they are not marked as `pub`: `replay`
+
+/// 前台应用采样流，可以同时有多个监听者。client_id 标识监听者（例如主界面、托盘），
+/// 同一个 client 重新监听时先补发上次断开后（例如热重启期间）错过的采样
+Stream<Application> applicationInfoStream({required String clientId}) =>
+    RustLib.instance.api.crateApiSpyApiApplicationInfoStream(
+      clientId: clientId,
+    );
+
+/// 前台应用采样流，先补发 since（unix 毫秒）及之后的采样，用于 UI 重新连接后补齐
+Stream<Application> applicationInfoStreamSince({PlatformInt64? since}) =>
+    RustLib.instance.api.crateApiSpyApiApplicationInfoStreamSince(since: since);
+
+/// 追踪事件流：焦点切换、标题变化、会话结束、截图、离开和回来、会议、应用启动和退出、错误
+Stream<TrackerEvent> trackerEventStream() =>
+    RustLib.instance.api.crateApiSpyApiTrackerEventStream();
+
+/// 按事件类型和应用过滤的追踪事件流，Dart 端取消监听后自动退订。
+/// after_seq 为上次收到的事件序号，since 为时间（unix 毫秒），先补发之后的事件；都为空时只接收新事件
+Stream<TrackerEvent> subscribeTrackerEvents({
+  required EventFilter filter,
+  BigInt? afterSeq,
+  PlatformInt64? since,
+}) => RustLib.instance.api.crateApiSpyApiSubscribeTrackerEvents(
+  filter: filter,
+  afterSeq: afterSeq,
+  since: since,
+);
+
+/// 打开事件日志，截掉崩溃时写了一半的记录，按日志重建会话并恢复最近事件。
+/// 必须在 start_spy 之前调用：已经有会话时不会重建
+JournalRecovery initJournal({required String path}) =>
+    RustLib.instance.api.crateApiSpyApiInitJournal(path: path);
+
+/// 最后发布的追踪事件序号
+BigInt getEventCursor() => RustLib.instance.api.crateApiSpyApiGetEventCursor();
 
 void startSpy() => RustLib.instance.api.crateApiSpyApiStartSpy();
 
@@ -17,6 +69,22 @@ bool getSpyStatus() => RustLib.instance.api.crateApiSpyApiGetSpyStatus();
 void initSavePath({required String path}) =>
     RustLib.instance.api.crateApiSpyApiInitSavePath(path: path);
 
+/// 设置图标缓存目录，图标按内容哈希保存为 `<id>.png`
+void initIconCachePath({required String path}) =>
+    RustLib.instance.api.crateApiSpyApiInitIconCachePath(path: path);
+
+/// 按图标 id 获取 PNG，size 为 0 时返回原始尺寸
+Uint8List? getIcon({required String id, required int size}) =>
+    RustLib.instance.api.crateApiSpyApiGetIcon(id: id, size: size);
+
+/// 截图范围：前台窗口、窗口所在显示器或所有显示器
+void setCaptureMode({required CaptureMode mode}) =>
+    RustLib.instance.api.crateApiSpyApiSetCaptureMode(mode: mode);
+
+/// 显示器布局（虚拟屏幕坐标）
+List<Monitor> listMonitors() =>
+    RustLib.instance.api.crateApiSpyApiListMonitors();
+
 void initScreenshotApps({required List<String> v}) =>
     RustLib.instance.api.crateApiSpyApiInitScreenshotApps(v: v);
 
@@ -25,3 +93,240 @@ void insertScreenshotApps({required String v}) =>
 
 void removeScreenshotApps({required String v}) =>
     RustLib.instance.api.crateApiSpyApiRemoveScreenshotApps(v: v);
+
+/// 正在运行的应用（每个应用一条），可在应用获得焦点前配置截图、分析等设置
+List<Application> listRunningApplications() =>
+    RustLib.instance.api.crateApiSpyApiListRunningApplications();
+
+/// 所有打开的窗口及其所属应用
+List<WindowInfo> listWindows() =>
+    RustLib.instance.api.crateApiSpyApiListWindows();
+
+/// 已安装的应用及分类建议，用于在应用获得焦点前预先设置分类；
+/// 需要扫描 desktop 文件，不在 UI 线程执行
+Future<List<InstalledApp>> listInstalledApplications() =>
+    RustLib.instance.api.crateApiSpyApiListInstalledApplications();
+
+/// 已安装应用的图标 id，第一次调用时提取，列表滚动到该应用时再获取
+Future<String?> getInstalledAppIcon({required String id}) =>
+    RustLib.instance.api.crateApiSpyApiGetInstalledAppIcon(id: id);
+
+/// 开始跟踪应用的启动和退出，返回使用的方式：`netlink` 或 `poll`
+String startLifecycleWatcher() =>
+    RustLib.instance.api.crateApiSpyApiStartLifecycleWatcher();
+
+void stopLifecycleWatcher() =>
+    RustLib.instance.api.crateApiSpyApiStopLifecycleWatcher();
+
+/// [start, end]（unix 毫秒）内的应用启动和退出事件
+List<AppLifecycleEvent> getLifecycleEvents({
+  required PlatformInt64 start,
+  required PlatformInt64 end,
+}) => RustLib.instance.api.crateApiSpyApiGetLifecycleEvents(
+  start: start,
+  end: end,
+);
+
+/// 统计 [start, end]（unix 毫秒）内各应用的运行时间和前台时间
+List<AppRunningTime> getRunningTime({
+  required PlatformInt64 start,
+  required PlatformInt64 end,
+}) => RustLib.instance.api.crateApiSpyApiGetRunningTime(start: start, end: end);
+
+/// 启动本地 WakaTime 兼容心跳服务，返回实际监听端口（传 0 时由系统分配）。
+/// 编辑器插件需把 api key 设为 api_key，不带正确 api key 或来自网页的请求会被拒绝
+int startHeartbeatServer({required int port, required String apiKey}) =>
+    RustLib.instance.api.crateApiSpyApiStartHeartbeatServer(
+      port: port,
+      apiKey: apiKey,
+    );
+
+void stopHeartbeatServer() =>
+    RustLib.instance.api.crateApiSpyApiStopHeartbeatServer();
+
+/// 统计 [start, end]（unix 毫秒）内按文件、分支、项目归属的编码时间；
+/// 心跳期间前台不是发送心跳的编辑器或终端时，app_name 为空
+List<CodingActivity> getCodingActivity({
+  required PlatformInt64 start,
+  required PlatformInt64 end,
+}) => RustLib.instance.api.crateApiSpyApiGetCodingActivity(
+  start: start,
+  end: end,
+);
+
+/// 启动 shell 钩子事件监听，返回 unix socket 路径（不传时使用默认路径）
+String startShellListener({String? path}) =>
+    RustLib.instance.api.crateApiSpyApiStartShellListener(path: path);
+
+void stopShellListener() =>
+    RustLib.instance.api.crateApiSpyApiStopShellListener();
+
+/// 获取 bash / zsh / fish 的钩子脚本
+String? getShellHookScript({required String shell}) =>
+    RustLib.instance.api.crateApiSpyApiGetShellHookScript(shell: shell);
+
+/// 统计 [start, end]（unix 毫秒）内终端会话按命令、目录拆分的时间
+List<TerminalActivity> getTerminalActivity({
+  required PlatformInt64 start,
+  required PlatformInt64 end,
+}) => RustLib.instance.api.crateApiSpyApiGetTerminalActivity(
+  start: start,
+  end: end,
+);
+
+/// 统计 [start, end]（unix 毫秒）内按 git 仓库和分支归属的前台时间
+List<RepositoryTime> getRepositoryTime({
+  required PlatformInt64 start,
+  required PlatformInt64 end,
+}) => RustLib.instance.api.crateApiSpyApiGetRepositoryTime(
+  start: start,
+  end: end,
+);
+
+/// 设置工单号正则，空字符串恢复默认的 `PROJ-1234` 格式
+void setIssueKeyPattern({required String pattern}) =>
+    RustLib.instance.api.crateApiSpyApiSetIssueKeyPattern(pattern: pattern);
+
+/// 只识别这些 Jira 项目键的工单，空列表表示不限制
+void setIssueProjects({required List<String> projects}) =>
+    RustLib.instance.api.crateApiSpyApiSetIssueProjects(projects: projects);
+
+/// 统计 [start, end]（unix 毫秒）内各工单的时间
+List<IssueTime> getIssueTime({
+  required PlatformInt64 start,
+  required PlatformInt64 end,
+}) => RustLib.instance.api.crateApiSpyApiGetIssueTime(start: start, end: end);
+
+/// 导出 [start, end]（unix 毫秒）内的工作日志，author 为空时使用系统用户名
+String exportWorklogs({
+  required PlatformInt64 start,
+  required PlatformInt64 end,
+  required WorklogFormat format,
+  String? author,
+}) => RustLib.instance.api.crateApiSpyApiExportWorklogs(
+  start: start,
+  end: end,
+  format: format,
+  author: author,
+);
+
+/// 设置需要在后台持续采样资源占用的应用（进程名或可执行文件名）
+void setBackgroundApps({required List<String> apps}) =>
+    RustLib.instance.api.crateApiSpyApiSetBackgroundApps(apps: apps);
+
+/// 统计 [start, end]（unix 毫秒）内各应用在前台和后台时的资源占用
+List<AppResourceUsage> getResourceUsage({
+  required PlatformInt64 start,
+  required PlatformInt64 end,
+}) => RustLib.instance.api.crateApiSpyApiGetResourceUsage(
+  start: start,
+  end: end,
+);
+
+/// 开启后每次采样同时统计可见但不在前台的窗口
+void setVisibleTracking({required bool enabled}) =>
+    RustLib.instance.api.crateApiSpyApiSetVisibleTracking(enabled: enabled);
+
+/// 统计 [start, end]（unix 毫秒）内各应用可见但不在前台的时间
+List<VisibleTime> getVisibleTime({
+  required PlatformInt64 start,
+  required PlatformInt64 end,
+}) => RustLib.instance.api.crateApiSpyApiGetVisibleTime(start: start, end: end);
+
+/// 设置工作区规则：按名称匹配工作区，得到分类和项目
+void setWorkspaceRules({required List<WorkspaceRule> rules}) =>
+    RustLib.instance.api.crateApiSpyApiSetWorkspaceRules(rules: rules);
+
+/// 按工作区统计的前台时间
+List<WorkspaceTime> getWorkspaceTime({
+  required PlatformInt64 start,
+  required PlatformInt64 end,
+}) => RustLib.instance.api.crateApiSpyApiGetWorkspaceTime(
+  start: start,
+  end: end,
+);
+
+/// 按分类统计的前台时间，app_categories 为界面设置的应用分类（应用名称 → 分类），
+/// 工作区规则给出的分类优先
+List<CategoryTime> getCategoryTime({
+  required PlatformInt64 start,
+  required PlatformInt64 end,
+  required Map<String,
+  AppCategory> appCategories,
+}) => RustLib.instance.api.crateApiSpyApiGetCategoryTime(
+  start: start,
+  end: end,
+  appCategories: appCategories,
+);
+
+/// 按应用统计的全屏时间，immersive 标记全屏占比高的应用（游戏、视频）
+List<FullscreenTime> getFullscreenTime({
+  required PlatformInt64 start,
+  required PlatformInt64 end,
+}) => RustLib.instance.api.crateApiSpyApiGetFullscreenTime(
+  start: start,
+  end: end,
+);
+
+/// 开始通过 MPRIS 记录媒体播放（仅 Linux）
+void startMediaWatcher() =>
+    RustLib.instance.api.crateApiSpyApiStartMediaWatcher();
+
+void stopMediaWatcher() =>
+    RustLib.instance.api.crateApiSpyApiStopMediaWatcher();
+
+/// 最近一次读取到的各播放器状态
+List<MediaState> getNowPlaying() =>
+    RustLib.instance.api.crateApiSpyApiGetNowPlaying();
+
+/// [start, end]（unix 毫秒）内的播放区间，与前台会话并行
+List<MediaActivity> getMediaActivity({
+  required PlatformInt64 start,
+  required PlatformInt64 end,
+}) => RustLib.instance.api.crateApiSpyApiGetMediaActivity(
+  start: start,
+  end: end,
+);
+
+/// 按播放器统计的播放时间
+List<MediaTime> getMediaTime({
+  required PlatformInt64 start,
+  required PlatformInt64 end,
+}) => RustLib.instance.api.crateApiSpyApiGetMediaTime(start: start, end: end);
+
+/// 开始检测会议：会议窗口标题、麦克风、摄像头和屏幕共享
+void startMeetingDetector() =>
+    RustLib.instance.api.crateApiSpyApiStartMeetingDetector();
+
+void stopMeetingDetector() =>
+    RustLib.instance.api.crateApiSpyApiStopMeetingDetector();
+
+/// [start, end]（unix 毫秒）内的会议
+List<Meeting> getMeetings({
+  required PlatformInt64 start,
+  required PlatformInt64 end,
+}) => RustLib.instance.api.crateApiSpyApiGetMeetings(start: start, end: end);
+
+/// 开始监控输入活跃度（只计数，不记录按键内容），返回使用的方式
+String startInputMonitor() =>
+    RustLib.instance.api.crateApiSpyApiStartInputMonitor();
+
+void stopInputMonitor() =>
+    RustLib.instance.api.crateApiSpyApiStopInputMonitor();
+
+/// 设置空闲阈值（秒），超过阈值没有输入时不计入前台时间
+void setIdleThreshold({required PlatformInt64 secs}) =>
+    RustLib.instance.api.crateApiSpyApiSetIdleThreshold(secs: secs);
+
+/// 距离最后一次输入的秒数，没有开启输入监控时为 None
+PlatformInt64? getIdleSeconds() =>
+    RustLib.instance.api.crateApiSpyApiGetIdleSeconds();
+
+/// 按应用统计的专注指标：活跃时间、空闲时间、输入次数和活跃程度
+List<FocusMetrics> getFocusMetrics({
+  required PlatformInt64 start,
+  required PlatformInt64 end,
+}) => RustLib.instance.api.crateApiSpyApiGetFocusMetrics(
+  start: start,
+  end: end,
+);
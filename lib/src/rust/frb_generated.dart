@@ -11,7 +11,25 @@ import 'frb_generated.dart';
 import 'frb_generated.io.dart'
     if (dart.library.js_interop) 'frb_generated.web.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'spy/bus.dart';
+import 'spy/catalog.dart';
+import 'spy/event.dart';
+import 'spy/git.dart';
+import 'spy/heartbeat.dart';
+import 'spy/input.dart';
+import 'spy/issue.dart';
+import 'spy/journal.dart';
+import 'spy/lifecycle.dart';
+import 'spy/media.dart';
+import 'spy/meeting.dart';
 import 'spy/model.dart';
+import 'spy/monitor.dart';
+import 'spy/process.dart';
+import 'spy/resource.dart';
+import 'spy/shell.dart';
+import 'spy/visibility.dart';
+import 'spy/window_state.dart';
+import 'spy/workspace.dart';
 
 /// Main entrypoint of the Rust API
 class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
@@ -68,7 +86,7 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
   String get codegenVersion => '2.11.1';
 
   @override
-  int get rustContentHash => -61306825;
+  int get rustContentHash => -539925813;
 
   static const kDefaultExternalLibraryLoaderConfig =
       ExternalLibraryLoaderConfig(
@@ -79,23 +97,186 @@ class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
 }
 
 abstract class RustLibApi extends BaseApi {
-  Stream<Application> crateApiSpyApiApplicationInfoStream();
+  Stream<Application> crateApiSpyApiApplicationInfoStream({
+    required String clientId,
+  });
+
+  Stream<Application> crateApiSpyApiApplicationInfoStreamSince({
+    PlatformInt64? since,
+  });
+
+  String crateApiSpyApiExportWorklogs({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+    required WorklogFormat format,
+    String? author,
+  });
+
+  List<CategoryTime> crateApiSpyApiGetCategoryTime({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+    required Map<String,
+    AppCategory> appCategories,
+  });
+
+  List<CodingActivity> crateApiSpyApiGetCodingActivity({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+  });
+
+  BigInt crateApiSpyApiGetEventCursor();
+
+  List<FocusMetrics> crateApiSpyApiGetFocusMetrics({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+  });
+
+  List<FullscreenTime> crateApiSpyApiGetFullscreenTime({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+  });
+
+  Uint8List? crateApiSpyApiGetIcon({required String id, required int size});
+
+  PlatformInt64? crateApiSpyApiGetIdleSeconds();
+
+  Future<String?> crateApiSpyApiGetInstalledAppIcon({required String id});
+
+  List<IssueTime> crateApiSpyApiGetIssueTime({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+  });
+
+  List<AppLifecycleEvent> crateApiSpyApiGetLifecycleEvents({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+  });
+
+  List<MediaActivity> crateApiSpyApiGetMediaActivity({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+  });
+
+  List<MediaTime> crateApiSpyApiGetMediaTime({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+  });
+
+  List<Meeting> crateApiSpyApiGetMeetings({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+  });
+
+  List<MediaState> crateApiSpyApiGetNowPlaying();
+
+  List<RepositoryTime> crateApiSpyApiGetRepositoryTime({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+  });
+
+  List<AppResourceUsage> crateApiSpyApiGetResourceUsage({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+  });
+
+  List<AppRunningTime> crateApiSpyApiGetRunningTime({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+  });
+
+  String? crateApiSpyApiGetShellHookScript({required String shell});
 
   bool crateApiSpyApiGetSpyStatus();
 
+  List<TerminalActivity> crateApiSpyApiGetTerminalActivity({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+  });
+
+  List<VisibleTime> crateApiSpyApiGetVisibleTime({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+  });
+
+  List<WorkspaceTime> crateApiSpyApiGetWorkspaceTime({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+  });
+
   String crateApiSimpleGreet({required String name});
 
   Future<void> crateApiSimpleInitApp();
 
+  void crateApiSpyApiInitIconCachePath({required String path});
+
+  JournalRecovery crateApiSpyApiInitJournal({required String path});
+
   void crateApiSpyApiInitSavePath({required String path});
 
   void crateApiSpyApiInitScreenshotApps({required List<String> v});
 
   void crateApiSpyApiInsertScreenshotApps({required String v});
 
+  Future<List<InstalledApp>> crateApiSpyApiListInstalledApplications();
+
+  List<Monitor> crateApiSpyApiListMonitors();
+
+  List<Application> crateApiSpyApiListRunningApplications();
+
+  List<WindowInfo> crateApiSpyApiListWindows();
+
   void crateApiSpyApiRemoveScreenshotApps({required String v});
 
+  void crateApiSpyApiSetBackgroundApps({required List<String> apps});
+
+  void crateApiSpyApiSetCaptureMode({required CaptureMode mode});
+
+  void crateApiSpyApiSetIdleThreshold({required PlatformInt64 secs});
+
+  void crateApiSpyApiSetIssueKeyPattern({required String pattern});
+
+  void crateApiSpyApiSetIssueProjects({required List<String> projects});
+
+  void crateApiSpyApiSetVisibleTracking({required bool enabled});
+
+  void crateApiSpyApiSetWorkspaceRules({required List<WorkspaceRule> rules});
+
+  int crateApiSpyApiStartHeartbeatServer({
+    required int port,
+    required String apiKey,
+  });
+
+  String crateApiSpyApiStartInputMonitor();
+
+  String crateApiSpyApiStartLifecycleWatcher();
+
+  void crateApiSpyApiStartMediaWatcher();
+
+  void crateApiSpyApiStartMeetingDetector();
+
+  String crateApiSpyApiStartShellListener({String? path});
+
   void crateApiSpyApiStartSpy();
+
+  void crateApiSpyApiStopHeartbeatServer();
+
+  void crateApiSpyApiStopInputMonitor();
+
+  void crateApiSpyApiStopLifecycleWatcher();
+
+  void crateApiSpyApiStopMediaWatcher();
+
+  void crateApiSpyApiStopMeetingDetector();
+
+  void crateApiSpyApiStopShellListener();
+
+  Stream<TrackerEvent> crateApiSpyApiSubscribeTrackerEvents({
+    required EventFilter filter,
+    BigInt? afterSeq,
+    PlatformInt64? since,
+  });
+
+  Stream<TrackerEvent> crateApiSpyApiTrackerEventStream();
 }
 
 class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
@@ -107,13 +288,16 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   });
 
   @override
-  Stream<Application> crateApiSpyApiApplicationInfoStream() {
+  Stream<Application> crateApiSpyApiApplicationInfoStream({
+    required String clientId,
+  }) {
     final s = RustStreamSink<Application>();
     handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
           sse_encode_StreamSink_application_Sse(s, serializer);
+          sse_encode_String(clientId, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
         },
         codec: SseCodec(
@@ -121,7 +305,7 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
           decodeErrorData: sse_decode_AnyhowException,
         ),
         constMeta: kCrateApiSpyApiApplicationInfoStreamConstMeta,
-        argValues: [s],
+        argValues: [s, clientId],
         apiImpl: this,
       ),
     );
@@ -131,433 +315,4718 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   TaskConstMeta get kCrateApiSpyApiApplicationInfoStreamConstMeta =>
       const TaskConstMeta(
         debugName: "application_info_stream",
-        argNames: ["s"],
+        argNames: ["s", "clientId"],
       );
 
   @override
-  bool crateApiSpyApiGetSpyStatus() {
-    return handler.executeSync(
+  Stream<Application> crateApiSpyApiApplicationInfoStreamSince({
+    PlatformInt64? since,
+  }) {
+    final s = RustStreamSink<Application>();
+    handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_StreamSink_application_Sse(s, serializer);
+          sse_encode_opt_box_autoadd_i_64(since, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSpyApiGetSpyStatusConstMeta,
-        argValues: [],
+        constMeta: kCrateApiSpyApiApplicationInfoStreamSinceConstMeta,
+        argValues: [s, since],
         apiImpl: this,
       ),
     );
+    return s.stream;
   }
 
-  TaskConstMeta get kCrateApiSpyApiGetSpyStatusConstMeta =>
-      const TaskConstMeta(debugName: "get_spy_status", argNames: []);
+  TaskConstMeta get kCrateApiSpyApiApplicationInfoStreamSinceConstMeta =>
+      const TaskConstMeta(
+        debugName: "application_info_stream_since",
+        argNames: ["s", "since"],
+      );
 
   @override
-  String crateApiSimpleGreet({required String name}) {
+  String crateApiSpyApiExportWorklogs({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+    required WorklogFormat format,
+    String? author,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(name, serializer);
+          sse_encode_i_64(start, serializer);
+          sse_encode_i_64(end, serializer);
+          sse_encode_worklog_format(format, serializer);
+          sse_encode_opt_String(author, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
         },
         codec: SseCodec(
           decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
+          decodeErrorData: sse_decode_AnyhowException,
         ),
-        constMeta: kCrateApiSimpleGreetConstMeta,
-        argValues: [name],
+        constMeta: kCrateApiSpyApiExportWorklogsConstMeta,
+        argValues: [start, end, format, author],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
-      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+  TaskConstMeta get kCrateApiSpyApiExportWorklogsConstMeta =>
+      const TaskConstMeta(
+        debugName: "export_worklogs",
+        argNames: ["start", "end", "format", "author"],
+      );
 
   @override
-  Future<void> crateApiSimpleInitApp() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (port_) {
+  List<CategoryTime> crateApiSpyApiGetCategoryTime({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+    required Map<String,
+    AppCategory> appCategories,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 4,
-            port: port_,
-          );
+          sse_encode_i_64(start, serializer);
+          sse_encode_i_64(end, serializer);
+          sse_encode_Map_String_app_category_None(appCategories, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_list_category_time,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiSimpleInitAppConstMeta,
-        argValues: [],
+        constMeta: kCrateApiSpyApiGetCategoryTimeConstMeta,
+        argValues: [start, end, appCategories],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
-      const TaskConstMeta(debugName: "init_app", argNames: []);
+  TaskConstMeta get kCrateApiSpyApiGetCategoryTimeConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_category_time",
+        argNames: ["start", "end", "appCategories"],
+      );
 
   @override
-  void crateApiSpyApiInitSavePath({required String path}) {
+  List<CodingActivity> crateApiSpyApiGetCodingActivity({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(path, serializer);
+          sse_encode_i_64(start, serializer);
+          sse_encode_i_64(end, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_list_coding_activity,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiSpyApiInitSavePathConstMeta,
-        argValues: [path],
+        constMeta: kCrateApiSpyApiGetCodingActivityConstMeta,
+        argValues: [start, end],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSpyApiInitSavePathConstMeta =>
-      const TaskConstMeta(debugName: "init_save_path", argNames: ["path"]);
+  TaskConstMeta get kCrateApiSpyApiGetCodingActivityConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_coding_activity",
+        argNames: ["start", "end"],
+      );
 
   @override
-  void crateApiSpyApiInitScreenshotApps({required List<String> v}) {
+  BigInt crateApiSpyApiGetEventCursor() {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_String(v, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_u_64,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiSpyApiInitScreenshotAppsConstMeta,
-        argValues: [v],
+        constMeta: kCrateApiSpyApiGetEventCursorConstMeta,
+        argValues: [],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSpyApiInitScreenshotAppsConstMeta =>
-      const TaskConstMeta(debugName: "init_screenshot_apps", argNames: ["v"]);
+  TaskConstMeta get kCrateApiSpyApiGetEventCursorConstMeta =>
+      const TaskConstMeta(debugName: "get_event_cursor", argNames: []);
 
   @override
-  void crateApiSpyApiInsertScreenshotApps({required String v}) {
+  List<FocusMetrics> crateApiSpyApiGetFocusMetrics({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(v, serializer);
+          sse_encode_i_64(start, serializer);
+          sse_encode_i_64(end, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_list_focus_metrics,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiSpyApiInsertScreenshotAppsConstMeta,
-        argValues: [v],
+        constMeta: kCrateApiSpyApiGetFocusMetricsConstMeta,
+        argValues: [start, end],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSpyApiInsertScreenshotAppsConstMeta =>
-      const TaskConstMeta(debugName: "insert_screenshot_apps", argNames: ["v"]);
+  TaskConstMeta get kCrateApiSpyApiGetFocusMetricsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_focus_metrics",
+        argNames: ["start", "end"],
+      );
 
   @override
-  void crateApiSpyApiRemoveScreenshotApps({required String v}) {
+  List<FullscreenTime> crateApiSpyApiGetFullscreenTime({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+  }) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(v, serializer);
+          sse_encode_i_64(start, serializer);
+          sse_encode_i_64(end, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_list_fullscreen_time,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiSpyApiRemoveScreenshotAppsConstMeta,
-        argValues: [v],
+        constMeta: kCrateApiSpyApiGetFullscreenTimeConstMeta,
+        argValues: [start, end],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSpyApiRemoveScreenshotAppsConstMeta =>
-      const TaskConstMeta(debugName: "remove_screenshot_apps", argNames: ["v"]);
+  TaskConstMeta get kCrateApiSpyApiGetFullscreenTimeConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_fullscreen_time",
+        argNames: ["start", "end"],
+      );
 
   @override
-  void crateApiSpyApiStartSpy() {
+  Uint8List? crateApiSpyApiGetIcon({required String id, required int size}) {
     return handler.executeSync(
       SyncTask(
         callFfi: () {
           final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          sse_encode_u_32(size, serializer);
           return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
         },
         codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
+          decodeSuccessData: sse_decode_opt_list_prim_u_8_strict,
           decodeErrorData: null,
         ),
-        constMeta: kCrateApiSpyApiStartSpyConstMeta,
-        argValues: [],
+        constMeta: kCrateApiSpyApiGetIconConstMeta,
+        argValues: [id, size],
         apiImpl: this,
       ),
     );
   }
 
-  TaskConstMeta get kCrateApiSpyApiStartSpyConstMeta =>
-      const TaskConstMeta(debugName: "start_spy", argNames: []);
+  TaskConstMeta get kCrateApiSpyApiGetIconConstMeta =>
+      const TaskConstMeta(debugName: "get_icon", argNames: ["id", "size"]);
 
-  @protected
-  AnyhowException dco_decode_AnyhowException(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return AnyhowException(raw as String);
+  @override
+  PlatformInt64? crateApiSpyApiGetIdleSeconds() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_i_64,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiGetIdleSecondsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  RustStreamSink<Application> dco_decode_StreamSink_application_Sse(
-    dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    throw UnimplementedError();
-  }
+  TaskConstMeta get kCrateApiSpyApiGetIdleSecondsConstMeta =>
+      const TaskConstMeta(debugName: "get_idle_seconds", argNames: []);
 
-  @protected
-  String dco_decode_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
+  @override
+  Future<String?> crateApiSpyApiGetInstalledAppIcon({required String id}) {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(id, serializer);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 11,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiGetInstalledAppIconConstMeta,
+        argValues: [id],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  Application dco_decode_application(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return Application(
-      icon: dco_decode_opt_String(arr[0]),
-      name: dco_decode_String(arr[1]),
-      title: dco_decode_String(arr[2]),
-      path: dco_decode_String(arr[3]),
-      screenShotPath: dco_decode_opt_String(arr[4]),
+  TaskConstMeta get kCrateApiSpyApiGetInstalledAppIconConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_installed_app_icon",
+        argNames: ["id"],
+      );
+
+  @override
+  List<IssueTime> crateApiSpyApiGetIssueTime({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(start, serializer);
+          sse_encode_i_64(end, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_issue_time,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiGetIssueTimeConstMeta,
+        argValues: [start, end],
+        apiImpl: this,
+      ),
     );
   }
 
-  @protected
-  bool dco_decode_bool(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
+  TaskConstMeta get kCrateApiSpyApiGetIssueTimeConstMeta => const TaskConstMeta(
+    debugName: "get_issue_time",
+    argNames: ["start", "end"],
+  );
 
-  @protected
-  List<String> dco_decode_list_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  @override
+  List<AppLifecycleEvent> crateApiSpyApiGetLifecycleEvents({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(start, serializer);
+          sse_encode_i_64(end, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_app_lifecycle_event,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiGetLifecycleEventsConstMeta,
+        argValues: [start, end],
+        apiImpl: this,
+      ),
+    );
   }
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
+  TaskConstMeta get kCrateApiSpyApiGetLifecycleEventsConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_lifecycle_events",
+        argNames: ["start", "end"],
+      );
+
+  @override
+  List<MediaActivity> crateApiSpyApiGetMediaActivity({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(start, serializer);
+          sse_encode_i_64(end, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_media_activity,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiGetMediaActivityConstMeta,
+        argValues: [start, end],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiGetMediaActivityConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_media_activity",
+        argNames: ["start", "end"],
+      );
+
+  @override
+  List<MediaTime> crateApiSpyApiGetMediaTime({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(start, serializer);
+          sse_encode_i_64(end, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_media_time,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiGetMediaTimeConstMeta,
+        argValues: [start, end],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiGetMediaTimeConstMeta => const TaskConstMeta(
+    debugName: "get_media_time",
+    argNames: ["start", "end"],
+  );
+
+  @override
+  List<Meeting> crateApiSpyApiGetMeetings({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(start, serializer);
+          sse_encode_i_64(end, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_meeting,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiGetMeetingsConstMeta,
+        argValues: [start, end],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiGetMeetingsConstMeta => const TaskConstMeta(
+    debugName: "get_meetings",
+    argNames: ["start", "end"],
+  );
+
+  @override
+  List<MediaState> crateApiSpyApiGetNowPlaying() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_media_state,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiGetNowPlayingConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiGetNowPlayingConstMeta =>
+      const TaskConstMeta(debugName: "get_now_playing", argNames: []);
+
+  @override
+  List<RepositoryTime> crateApiSpyApiGetRepositoryTime({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(start, serializer);
+          sse_encode_i_64(end, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_repository_time,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiGetRepositoryTimeConstMeta,
+        argValues: [start, end],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiGetRepositoryTimeConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_repository_time",
+        argNames: ["start", "end"],
+      );
+
+  @override
+  List<AppResourceUsage> crateApiSpyApiGetResourceUsage({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(start, serializer);
+          sse_encode_i_64(end, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_app_resource_usage,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiGetResourceUsageConstMeta,
+        argValues: [start, end],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiGetResourceUsageConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_resource_usage",
+        argNames: ["start", "end"],
+      );
+
+  @override
+  List<AppRunningTime> crateApiSpyApiGetRunningTime({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(start, serializer);
+          sse_encode_i_64(end, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_app_running_time,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiGetRunningTimeConstMeta,
+        argValues: [start, end],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiGetRunningTimeConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_running_time",
+        argNames: ["start", "end"],
+      );
+
+  @override
+  String? crateApiSpyApiGetShellHookScript({required String shell}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(shell, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiGetShellHookScriptConstMeta,
+        argValues: [shell],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiGetShellHookScriptConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_shell_hook_script",
+        argNames: ["shell"],
+      );
+
+  @override
+  bool crateApiSpyApiGetSpyStatus() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiGetSpyStatusConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiGetSpyStatusConstMeta =>
+      const TaskConstMeta(debugName: "get_spy_status", argNames: []);
+
+  @override
+  List<TerminalActivity> crateApiSpyApiGetTerminalActivity({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(start, serializer);
+          sse_encode_i_64(end, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_terminal_activity,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiGetTerminalActivityConstMeta,
+        argValues: [start, end],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiGetTerminalActivityConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_terminal_activity",
+        argNames: ["start", "end"],
+      );
+
+  @override
+  List<VisibleTime> crateApiSpyApiGetVisibleTime({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(start, serializer);
+          sse_encode_i_64(end, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_visible_time,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiGetVisibleTimeConstMeta,
+        argValues: [start, end],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiGetVisibleTimeConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_visible_time",
+        argNames: ["start", "end"],
+      );
+
+  @override
+  List<WorkspaceTime> crateApiSpyApiGetWorkspaceTime({
+    required PlatformInt64 start,
+    required PlatformInt64 end,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(start, serializer);
+          sse_encode_i_64(end, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_workspace_time,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiGetWorkspaceTimeConstMeta,
+        argValues: [start, end],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiGetWorkspaceTimeConstMeta =>
+      const TaskConstMeta(
+        debugName: "get_workspace_time",
+        argNames: ["start", "end"],
+      );
+
+  @override
+  String crateApiSimpleGreet({required String name}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(name, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleGreetConstMeta,
+        argValues: [name],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleGreetConstMeta =>
+      const TaskConstMeta(debugName: "greet", argNames: ["name"]);
+
+  @override
+  Future<void> crateApiSimpleInitApp() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 27,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSimpleInitAppConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSimpleInitAppConstMeta =>
+      const TaskConstMeta(debugName: "init_app", argNames: []);
+
+  @override
+  void crateApiSpyApiInitIconCachePath({required String path}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiInitIconCachePathConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiInitIconCachePathConstMeta =>
+      const TaskConstMeta(
+        debugName: "init_icon_cache_path",
+        argNames: ["path"],
+      );
+
+  @override
+  JournalRecovery crateApiSpyApiInitJournal({required String path}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_journal_recovery,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSpyApiInitJournalConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiInitJournalConstMeta =>
+      const TaskConstMeta(debugName: "init_journal", argNames: ["path"]);
+
+  @override
+  void crateApiSpyApiInitSavePath({required String path}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(path, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiInitSavePathConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiInitSavePathConstMeta =>
+      const TaskConstMeta(debugName: "init_save_path", argNames: ["path"]);
+
+  @override
+  void crateApiSpyApiInitScreenshotApps({required List<String> v}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(v, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiInitScreenshotAppsConstMeta,
+        argValues: [v],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiInitScreenshotAppsConstMeta =>
+      const TaskConstMeta(debugName: "init_screenshot_apps", argNames: ["v"]);
+
+  @override
+  void crateApiSpyApiInsertScreenshotApps({required String v}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(v, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiInsertScreenshotAppsConstMeta,
+        argValues: [v],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiInsertScreenshotAppsConstMeta =>
+      const TaskConstMeta(debugName: "insert_screenshot_apps", argNames: ["v"]);
+
+  @override
+  Future<List<InstalledApp>> crateApiSpyApiListInstalledApplications() {
+    return handler.executeNormal(
+      NormalTask(
+        callFfi: (port_) {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          pdeCallFfi(
+            generalizedFrbRustBinding,
+            serializer,
+            funcId: 33,
+            port: port_,
+          );
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_installed_app,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiListInstalledApplicationsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiListInstalledApplicationsConstMeta =>
+      const TaskConstMeta(
+        debugName: "list_installed_applications",
+        argNames: [],
+      );
+
+  @override
+  List<Monitor> crateApiSpyApiListMonitors() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_monitor,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiListMonitorsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiListMonitorsConstMeta =>
+      const TaskConstMeta(debugName: "list_monitors", argNames: []);
+
+  @override
+  List<Application> crateApiSpyApiListRunningApplications() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_application,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiListRunningApplicationsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiListRunningApplicationsConstMeta =>
+      const TaskConstMeta(debugName: "list_running_applications", argNames: []);
+
+  @override
+  List<WindowInfo> crateApiSpyApiListWindows() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_list_window_info,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiListWindowsConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiListWindowsConstMeta =>
+      const TaskConstMeta(debugName: "list_windows", argNames: []);
+
+  @override
+  void crateApiSpyApiRemoveScreenshotApps({required String v}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(v, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiRemoveScreenshotAppsConstMeta,
+        argValues: [v],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiRemoveScreenshotAppsConstMeta =>
+      const TaskConstMeta(debugName: "remove_screenshot_apps", argNames: ["v"]);
+
+  @override
+  void crateApiSpyApiSetBackgroundApps({required List<String> apps}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(apps, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiSetBackgroundAppsConstMeta,
+        argValues: [apps],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiSetBackgroundAppsConstMeta =>
+      const TaskConstMeta(debugName: "set_background_apps", argNames: ["apps"]);
+
+  @override
+  void crateApiSpyApiSetCaptureMode({required CaptureMode mode}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_capture_mode(mode, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiSetCaptureModeConstMeta,
+        argValues: [mode],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiSetCaptureModeConstMeta =>
+      const TaskConstMeta(debugName: "set_capture_mode", argNames: ["mode"]);
+
+  @override
+  void crateApiSpyApiSetIdleThreshold({required PlatformInt64 secs}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_i_64(secs, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiSetIdleThresholdConstMeta,
+        argValues: [secs],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiSetIdleThresholdConstMeta =>
+      const TaskConstMeta(debugName: "set_idle_threshold", argNames: ["secs"]);
+
+  @override
+  void crateApiSpyApiSetIssueKeyPattern({required String pattern}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_String(pattern, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSpyApiSetIssueKeyPatternConstMeta,
+        argValues: [pattern],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiSetIssueKeyPatternConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_issue_key_pattern",
+        argNames: ["pattern"],
+      );
+
+  @override
+  void crateApiSpyApiSetIssueProjects({required List<String> projects}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_String(projects, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiSetIssueProjectsConstMeta,
+        argValues: [projects],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiSetIssueProjectsConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_issue_projects",
+        argNames: ["projects"],
+      );
+
+  @override
+  void crateApiSpyApiSetVisibleTracking({required bool enabled}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_bool(enabled, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiSetVisibleTrackingConstMeta,
+        argValues: [enabled],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiSetVisibleTrackingConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_visible_tracking",
+        argNames: ["enabled"],
+      );
+
+  @override
+  void crateApiSpyApiSetWorkspaceRules({required List<WorkspaceRule> rules}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_list_workspace_rule(rules, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSpyApiSetWorkspaceRulesConstMeta,
+        argValues: [rules],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiSetWorkspaceRulesConstMeta =>
+      const TaskConstMeta(
+        debugName: "set_workspace_rules",
+        argNames: ["rules"],
+      );
+
+  @override
+  int crateApiSpyApiStartHeartbeatServer({
+    required int port,
+    required String apiKey,
+  }) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_u_16(port, serializer);
+          sse_encode_String(apiKey, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_u_16,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSpyApiStartHeartbeatServerConstMeta,
+        argValues: [port, apiKey],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiStartHeartbeatServerConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_heartbeat_server",
+        argNames: ["port", "apiKey"],
+      );
+
+  @override
+  String crateApiSpyApiStartInputMonitor() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSpyApiStartInputMonitorConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiStartInputMonitorConstMeta =>
+      const TaskConstMeta(debugName: "start_input_monitor", argNames: []);
+
+  @override
+  String crateApiSpyApiStartLifecycleWatcher() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSpyApiStartLifecycleWatcherConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiStartLifecycleWatcherConstMeta =>
+      const TaskConstMeta(debugName: "start_lifecycle_watcher", argNames: []);
+
+  @override
+  void crateApiSpyApiStartMediaWatcher() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSpyApiStartMediaWatcherConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiStartMediaWatcherConstMeta =>
+      const TaskConstMeta(debugName: "start_media_watcher", argNames: []);
+
+  @override
+  void crateApiSpyApiStartMeetingDetector() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSpyApiStartMeetingDetectorConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiStartMeetingDetectorConstMeta =>
+      const TaskConstMeta(debugName: "start_meeting_detector", argNames: []);
+
+  @override
+  String crateApiSpyApiStartShellListener({String? path}) {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_opt_String(path, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSpyApiStartShellListenerConstMeta,
+        argValues: [path],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiStartShellListenerConstMeta =>
+      const TaskConstMeta(
+        debugName: "start_shell_listener",
+        argNames: ["path"],
+      );
+
+  @override
+  void crateApiSpyApiStartSpy() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiStartSpyConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiStartSpyConstMeta =>
+      const TaskConstMeta(debugName: "start_spy", argNames: []);
+
+  @override
+  void crateApiSpyApiStopHeartbeatServer() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiStopHeartbeatServerConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiStopHeartbeatServerConstMeta =>
+      const TaskConstMeta(debugName: "stop_heartbeat_server", argNames: []);
+
+  @override
+  void crateApiSpyApiStopInputMonitor() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiStopInputMonitorConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiStopInputMonitorConstMeta =>
+      const TaskConstMeta(debugName: "stop_input_monitor", argNames: []);
+
+  @override
+  void crateApiSpyApiStopLifecycleWatcher() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiStopLifecycleWatcherConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiStopLifecycleWatcherConstMeta =>
+      const TaskConstMeta(debugName: "stop_lifecycle_watcher", argNames: []);
+
+  @override
+  void crateApiSpyApiStopMediaWatcher() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiStopMediaWatcherConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiStopMediaWatcherConstMeta =>
+      const TaskConstMeta(debugName: "stop_media_watcher", argNames: []);
+
+  @override
+  void crateApiSpyApiStopMeetingDetector() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiStopMeetingDetectorConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiStopMeetingDetectorConstMeta =>
+      const TaskConstMeta(debugName: "stop_meeting_detector", argNames: []);
+
+  @override
+  void crateApiSpyApiStopShellListener() {
+    return handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        ),
+        constMeta: kCrateApiSpyApiStopShellListenerConstMeta,
+        argValues: [],
+        apiImpl: this,
+      ),
+    );
+  }
+
+  TaskConstMeta get kCrateApiSpyApiStopShellListenerConstMeta =>
+      const TaskConstMeta(debugName: "stop_shell_listener", argNames: []);
+
+  @override
+  Stream<TrackerEvent> crateApiSpyApiSubscribeTrackerEvents({
+    required EventFilter filter,
+    BigInt? afterSeq,
+    PlatformInt64? since,
+  }) {
+    final s = RustStreamSink<TrackerEvent>();
+    handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_StreamSink_tracker_event_Sse(s, serializer);
+          sse_encode_box_autoadd_event_filter(filter, serializer);
+          sse_encode_opt_box_autoadd_u_64(afterSeq, serializer);
+          sse_encode_opt_box_autoadd_i_64(since, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSpyApiSubscribeTrackerEventsConstMeta,
+        argValues: [s, filter, afterSeq, since],
+        apiImpl: this,
+      ),
+    );
+    return s.stream;
+  }
+
+  TaskConstMeta get kCrateApiSpyApiSubscribeTrackerEventsConstMeta =>
+      const TaskConstMeta(
+        debugName: "subscribe_tracker_events",
+        argNames: ["s", "filter", "afterSeq", "since"],
+      );
+
+  @override
+  Stream<TrackerEvent> crateApiSpyApiTrackerEventStream() {
+    final s = RustStreamSink<TrackerEvent>();
+    handler.executeSync(
+      SyncTask(
+        callFfi: () {
+          final serializer = SseSerializer(generalizedFrbRustBinding);
+          sse_encode_StreamSink_tracker_event_Sse(s, serializer);
+          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59)!;
+        },
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+        constMeta: kCrateApiSpyApiTrackerEventStreamConstMeta,
+        argValues: [s],
+        apiImpl: this,
+      ),
+    );
+    return s.stream;
+  }
+
+  TaskConstMeta get kCrateApiSpyApiTrackerEventStreamConstMeta =>
+      const TaskConstMeta(debugName: "tracker_event_stream", argNames: ["s"]);
+
+  @protected
+  AnyhowException dco_decode_AnyhowException(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AnyhowException(raw as String);
+  }
+
+  @protected
+  Map<String, AppCategory> dco_decode_Map_String_app_category_None(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return Map.fromEntries(
+      dco_decode_list_record_string_app_category(raw).map(
+        (e) => MapEntry(e.$1, e.$2),
+      ),
+    );
+  }
+
+  @protected
+  RustStreamSink<Application> dco_decode_StreamSink_application_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  RustStreamSink<TrackerEvent> dco_decode_StreamSink_tracker_event_Sse(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    throw UnimplementedError();
+  }
+
+  @protected
+  String dco_decode_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as String;
+  }
+
+  @protected
+  ActivityLevel dco_decode_activity_level(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return ActivityLevel.values[raw as int];
+  }
+
+  @protected
+  AppCategory dco_decode_app_category(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return AppCategory.values[raw as int];
+  }
+
+  @protected
+  AppLifecycleEvent dco_decode_app_lifecycle_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return AppLifecycleEvent(
+      kind: dco_decode_lifecycle_kind(arr[0]),
+      name: dco_decode_String(arr[1]),
+      pid: dco_decode_u_32(arr[2]),
+      timestamp: dco_decode_i_64(arr[3]),
+    );
+  }
+
+  @protected
+  AppResourceUsage dco_decode_app_resource_usage(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return AppResourceUsage(
+      name: dco_decode_String(arr[0]),
+      foreground: dco_decode_resource_stats(arr[1]),
+      background: dco_decode_resource_stats(arr[2]),
+    );
+  }
+
+  @protected
+  AppRunningTime dco_decode_app_running_time(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return AppRunningTime(
+      name: dco_decode_String(arr[0]),
+      runningSeconds: dco_decode_i_64(arr[1]),
+      focusedSeconds: dco_decode_i_64(arr[2]),
+    );
+  }
+
+  @protected
+  Application dco_decode_application(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 17)
+      throw Exception('unexpected arr length: expect 17 but see ${arr.length}');
+    return Application(
+      icon: dco_decode_opt_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+      title: dco_decode_String(arr[2]),
+      path: dco_decode_String(arr[3]),
+      screenShotPath: dco_decode_opt_String(arr[4]),
+      repo: dco_decode_opt_String(arr[5]),
+      branch: dco_decode_opt_String(arr[6]),
+      appId: dco_decode_opt_String(arr[7]),
+      exePath: dco_decode_String(arr[8]),
+      runtime: dco_decode_opt_String(arr[9]),
+      package: dco_decode_opt_String(arr[10]),
+      version: dco_decode_opt_String(arr[11]),
+      process: dco_decode_opt_box_autoadd_process_info(arr[12]),
+      monitor: dco_decode_opt_String(arr[13]),
+      workspace: dco_decode_opt_box_autoadd_workspace(arr[14]),
+      state: dco_decode_opt_box_autoadd_window_state(arr[15]),
+      geometry: dco_decode_opt_box_autoadd_rect(arr[16]),
+    );
+  }
+
+  @protected
+  bool dco_decode_bool(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as bool;
+  }
+
+  @protected
+  ActivityLevel dco_decode_box_autoadd_activity_level(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_activity_level(raw);
+  }
+
+  @protected
+  AppCategory dco_decode_box_autoadd_app_category(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_app_category(raw);
+  }
+
+  @protected
+  Application dco_decode_box_autoadd_application(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_application(raw);
+  }
+
+  @protected
+  EventFilter dco_decode_box_autoadd_event_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_event_filter(raw);
+  }
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_i_64(raw);
+  }
+
+  @protected
+  ProcessInfo dco_decode_box_autoadd_process_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_process_info(raw);
+  }
+
+  @protected
+  Rect dco_decode_box_autoadd_rect(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_rect(raw);
+  }
+
+  @protected
+  SessionSummary dco_decode_box_autoadd_session_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_session_summary(raw);
+  }
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_u_64(raw);
+  }
+
+  @protected
+  WindowState dco_decode_box_autoadd_window_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_window_state(raw);
+  }
+
+  @protected
+  Workspace dco_decode_box_autoadd_workspace(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dco_decode_workspace(raw);
+  }
+
+  @protected
+  CaptureMode dco_decode_capture_mode(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return CaptureMode.values[raw as int];
+  }
+
+  @protected
+  CategoryTime dco_decode_category_time(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return CategoryTime(
+      category: dco_decode_app_category(arr[0]),
+      seconds: dco_decode_f_64(arr[1]),
+    );
+  }
+
+  @protected
+  CodingActivity dco_decode_coding_activity(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return CodingActivity(
+      appName: dco_decode_opt_String(arr[0]),
+      project: dco_decode_opt_String(arr[1]),
+      branch: dco_decode_opt_String(arr[2]),
+      entity: dco_decode_String(arr[3]),
+      language: dco_decode_opt_String(arr[4]),
+      seconds: dco_decode_f_64(arr[5]),
+    );
+  }
+
+  @protected
+  EventFilter dco_decode_event_filter(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return EventFilter(
+      kinds: dco_decode_list_tracker_event_kind(arr[0]),
+      apps: dco_decode_list_String(arr[1]),
+    );
+  }
+
+  @protected
+  double dco_decode_f_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  double dco_decode_f_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as double;
+  }
+
+  @protected
+  FocusMetrics dco_decode_focus_metrics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return FocusMetrics(
+      name: dco_decode_String(arr[0]),
+      seconds: dco_decode_f_64(arr[1]),
+      activeSeconds: dco_decode_f_64(arr[2]),
+      idleSeconds: dco_decode_f_64(arr[3]),
+      input: dco_decode_input_counts(arr[4]),
+      level: dco_decode_activity_level(arr[5]),
+    );
+  }
+
+  @protected
+  FullscreenTime dco_decode_fullscreen_time(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return FullscreenTime(
+      name: dco_decode_String(arr[0]),
+      fullscreenSeconds: dco_decode_f_64(arr[1]),
+      seconds: dco_decode_f_64(arr[2]),
+      immersive: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  int dco_decode_i_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeI64(raw);
+  }
+
+  @protected
+  InputCounts dco_decode_input_counts(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return InputCounts(
+      keystrokes: dco_decode_u_32(arr[0]),
+      clicks: dco_decode_u_32(arr[1]),
+      scrolls: dco_decode_u_32(arr[2]),
+      mouseDistance: dco_decode_u_32(arr[3]),
+    );
+  }
+
+  @protected
+  InstalledApp dco_decode_installed_app(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return InstalledApp(
+      id: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+      exec: dco_decode_opt_String(arr[2]),
+      icon: dco_decode_opt_String(arr[3]),
+      categories: dco_decode_list_String(arr[4]),
+      suggested: dco_decode_app_category(arr[5]),
+      source: dco_decode_opt_String(arr[6]),
+    );
+  }
+
+  @protected
+  IssueTime dco_decode_issue_time(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return IssueTime(
+      key: dco_decode_String(arr[0]),
+      apps: dco_decode_list_String(arr[1]),
+      seconds: dco_decode_f_64(arr[2]),
+    );
+  }
+
+  @protected
+  JournalRecovery dco_decode_journal_recovery(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return JournalRecovery(
+      records: dco_decode_u_32(arr[0]),
+      truncatedBytes: dco_decode_u_64(arr[1]),
+      sessions: dco_decode_u_32(arr[2]),
+    );
+  }
+
+  @protected
+  LifecycleKind dco_decode_lifecycle_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return LifecycleKind.values[raw as int];
+  }
+
+  @protected
+  List<String> dco_decode_list_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_String).toList();
+  }
+
+  @protected
+  List<AppLifecycleEvent> dco_decode_list_app_lifecycle_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_app_lifecycle_event).toList();
+  }
+
+  @protected
+  List<AppResourceUsage> dco_decode_list_app_resource_usage(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_app_resource_usage).toList();
+  }
+
+  @protected
+  List<AppRunningTime> dco_decode_list_app_running_time(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_app_running_time).toList();
+  }
+
+  @protected
+  List<Application> dco_decode_list_application(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_application).toList();
+  }
+
+  @protected
+  List<CategoryTime> dco_decode_list_category_time(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_category_time).toList();
+  }
+
+  @protected
+  List<CodingActivity> dco_decode_list_coding_activity(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_coding_activity).toList();
+  }
+
+  @protected
+  List<FocusMetrics> dco_decode_list_focus_metrics(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_focus_metrics).toList();
+  }
+
+  @protected
+  List<FullscreenTime> dco_decode_list_fullscreen_time(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_fullscreen_time).toList();
+  }
+
+  @protected
+  List<InstalledApp> dco_decode_list_installed_app(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_installed_app).toList();
+  }
+
+  @protected
+  List<IssueTime> dco_decode_list_issue_time(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_issue_time).toList();
+  }
+
+  @protected
+  List<MediaActivity> dco_decode_list_media_activity(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_media_activity).toList();
+  }
+
+  @protected
+  List<MediaState> dco_decode_list_media_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_media_state).toList();
+  }
+
+  @protected
+  List<MediaTime> dco_decode_list_media_time(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_media_time).toList();
+  }
+
+  @protected
+  List<Meeting> dco_decode_list_meeting(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_meeting).toList();
+  }
+
+  @protected
+  List<Monitor> dco_decode_list_monitor(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_monitor).toList();
+  }
+
+  @protected
+  List<ParentProcess> dco_decode_list_parent_process(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_parent_process).toList();
+  }
+
+  @protected
+  Uint8List dco_decode_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as Uint8List;
+  }
+
+  @protected
+  List<(String, AppCategory)> dco_decode_list_record_string_app_category(
+    dynamic raw,
+  ) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_record_string_app_category).toList(
+    );
+  }
+
+  @protected
+  List<RepositoryTime> dco_decode_list_repository_time(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_repository_time).toList();
+  }
+
+  @protected
+  List<TerminalActivity> dco_decode_list_terminal_activity(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_terminal_activity).toList();
+  }
+
+  @protected
+  List<TrackerEventKind> dco_decode_list_tracker_event_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_tracker_event_kind).toList();
+  }
+
+  @protected
+  List<VisibleTime> dco_decode_list_visible_time(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_visible_time).toList();
+  }
+
+  @protected
+  List<WindowInfo> dco_decode_list_window_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_window_info).toList();
+  }
+
+  @protected
+  List<WorkspaceRule> dco_decode_list_workspace_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_workspace_rule).toList();
+  }
+
+  @protected
+  List<WorkspaceTime> dco_decode_list_workspace_time(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return (raw as List<dynamic>).map(dco_decode_workspace_time).toList();
+  }
+
+  @protected
+  MediaActivity dco_decode_media_activity(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return MediaActivity(
+      player: dco_decode_String(arr[0]),
+      title: dco_decode_opt_String(arr[1]),
+      artist: dco_decode_opt_String(arr[2]),
+      start: dco_decode_i_64(arr[3]),
+      end: dco_decode_i_64(arr[4]),
+    );
+  }
+
+  @protected
+  MediaState dco_decode_media_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return MediaState(
+      player: dco_decode_String(arr[0]),
+      status: dco_decode_playback_status(arr[1]),
+      title: dco_decode_opt_String(arr[2]),
+      artist: dco_decode_opt_String(arr[3]),
+      album: dco_decode_opt_String(arr[4]),
+      url: dco_decode_opt_String(arr[5]),
+    );
+  }
+
+  @protected
+  MediaTime dco_decode_media_time(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return MediaTime(
+      player: dco_decode_String(arr[0]),
+      seconds: dco_decode_f_64(arr[1]),
+    );
+  }
+
+  @protected
+  Meeting dco_decode_meeting(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 7)
+      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+    return Meeting(
+      id: dco_decode_u_64(arr[0]),
+      app: dco_decode_opt_String(arr[1]),
+      start: dco_decode_i_64(arr[2]),
+      end: dco_decode_i_64(arr[3]),
+      camera: dco_decode_bool(arr[4]),
+      screenShare: dco_decode_bool(arr[5]),
+      ongoing: dco_decode_bool(arr[6]),
+    );
+  }
+
+  @protected
+  Monitor dco_decode_monitor(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return Monitor(
+      name: dco_decode_String(arr[0]),
+      x: dco_decode_i_32(arr[1]),
+      y: dco_decode_i_32(arr[2]),
+      width: dco_decode_i_32(arr[3]),
+      height: dco_decode_i_32(arr[4]),
+      primary: dco_decode_bool(arr[5]),
+    );
+  }
+
+  @protected
+  String? dco_decode_opt_String(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_String(raw);
+  }
+
+  @protected
+  ActivityLevel? dco_decode_opt_box_autoadd_activity_level(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_activity_level(raw);
+  }
+
+  @protected
+  AppCategory? dco_decode_opt_box_autoadd_app_category(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_app_category(raw);
+  }
+
+  @protected
+  Application? dco_decode_opt_box_autoadd_application(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_application(raw);
+  }
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_i_64(raw);
+  }
+
+  @protected
+  ProcessInfo? dco_decode_opt_box_autoadd_process_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_process_info(raw);
+  }
+
+  @protected
+  Rect? dco_decode_opt_box_autoadd_rect(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_rect(raw);
+  }
+
+  @protected
+  SessionSummary? dco_decode_opt_box_autoadd_session_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_session_summary(raw);
+  }
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_32(raw);
+  }
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
+  }
+
+  @protected
+  WindowState? dco_decode_opt_box_autoadd_window_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_window_state(raw);
+  }
+
+  @protected
+  Workspace? dco_decode_opt_box_autoadd_workspace(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_box_autoadd_workspace(raw);
+  }
+
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw == null ? null : dco_decode_list_prim_u_8_strict(raw);
+  }
+
+  @protected
+  ParentProcess dco_decode_parent_process(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2)
+      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+    return ParentProcess(
+      pid: dco_decode_u_32(arr[0]),
+      name: dco_decode_String(arr[1]),
+    );
+  }
+
+  @protected
+  PlaybackStatus dco_decode_playback_status(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return PlaybackStatus.values[raw as int];
+  }
+
+  @protected
+  ProcessInfo dco_decode_process_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 5)
+      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+    return ProcessInfo(
+      pid: dco_decode_u_32(arr[0]),
+      parents: dco_decode_list_parent_process(arr[1]),
+      cmdline: dco_decode_list_String(arr[2]),
+      startTime: dco_decode_u_64(arr[3]),
+      user: dco_decode_opt_String(arr[4]),
+    );
+  }
+
+  @protected (String, AppCategory)
+  dco_decode_record_string_app_category(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 2) {
+      throw Exception('Expected 2 elements, got ${arr.length}');
+    }
+    return (dco_decode_String(arr[0]), dco_decode_app_category(arr[1]));
+  }
+
+  @protected
+  Rect dco_decode_rect(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return Rect(
+      x: dco_decode_i_32(arr[0]),
+      y: dco_decode_i_32(arr[1]),
+      width: dco_decode_i_32(arr[2]),
+      height: dco_decode_i_32(arr[3]),
+    );
+  }
+
+  @protected
+  RepositoryTime dco_decode_repository_time(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return RepositoryTime(
+      repo: dco_decode_String(arr[0]),
+      name: dco_decode_String(arr[1]),
+      branch: dco_decode_opt_String(arr[2]),
+      seconds: dco_decode_f_64(arr[3]),
+    );
+  }
+
+  @protected
+  ResourceStats dco_decode_resource_stats(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 8)
+      throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+    return ResourceStats(
+      samples: dco_decode_u_32(arr[0]),
+      cpuAvg: dco_decode_f_32(arr[1]),
+      cpuMax: dco_decode_f_32(arr[2]),
+      memoryAvg: dco_decode_u_64(arr[3]),
+      memoryMax: dco_decode_u_64(arr[4]),
+      diskRead: dco_decode_u_64(arr[5]),
+      diskWrite: dco_decode_u_64(arr[6]),
+      threadsMax: dco_decode_u_32(arr[7]),
+    );
+  }
+
+  @protected
+  SessionSummary dco_decode_session_summary(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 6)
+      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+    return SessionSummary(
+      id: dco_decode_u_64(arr[0]),
+      name: dco_decode_String(arr[1]),
+      title: dco_decode_String(arr[2]),
+      start: dco_decode_i_64(arr[3]),
+      end: dco_decode_i_64(arr[4]),
+      activity: dco_decode_opt_box_autoadd_activity_level(arr[5]),
+    );
+  }
+
+  @protected
+  TerminalActivity dco_decode_terminal_activity(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return TerminalActivity(
+      appName: dco_decode_String(arr[0]),
+      command: dco_decode_opt_String(arr[1]),
+      cwd: dco_decode_String(arr[2]),
+      seconds: dco_decode_f_64(arr[3]),
+    );
+  }
+
+  @protected
+  TrackerEvent dco_decode_tracker_event(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 15)
+      throw Exception('unexpected arr length: expect 15 but see ${arr.length}');
+    return TrackerEvent(
+      schemaVersion: dco_decode_u_32(arr[0]),
+      seq: dco_decode_u_64(arr[1]),
+      kind: dco_decode_tracker_event_kind(arr[2]),
+      timestamp: dco_decode_i_64(arr[3]),
+      emittedAt: dco_decode_i_64(arr[4]),
+      app: dco_decode_opt_box_autoadd_application(arr[5]),
+      session: dco_decode_opt_box_autoadd_session_summary(arr[6]),
+      path: dco_decode_opt_String(arr[7]),
+      source: dco_decode_opt_String(arr[8]),
+      message: dco_decode_opt_String(arr[9]),
+      idleSeconds: dco_decode_opt_box_autoadd_i_64(arr[10]),
+      name: dco_decode_opt_String(arr[11]),
+      meetingId: dco_decode_opt_box_autoadd_u_64(arr[12]),
+      pid: dco_decode_opt_box_autoadd_u_32(arr[13]),
+      dropped: dco_decode_opt_box_autoadd_u_64(arr[14]),
+    );
+  }
+
+  @protected
+  TrackerEventKind dco_decode_tracker_event_kind(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return TrackerEventKind.values[raw as int];
+  }
+
+  @protected
+  int dco_decode_u_16(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  int dco_decode_u_32(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return dcoDecodeU64(raw);
+  }
+
+  @protected
+  int dco_decode_u_8(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return raw as int;
+  }
+
+  @protected
+  void dco_decode_unit(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return;
+  }
+
+  @protected
+  VisibleTime dco_decode_visible_time(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return VisibleTime(
+      name: dco_decode_String(arr[0]),
+      visibleSeconds: dco_decode_i_64(arr[1]),
+      focusedSeconds: dco_decode_i_64(arr[2]),
+    );
+  }
+
+  @protected
+  WindowInfo dco_decode_window_info(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return WindowInfo(
+      windowId: dco_decode_u_64(arr[0]),
+      focused: dco_decode_bool(arr[1]),
+      app: dco_decode_application(arr[2]),
+    );
+  }
+
+  @protected
+  WindowState dco_decode_window_state(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return WindowState(
+      fullscreen: dco_decode_bool(arr[0]),
+      maximized: dco_decode_bool(arr[1]),
+      minimized: dco_decode_bool(arr[2]),
+      above: dco_decode_bool(arr[3]),
+    );
+  }
+
+  @protected
+  WorklogFormat dco_decode_worklog_format(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    return WorklogFormat.values[raw as int];
+  }
+
+  @protected
+  Workspace dco_decode_workspace(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 4)
+      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+    return Workspace(
+      index: dco_decode_u_32(arr[0]),
+      name: dco_decode_String(arr[1]),
+      category: dco_decode_opt_box_autoadd_app_category(arr[2]),
+      project: dco_decode_opt_String(arr[3]),
+    );
+  }
+
+  @protected
+  WorkspaceRule dco_decode_workspace_rule(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return WorkspaceRule(
+      pattern: dco_decode_String(arr[0]),
+      category: dco_decode_opt_box_autoadd_app_category(arr[1]),
+      project: dco_decode_opt_String(arr[2]),
+    );
+  }
+
+  @protected
+  WorkspaceTime dco_decode_workspace_time(dynamic raw) {
+    // Codec=Dco (DartCObject based), see doc to use other codecs
+    final arr = raw as List<dynamic>;
+    if (arr.length != 3)
+      throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+    return WorkspaceTime(
+      name: dco_decode_String(arr[0]),
+      project: dco_decode_opt_String(arr[1]),
+      seconds: dco_decode_f_64(arr[2]),
+    );
+  }
+
+  @protected
+  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_String(deserializer);
+    return AnyhowException(inner);
+  }
+
+  @protected
+  Map<String, AppCategory> sse_decode_Map_String_app_category_None(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_record_string_app_category(deserializer);
+    return Map.fromEntries(inner.map((e) => MapEntry(e.$1, e.$2)));
+  }
+
+  @protected
+  RustStreamSink<Application> sse_decode_StreamSink_application_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  RustStreamSink<TrackerEvent> sse_decode_StreamSink_tracker_event_Sse(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    throw UnimplementedError('Unreachable ()');
+  }
+
+  @protected
+  String sse_decode_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_list_prim_u_8_strict(deserializer);
+    return utf8.decoder.convert(inner);
+  }
+
+  @protected
+  ActivityLevel sse_decode_activity_level(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return ActivityLevel.values[inner];
+  }
+
+  @protected
+  AppCategory sse_decode_app_category(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return AppCategory.values[inner];
+  }
+
+  @protected
+  AppLifecycleEvent sse_decode_app_lifecycle_event(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kind = sse_decode_lifecycle_kind(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_pid = sse_decode_u_32(deserializer);
+    var var_timestamp = sse_decode_i_64(deserializer);
+    return AppLifecycleEvent(
+      kind: var_kind,
+      name: var_name,
+      pid: var_pid,
+      timestamp: var_timestamp,
+    );
+  }
+
+  @protected
+  AppResourceUsage sse_decode_app_resource_usage(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_foreground = sse_decode_resource_stats(deserializer);
+    var var_background = sse_decode_resource_stats(deserializer);
+    return AppResourceUsage(
+      name: var_name,
+      foreground: var_foreground,
+      background: var_background,
+    );
+  }
+
+  @protected
+  AppRunningTime sse_decode_app_running_time(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_runningSeconds = sse_decode_i_64(deserializer);
+    var var_focusedSeconds = sse_decode_i_64(deserializer);
+    return AppRunningTime(
+      name: var_name,
+      runningSeconds: var_runningSeconds,
+      focusedSeconds: var_focusedSeconds,
+    );
+  }
+
+  @protected
+  Application sse_decode_application(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_icon = sse_decode_opt_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_path = sse_decode_String(deserializer);
+    var var_screenShotPath = sse_decode_opt_String(deserializer);
+    var var_repo = sse_decode_opt_String(deserializer);
+    var var_branch = sse_decode_opt_String(deserializer);
+    var var_appId = sse_decode_opt_String(deserializer);
+    var var_exePath = sse_decode_String(deserializer);
+    var var_runtime = sse_decode_opt_String(deserializer);
+    var var_package = sse_decode_opt_String(deserializer);
+    var var_version = sse_decode_opt_String(deserializer);
+    var var_process = sse_decode_opt_box_autoadd_process_info(deserializer);
+    var var_monitor = sse_decode_opt_String(deserializer);
+    var var_workspace = sse_decode_opt_box_autoadd_workspace(deserializer);
+    var var_state = sse_decode_opt_box_autoadd_window_state(deserializer);
+    var var_geometry = sse_decode_opt_box_autoadd_rect(deserializer);
+    return Application(
+      icon: var_icon,
+      name: var_name,
+      title: var_title,
+      path: var_path,
+      screenShotPath: var_screenShotPath,
+      repo: var_repo,
+      branch: var_branch,
+      appId: var_appId,
+      exePath: var_exePath,
+      runtime: var_runtime,
+      package: var_package,
+      version: var_version,
+      process: var_process,
+      monitor: var_monitor,
+      workspace: var_workspace,
+      state: var_state,
+      geometry: var_geometry,
+    );
+  }
+
+  @protected
+  bool sse_decode_bool(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8() != 0;
+  }
+
+  @protected
+  ActivityLevel sse_decode_box_autoadd_activity_level(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_activity_level(deserializer));
+  }
+
+  @protected
+  AppCategory sse_decode_box_autoadd_app_category(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_app_category(deserializer));
+  }
+
+  @protected
+  Application sse_decode_box_autoadd_application(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_application(deserializer));
+  }
+
+  @protected
+  EventFilter sse_decode_box_autoadd_event_filter(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_event_filter(deserializer));
+  }
+
+  @protected
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_i_64(deserializer));
+  }
+
+  @protected
+  ProcessInfo sse_decode_box_autoadd_process_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_process_info(deserializer));
+  }
+
+  @protected
+  Rect sse_decode_box_autoadd_rect(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_rect(deserializer));
+  }
+
+  @protected
+  SessionSummary sse_decode_box_autoadd_session_summary(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_session_summary(deserializer));
+  }
+
+  @protected
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_32(deserializer));
+  }
+
+  @protected
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_u_64(deserializer));
+  }
+
+  @protected
+  WindowState sse_decode_box_autoadd_window_state(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_window_state(deserializer));
+  }
+
+  @protected
+  Workspace sse_decode_box_autoadd_workspace(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return (sse_decode_workspace(deserializer));
+  }
+
+  @protected
+  CaptureMode sse_decode_capture_mode(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return CaptureMode.values[inner];
+  }
+
+  @protected
+  CategoryTime sse_decode_category_time(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_category = sse_decode_app_category(deserializer);
+    var var_seconds = sse_decode_f_64(deserializer);
+    return CategoryTime(category: var_category, seconds: var_seconds);
+  }
+
+  @protected
+  CodingActivity sse_decode_coding_activity(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_appName = sse_decode_opt_String(deserializer);
+    var var_project = sse_decode_opt_String(deserializer);
+    var var_branch = sse_decode_opt_String(deserializer);
+    var var_entity = sse_decode_String(deserializer);
+    var var_language = sse_decode_opt_String(deserializer);
+    var var_seconds = sse_decode_f_64(deserializer);
+    return CodingActivity(
+      appName: var_appName,
+      project: var_project,
+      branch: var_branch,
+      entity: var_entity,
+      language: var_language,
+      seconds: var_seconds,
+    );
+  }
+
+  @protected
+  EventFilter sse_decode_event_filter(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_kinds = sse_decode_list_tracker_event_kind(deserializer);
+    var var_apps = sse_decode_list_String(deserializer);
+    return EventFilter(kinds: var_kinds, apps: var_apps);
+  }
+
+  @protected
+  double sse_decode_f_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat32();
+  }
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getFloat64();
+  }
+
+  @protected
+  FocusMetrics sse_decode_focus_metrics(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_seconds = sse_decode_f_64(deserializer);
+    var var_activeSeconds = sse_decode_f_64(deserializer);
+    var var_idleSeconds = sse_decode_f_64(deserializer);
+    var var_input = sse_decode_input_counts(deserializer);
+    var var_level = sse_decode_activity_level(deserializer);
+    return FocusMetrics(
+      name: var_name,
+      seconds: var_seconds,
+      activeSeconds: var_activeSeconds,
+      idleSeconds: var_idleSeconds,
+      input: var_input,
+      level: var_level,
+    );
+  }
+
+  @protected
+  FullscreenTime sse_decode_fullscreen_time(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_fullscreenSeconds = sse_decode_f_64(deserializer);
+    var var_seconds = sse_decode_f_64(deserializer);
+    var var_immersive = sse_decode_bool(deserializer);
+    return FullscreenTime(
+      name: var_name,
+      fullscreenSeconds: var_fullscreenSeconds,
+      seconds: var_seconds,
+      immersive: var_immersive,
+    );
+  }
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getInt32();
+  }
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getPlatformInt64();
+  }
+
+  @protected
+  InputCounts sse_decode_input_counts(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_keystrokes = sse_decode_u_32(deserializer);
+    var var_clicks = sse_decode_u_32(deserializer);
+    var var_scrolls = sse_decode_u_32(deserializer);
+    var var_mouseDistance = sse_decode_u_32(deserializer);
+    return InputCounts(
+      keystrokes: var_keystrokes,
+      clicks: var_clicks,
+      scrolls: var_scrolls,
+      mouseDistance: var_mouseDistance,
+    );
+  }
+
+  @protected
+  InstalledApp sse_decode_installed_app(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_exec = sse_decode_opt_String(deserializer);
+    var var_icon = sse_decode_opt_String(deserializer);
+    var var_categories = sse_decode_list_String(deserializer);
+    var var_suggested = sse_decode_app_category(deserializer);
+    var var_source = sse_decode_opt_String(deserializer);
+    return InstalledApp(
+      id: var_id,
+      name: var_name,
+      exec: var_exec,
+      icon: var_icon,
+      categories: var_categories,
+      suggested: var_suggested,
+      source: var_source,
+    );
+  }
+
+  @protected
+  IssueTime sse_decode_issue_time(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_key = sse_decode_String(deserializer);
+    var var_apps = sse_decode_list_String(deserializer);
+    var var_seconds = sse_decode_f_64(deserializer);
+    return IssueTime(key: var_key, apps: var_apps, seconds: var_seconds);
+  }
+
+  @protected
+  JournalRecovery sse_decode_journal_recovery(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_records = sse_decode_u_32(deserializer);
+    var var_truncatedBytes = sse_decode_u_64(deserializer);
+    var var_sessions = sse_decode_u_32(deserializer);
+    return JournalRecovery(
+      records: var_records,
+      truncatedBytes: var_truncatedBytes,
+      sessions: var_sessions,
+    );
+  }
+
+  @protected
+  LifecycleKind sse_decode_lifecycle_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return LifecycleKind.values[inner];
+  }
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <String>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_String(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AppLifecycleEvent> sse_decode_list_app_lifecycle_event(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AppLifecycleEvent>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_app_lifecycle_event(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AppResourceUsage> sse_decode_list_app_resource_usage(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AppResourceUsage>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_app_resource_usage(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<AppRunningTime> sse_decode_list_app_running_time(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <AppRunningTime>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_app_running_time(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Application> sse_decode_list_application(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Application>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_application(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CategoryTime> sse_decode_list_category_time(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CategoryTime>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_category_time(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<CodingActivity> sse_decode_list_coding_activity(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <CodingActivity>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_coding_activity(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FocusMetrics> sse_decode_list_focus_metrics(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FocusMetrics>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_focus_metrics(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<FullscreenTime> sse_decode_list_fullscreen_time(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <FullscreenTime>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_fullscreen_time(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<InstalledApp> sse_decode_list_installed_app(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <InstalledApp>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_installed_app(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<IssueTime> sse_decode_list_issue_time(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <IssueTime>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_issue_time(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MediaActivity> sse_decode_list_media_activity(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MediaActivity>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_media_activity(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MediaState> sse_decode_list_media_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MediaState>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_media_state(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<MediaTime> sse_decode_list_media_time(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <MediaTime>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_media_time(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Meeting> sse_decode_list_meeting(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Meeting>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_meeting(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<Monitor> sse_decode_list_monitor(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <Monitor>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_monitor(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<ParentProcess> sse_decode_list_parent_process(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <ParentProcess>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_parent_process(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var len_ = sse_decode_i_32(deserializer);
+    return deserializer.buffer.getUint8List(len_);
+  }
+
+  @protected
+  List<(String, AppCategory)> sse_decode_list_record_string_app_category(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <(String, AppCategory)>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_record_string_app_category(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<RepositoryTime> sse_decode_list_repository_time(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <RepositoryTime>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_repository_time(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TerminalActivity> sse_decode_list_terminal_activity(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TerminalActivity>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_terminal_activity(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<TrackerEventKind> sse_decode_list_tracker_event_kind(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <TrackerEventKind>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_tracker_event_kind(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<VisibleTime> sse_decode_list_visible_time(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <VisibleTime>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_visible_time(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<WindowInfo> sse_decode_list_window_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <WindowInfo>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_window_info(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<WorkspaceRule> sse_decode_list_workspace_rule(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <WorkspaceRule>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_workspace_rule(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  List<WorkspaceTime> sse_decode_list_workspace_time(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    var len_ = sse_decode_i_32(deserializer);
+    var ans_ = <WorkspaceTime>[];
+    for (var idx_ = 0; idx_ < len_; ++idx_) {
+      ans_.add(sse_decode_workspace_time(deserializer));
+    }
+    return ans_;
+  }
+
+  @protected
+  MediaActivity sse_decode_media_activity(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_player = sse_decode_String(deserializer);
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_artist = sse_decode_opt_String(deserializer);
+    var var_start = sse_decode_i_64(deserializer);
+    var var_end = sse_decode_i_64(deserializer);
+    return MediaActivity(
+      player: var_player,
+      title: var_title,
+      artist: var_artist,
+      start: var_start,
+      end: var_end,
+    );
+  }
+
+  @protected
+  MediaState sse_decode_media_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_player = sse_decode_String(deserializer);
+    var var_status = sse_decode_playback_status(deserializer);
+    var var_title = sse_decode_opt_String(deserializer);
+    var var_artist = sse_decode_opt_String(deserializer);
+    var var_album = sse_decode_opt_String(deserializer);
+    var var_url = sse_decode_opt_String(deserializer);
+    return MediaState(
+      player: var_player,
+      status: var_status,
+      title: var_title,
+      artist: var_artist,
+      album: var_album,
+      url: var_url,
+    );
+  }
+
+  @protected
+  MediaTime sse_decode_media_time(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_player = sse_decode_String(deserializer);
+    var var_seconds = sse_decode_f_64(deserializer);
+    return MediaTime(player: var_player, seconds: var_seconds);
+  }
+
+  @protected
+  Meeting sse_decode_meeting(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_u_64(deserializer);
+    var var_app = sse_decode_opt_String(deserializer);
+    var var_start = sse_decode_i_64(deserializer);
+    var var_end = sse_decode_i_64(deserializer);
+    var var_camera = sse_decode_bool(deserializer);
+    var var_screenShare = sse_decode_bool(deserializer);
+    var var_ongoing = sse_decode_bool(deserializer);
+    return Meeting(
+      id: var_id,
+      app: var_app,
+      start: var_start,
+      end: var_end,
+      camera: var_camera,
+      screenShare: var_screenShare,
+      ongoing: var_ongoing,
+    );
+  }
+
+  @protected
+  Monitor sse_decode_monitor(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_x = sse_decode_i_32(deserializer);
+    var var_y = sse_decode_i_32(deserializer);
+    var var_width = sse_decode_i_32(deserializer);
+    var var_height = sse_decode_i_32(deserializer);
+    var var_primary = sse_decode_bool(deserializer);
+    return Monitor(
+      name: var_name,
+      x: var_x,
+      y: var_y,
+      width: var_width,
+      height: var_height,
+      primary: var_primary,
+    );
+  }
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_String(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ActivityLevel? sse_decode_opt_box_autoadd_activity_level(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_activity_level(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  AppCategory? sse_decode_opt_box_autoadd_app_category(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_app_category(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Application? sse_decode_opt_box_autoadd_application(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_application(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_i_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ProcessInfo? sse_decode_opt_box_autoadd_process_info(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_process_info(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Rect? sse_decode_opt_box_autoadd_rect(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_rect(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  SessionSummary? sse_decode_opt_box_autoadd_session_summary(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_session_summary(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_32(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_u_64(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  WindowState? sse_decode_opt_box_autoadd_window_state(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_window_state(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Workspace? sse_decode_opt_box_autoadd_workspace(
+    SseDeserializer deserializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_box_autoadd_workspace(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    if (sse_decode_bool(deserializer)) {
+      return (sse_decode_list_prim_u_8_strict(deserializer));
+    } else {
+      return null;
+    }
+  }
+
+  @protected
+  ParentProcess sse_decode_parent_process(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_pid = sse_decode_u_32(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    return ParentProcess(pid: var_pid, name: var_name);
+  }
+
+  @protected
+  PlaybackStatus sse_decode_playback_status(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return PlaybackStatus.values[inner];
+  }
+
+  @protected
+  ProcessInfo sse_decode_process_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_pid = sse_decode_u_32(deserializer);
+    var var_parents = sse_decode_list_parent_process(deserializer);
+    var var_cmdline = sse_decode_list_String(deserializer);
+    var var_startTime = sse_decode_u_64(deserializer);
+    var var_user = sse_decode_opt_String(deserializer);
+    return ProcessInfo(
+      pid: var_pid,
+      parents: var_parents,
+      cmdline: var_cmdline,
+      startTime: var_startTime,
+      user: var_user,
+    );
+  }
+
+  @protected (String, AppCategory)
+  sse_decode_record_string_app_category(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_field0 = sse_decode_String(deserializer);
+    var var_field1 = sse_decode_app_category(deserializer);
+    return (var_field0, var_field1);
+  }
+
+  @protected
+  Rect sse_decode_rect(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_x = sse_decode_i_32(deserializer);
+    var var_y = sse_decode_i_32(deserializer);
+    var var_width = sse_decode_i_32(deserializer);
+    var var_height = sse_decode_i_32(deserializer);
+    return Rect(x: var_x, y: var_y, width: var_width, height: var_height);
+  }
+
+  @protected
+  RepositoryTime sse_decode_repository_time(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_repo = sse_decode_String(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_branch = sse_decode_opt_String(deserializer);
+    var var_seconds = sse_decode_f_64(deserializer);
+    return RepositoryTime(
+      repo: var_repo,
+      name: var_name,
+      branch: var_branch,
+      seconds: var_seconds,
+    );
+  }
+
+  @protected
+  ResourceStats sse_decode_resource_stats(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_samples = sse_decode_u_32(deserializer);
+    var var_cpuAvg = sse_decode_f_32(deserializer);
+    var var_cpuMax = sse_decode_f_32(deserializer);
+    var var_memoryAvg = sse_decode_u_64(deserializer);
+    var var_memoryMax = sse_decode_u_64(deserializer);
+    var var_diskRead = sse_decode_u_64(deserializer);
+    var var_diskWrite = sse_decode_u_64(deserializer);
+    var var_threadsMax = sse_decode_u_32(deserializer);
+    return ResourceStats(
+      samples: var_samples,
+      cpuAvg: var_cpuAvg,
+      cpuMax: var_cpuMax,
+      memoryAvg: var_memoryAvg,
+      memoryMax: var_memoryMax,
+      diskRead: var_diskRead,
+      diskWrite: var_diskWrite,
+      threadsMax: var_threadsMax,
+    );
+  }
+
+  @protected
+  SessionSummary sse_decode_session_summary(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_id = sse_decode_u_64(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_title = sse_decode_String(deserializer);
+    var var_start = sse_decode_i_64(deserializer);
+    var var_end = sse_decode_i_64(deserializer);
+    var var_activity = sse_decode_opt_box_autoadd_activity_level(deserializer);
+    return SessionSummary(
+      id: var_id,
+      name: var_name,
+      title: var_title,
+      start: var_start,
+      end: var_end,
+      activity: var_activity,
+    );
+  }
+
+  @protected
+  TerminalActivity sse_decode_terminal_activity(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_appName = sse_decode_String(deserializer);
+    var var_command = sse_decode_opt_String(deserializer);
+    var var_cwd = sse_decode_String(deserializer);
+    var var_seconds = sse_decode_f_64(deserializer);
+    return TerminalActivity(
+      appName: var_appName,
+      command: var_command,
+      cwd: var_cwd,
+      seconds: var_seconds,
+    );
+  }
+
+  @protected
+  TrackerEvent sse_decode_tracker_event(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_schemaVersion = sse_decode_u_32(deserializer);
+    var var_seq = sse_decode_u_64(deserializer);
+    var var_kind = sse_decode_tracker_event_kind(deserializer);
+    var var_timestamp = sse_decode_i_64(deserializer);
+    var var_emittedAt = sse_decode_i_64(deserializer);
+    var var_app = sse_decode_opt_box_autoadd_application(deserializer);
+    var var_session = sse_decode_opt_box_autoadd_session_summary(deserializer);
+    var var_path = sse_decode_opt_String(deserializer);
+    var var_source = sse_decode_opt_String(deserializer);
+    var var_message = sse_decode_opt_String(deserializer);
+    var var_idleSeconds = sse_decode_opt_box_autoadd_i_64(deserializer);
+    var var_name = sse_decode_opt_String(deserializer);
+    var var_meetingId = sse_decode_opt_box_autoadd_u_64(deserializer);
+    var var_pid = sse_decode_opt_box_autoadd_u_32(deserializer);
+    var var_dropped = sse_decode_opt_box_autoadd_u_64(deserializer);
+    return TrackerEvent(
+      schemaVersion: var_schemaVersion,
+      seq: var_seq,
+      kind: var_kind,
+      timestamp: var_timestamp,
+      emittedAt: var_emittedAt,
+      app: var_app,
+      session: var_session,
+      path: var_path,
+      source: var_source,
+      message: var_message,
+      idleSeconds: var_idleSeconds,
+      name: var_name,
+      meetingId: var_meetingId,
+      pid: var_pid,
+      dropped: var_dropped,
+    );
+  }
+
+  @protected
+  TrackerEventKind sse_decode_tracker_event_kind(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return TrackerEventKind.values[inner];
+  }
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint16();
+  }
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint32();
+  }
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getBigUint64();
+  }
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    return deserializer.buffer.getUint8();
+  }
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+  }
+
+  @protected
+  VisibleTime sse_decode_visible_time(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_visibleSeconds = sse_decode_i_64(deserializer);
+    var var_focusedSeconds = sse_decode_i_64(deserializer);
+    return VisibleTime(
+      name: var_name,
+      visibleSeconds: var_visibleSeconds,
+      focusedSeconds: var_focusedSeconds,
+    );
+  }
+
+  @protected
+  WindowInfo sse_decode_window_info(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_windowId = sse_decode_u_64(deserializer);
+    var var_focused = sse_decode_bool(deserializer);
+    var var_app = sse_decode_application(deserializer);
+    return WindowInfo(
+      windowId: var_windowId,
+      focused: var_focused,
+      app: var_app,
+    );
+  }
+
+  @protected
+  WindowState sse_decode_window_state(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_fullscreen = sse_decode_bool(deserializer);
+    var var_maximized = sse_decode_bool(deserializer);
+    var var_minimized = sse_decode_bool(deserializer);
+    var var_above = sse_decode_bool(deserializer);
+    return WindowState(
+      fullscreen: var_fullscreen,
+      maximized: var_maximized,
+      minimized: var_minimized,
+      above: var_above,
+    );
+  }
+
+  @protected
+  WorklogFormat sse_decode_worklog_format(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var inner = sse_decode_i_32(deserializer);
+    return WorklogFormat.values[inner];
+  }
+
+  @protected
+  Workspace sse_decode_workspace(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_index = sse_decode_u_32(deserializer);
+    var var_name = sse_decode_String(deserializer);
+    var var_category = sse_decode_opt_box_autoadd_app_category(deserializer);
+    var var_project = sse_decode_opt_String(deserializer);
+    return Workspace(
+      index: var_index,
+      name: var_name,
+      category: var_category,
+      project: var_project,
+    );
+  }
+
+  @protected
+  WorkspaceRule sse_decode_workspace_rule(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_pattern = sse_decode_String(deserializer);
+    var var_category = sse_decode_opt_box_autoadd_app_category(deserializer);
+    var var_project = sse_decode_opt_String(deserializer);
+    return WorkspaceRule(
+      pattern: var_pattern,
+      category: var_category,
+      project: var_project,
+    );
+  }
+
+  @protected
+  WorkspaceTime sse_decode_workspace_time(SseDeserializer deserializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    var var_name = sse_decode_String(deserializer);
+    var var_project = sse_decode_opt_String(deserializer);
+    var var_seconds = sse_decode_f_64(deserializer);
+    return WorkspaceTime(
+      name: var_name,
+      project: var_project,
+      seconds: var_seconds,
+    );
+  }
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.message, serializer);
+  }
+
+  @protected
+  void sse_encode_Map_String_app_category_None(
+    Map<String,
+    AppCategory> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_record_string_app_category(
+      self.entries.map((e) => (e.key, e.value)).toList(),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_application_Sse(
+    RustStreamSink<Application> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_application,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_StreamSink_tracker_event_Sse(
+    RustStreamSink<TrackerEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(
+      self.setupAndSerialize(
+        codec: SseCodec(
+          decodeSuccessData: sse_decode_tracker_event,
+          decodeErrorData: sse_decode_AnyhowException,
+        ),
+      ),
+      serializer,
+    );
+  }
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+  }
+
+  @protected
+  void sse_encode_activity_level(ActivityLevel self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_app_category(AppCategory self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_app_lifecycle_event(
+    AppLifecycleEvent self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_lifecycle_kind(self.kind, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_u_32(self.pid, serializer);
+    sse_encode_i_64(self.timestamp, serializer);
+  }
+
+  @protected
+  void sse_encode_app_resource_usage(
+    AppResourceUsage self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_resource_stats(self.foreground, serializer);
+    sse_encode_resource_stats(self.background, serializer);
+  }
+
+  @protected
+  void sse_encode_app_running_time(
+    AppRunningTime self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_i_64(self.runningSeconds, serializer);
+    sse_encode_i_64(self.focusedSeconds, serializer);
+  }
+
+  @protected
+  void sse_encode_application(Application self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.icon, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_String(self.title, serializer);
+    sse_encode_String(self.path, serializer);
+    sse_encode_opt_String(self.screenShotPath, serializer);
+    sse_encode_opt_String(self.repo, serializer);
+    sse_encode_opt_String(self.branch, serializer);
+    sse_encode_opt_String(self.appId, serializer);
+    sse_encode_String(self.exePath, serializer);
+    sse_encode_opt_String(self.runtime, serializer);
+    sse_encode_opt_String(self.package, serializer);
+    sse_encode_opt_String(self.version, serializer);
+    sse_encode_opt_box_autoadd_process_info(self.process, serializer);
+    sse_encode_opt_String(self.monitor, serializer);
+    sse_encode_opt_box_autoadd_workspace(self.workspace, serializer);
+    sse_encode_opt_box_autoadd_window_state(self.state, serializer);
+    sse_encode_opt_box_autoadd_rect(self.geometry, serializer);
+  }
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint8(self ? 1 : 0);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_activity_level(
+    ActivityLevel self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_activity_level(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_app_category(
+    AppCategory self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_app_category(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_application(
+    Application self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_application(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_event_filter(
+    EventFilter self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_event_filter(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_process_info(
+    ProcessInfo self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_process_info(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_rect(Rect self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_rect(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_session_summary(
+    SessionSummary self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_session_summary(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_window_state(
+    WindowState self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_window_state(self, serializer);
+  }
+
+  @protected
+  void sse_encode_box_autoadd_workspace(
+    Workspace self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_workspace(self, serializer);
+  }
+
+  @protected
+  void sse_encode_capture_mode(CaptureMode self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_category_time(CategoryTime self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_app_category(self.category, serializer);
+    sse_encode_f_64(self.seconds, serializer);
+  }
+
+  @protected
+  void sse_encode_coding_activity(
+    CodingActivity self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_opt_String(self.appName, serializer);
+    sse_encode_opt_String(self.project, serializer);
+    sse_encode_opt_String(self.branch, serializer);
+    sse_encode_String(self.entity, serializer);
+    sse_encode_opt_String(self.language, serializer);
+    sse_encode_f_64(self.seconds, serializer);
+  }
+
+  @protected
+  void sse_encode_event_filter(EventFilter self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_list_tracker_event_kind(self.kinds, serializer);
+    sse_encode_list_String(self.apps, serializer);
+  }
+
+  @protected
+  void sse_encode_f_32(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat32(self);
+  }
+
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putFloat64(self);
+  }
+
+  @protected
+  void sse_encode_focus_metrics(FocusMetrics self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_f_64(self.seconds, serializer);
+    sse_encode_f_64(self.activeSeconds, serializer);
+    sse_encode_f_64(self.idleSeconds, serializer);
+    sse_encode_input_counts(self.input, serializer);
+    sse_encode_activity_level(self.level, serializer);
+  }
+
+  @protected
+  void sse_encode_fullscreen_time(
+    FullscreenTime self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_f_64(self.fullscreenSeconds, serializer);
+    sse_encode_f_64(self.seconds, serializer);
+    sse_encode_bool(self.immersive, serializer);
+  }
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putInt32(self);
+  }
+
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putPlatformInt64(self);
+  }
+
+  @protected
+  void sse_encode_input_counts(InputCounts self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.keystrokes, serializer);
+    sse_encode_u_32(self.clicks, serializer);
+    sse_encode_u_32(self.scrolls, serializer);
+    sse_encode_u_32(self.mouseDistance, serializer);
+  }
+
+  @protected
+  void sse_encode_installed_app(InstalledApp self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.id, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_String(self.exec, serializer);
+    sse_encode_opt_String(self.icon, serializer);
+    sse_encode_list_String(self.categories, serializer);
+    sse_encode_app_category(self.suggested, serializer);
+    sse_encode_opt_String(self.source, serializer);
+  }
+
+  @protected
+  void sse_encode_issue_time(IssueTime self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.key, serializer);
+    sse_encode_list_String(self.apps, serializer);
+    sse_encode_f_64(self.seconds, serializer);
+  }
+
+  @protected
+  void sse_encode_journal_recovery(
+    JournalRecovery self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.records, serializer);
+    sse_encode_u_64(self.truncatedBytes, serializer);
+    sse_encode_u_32(self.sessions, serializer);
+  }
+
+  @protected
+  void sse_encode_lifecycle_kind(LifecycleKind self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_String(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_app_lifecycle_event(
+    List<AppLifecycleEvent> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_app_lifecycle_event(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_app_resource_usage(
+    List<AppResourceUsage> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_app_resource_usage(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_app_running_time(
+    List<AppRunningTime> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_app_running_time(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_application(
+    List<Application> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_application(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_category_time(
+    List<CategoryTime> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_category_time(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_coding_activity(
+    List<CodingActivity> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_coding_activity(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_focus_metrics(
+    List<FocusMetrics> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_focus_metrics(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_fullscreen_time(
+    List<FullscreenTime> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_fullscreen_time(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_installed_app(
+    List<InstalledApp> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_installed_app(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_issue_time(
+    List<IssueTime> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_issue_time(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_media_activity(
+    List<MediaActivity> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_media_activity(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_media_state(
+    List<MediaState> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_media_state(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_media_time(
+    List<MediaTime> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_media_time(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_meeting(List<Meeting> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_meeting(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_monitor(List<Monitor> self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_monitor(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_parent_process(
+    List<ParentProcess> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_parent_process(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    serializer.buffer.putUint8List(self);
+  }
+
+  @protected
+  void sse_encode_list_record_string_app_category(
+    List<(String, AppCategory)> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_record_string_app_category(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_repository_time(
+    List<RepositoryTime> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_repository_time(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_terminal_activity(
+    List<TerminalActivity> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_terminal_activity(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_tracker_event_kind(
+    List<TrackerEventKind> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_tracker_event_kind(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_visible_time(
+    List<VisibleTime> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_visible_time(item, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_list_window_info(
+    List<WindowInfo> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_window_info(item, serializer);
+    }
+  }
 
   @protected
-  String? dco_decode_opt_String(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
+  void sse_encode_list_workspace_rule(
+    List<WorkspaceRule> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_workspace_rule(item, serializer);
+    }
   }
 
   @protected
-  int dco_decode_u_8(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
+  void sse_encode_list_workspace_time(
+    List<WorkspaceTime> self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.length, serializer);
+    for (final item in self) {
+      sse_encode_workspace_time(item, serializer);
+    }
   }
 
   @protected
-  void dco_decode_unit(dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
+  void sse_encode_media_activity(MediaActivity self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.player, serializer);
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_opt_String(self.artist, serializer);
+    sse_encode_i_64(self.start, serializer);
+    sse_encode_i_64(self.end, serializer);
   }
 
   @protected
-  AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer) {
+  void sse_encode_media_state(MediaState self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_String(deserializer);
-    return AnyhowException(inner);
+    sse_encode_String(self.player, serializer);
+    sse_encode_playback_status(self.status, serializer);
+    sse_encode_opt_String(self.title, serializer);
+    sse_encode_opt_String(self.artist, serializer);
+    sse_encode_opt_String(self.album, serializer);
+    sse_encode_opt_String(self.url, serializer);
   }
 
   @protected
-  RustStreamSink<Application> sse_decode_StreamSink_application_Sse(
-    SseDeserializer deserializer,
+  void sse_encode_media_time(MediaTime self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.player, serializer);
+    sse_encode_f_64(self.seconds, serializer);
+  }
+
+  @protected
+  void sse_encode_meeting(Meeting self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.id, serializer);
+    sse_encode_opt_String(self.app, serializer);
+    sse_encode_i_64(self.start, serializer);
+    sse_encode_i_64(self.end, serializer);
+    sse_encode_bool(self.camera, serializer);
+    sse_encode_bool(self.screenShare, serializer);
+    sse_encode_bool(self.ongoing, serializer);
+  }
+
+  @protected
+  void sse_encode_monitor(Monitor self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_i_32(self.x, serializer);
+    sse_encode_i_32(self.y, serializer);
+    sse_encode_i_32(self.width, serializer);
+    sse_encode_i_32(self.height, serializer);
+    sse_encode_bool(self.primary, serializer);
+  }
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_String(self, serializer);
+    }
+  }
+
+  @protected
+  void sse_encode_opt_box_autoadd_activity_level(
+    ActivityLevel? self,
+    SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    throw UnimplementedError('Unreachable ()');
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_activity_level(self, serializer);
+    }
   }
 
   @protected
-  String sse_decode_String(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_app_category(
+    AppCategory? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_app_category(self, serializer);
+    }
   }
 
   @protected
-  Application sse_decode_application(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_application(
+    Application? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var var_icon = sse_decode_opt_String(deserializer);
-    var var_name = sse_decode_String(deserializer);
-    var var_title = sse_decode_String(deserializer);
-    var var_path = sse_decode_String(deserializer);
-    var var_screenShotPath = sse_decode_opt_String(deserializer);
-    return Application(
-      icon: var_icon,
-      name: var_name,
-      title: var_title,
-      path: var_path,
-      screenShotPath: var_screenShotPath,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_application(self, serializer);
+    }
   }
 
   @protected
-  bool sse_decode_bool(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_i_64(self, serializer);
+    }
   }
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_process_info(
+    ProcessInfo? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    var len_ = sse_decode_i_32(deserializer);
-    var ans_ = <String>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_String(deserializer));
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_process_info(self, serializer);
     }
-    return ans_;
   }
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_rect(Rect? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    var len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_rect(self, serializer);
+    }
   }
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_session_summary(
+    SessionSummary? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
 
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_session_summary(self, serializer);
     }
   }
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_32(self, serializer);
+    }
   }
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_u_64(self, serializer);
+    }
   }
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer) {
+  void sse_encode_opt_box_autoadd_window_state(
+    WindowState? self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_window_state(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
+  void sse_encode_opt_box_autoadd_workspace(
+    Workspace? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.message, serializer);
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_box_autoadd_workspace(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_StreamSink_application_Sse(
-    RustStreamSink<Application> self,
+  void sse_encode_opt_list_prim_u_8_strict(
+    Uint8List? self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(
-      self.setupAndSerialize(
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_application,
-          decodeErrorData: sse_decode_AnyhowException,
-        ),
-      ),
-      serializer,
-    );
+
+    sse_encode_bool(self != null, serializer);
+    if (self != null) {
+      sse_encode_list_prim_u_8_strict(self, serializer);
+    }
   }
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer) {
+  void sse_encode_parent_process(ParentProcess self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
+    sse_encode_u_32(self.pid, serializer);
+    sse_encode_String(self.name, serializer);
   }
 
   @protected
-  void sse_encode_application(Application self, SseSerializer serializer) {
+  void sse_encode_playback_status(
+    PlaybackStatus self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_opt_String(self.icon, serializer);
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_process_info(ProcessInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.pid, serializer);
+    sse_encode_list_parent_process(self.parents, serializer);
+    sse_encode_list_String(self.cmdline, serializer);
+    sse_encode_u_64(self.startTime, serializer);
+    sse_encode_opt_String(self.user, serializer);
+  }
+
+  @protected
+  void sse_encode_record_string_app_category(
+    (String, AppCategory) self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.$1, serializer);
+    sse_encode_app_category(self.$2, serializer);
+  }
+
+  @protected
+  void sse_encode_rect(Rect self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.x, serializer);
+    sse_encode_i_32(self.y, serializer);
+    sse_encode_i_32(self.width, serializer);
+    sse_encode_i_32(self.height, serializer);
+  }
+
+  @protected
+  void sse_encode_repository_time(
+    RepositoryTime self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.repo, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_String(self.branch, serializer);
+    sse_encode_f_64(self.seconds, serializer);
+  }
+
+  @protected
+  void sse_encode_resource_stats(ResourceStats self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.samples, serializer);
+    sse_encode_f_32(self.cpuAvg, serializer);
+    sse_encode_f_32(self.cpuMax, serializer);
+    sse_encode_u_64(self.memoryAvg, serializer);
+    sse_encode_u_64(self.memoryMax, serializer);
+    sse_encode_u_64(self.diskRead, serializer);
+    sse_encode_u_64(self.diskWrite, serializer);
+    sse_encode_u_32(self.threadsMax, serializer);
+  }
+
+  @protected
+  void sse_encode_session_summary(
+    SessionSummary self,
+    SseSerializer serializer,
+  ) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.id, serializer);
     sse_encode_String(self.name, serializer);
     sse_encode_String(self.title, serializer);
-    sse_encode_String(self.path, serializer);
-    sse_encode_opt_String(self.screenShotPath, serializer);
+    sse_encode_i_64(self.start, serializer);
+    sse_encode_i_64(self.end, serializer);
+    sse_encode_opt_box_autoadd_activity_level(self.activity, serializer);
   }
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer) {
+  void sse_encode_terminal_activity(
+    TerminalActivity self,
+    SseSerializer serializer,
+  ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
+    sse_encode_String(self.appName, serializer);
+    sse_encode_opt_String(self.command, serializer);
+    sse_encode_String(self.cwd, serializer);
+    sse_encode_f_64(self.seconds, serializer);
   }
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer) {
+  void sse_encode_tracker_event(TrackerEvent self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_String(item, serializer);
-    }
+    sse_encode_u_32(self.schemaVersion, serializer);
+    sse_encode_u_64(self.seq, serializer);
+    sse_encode_tracker_event_kind(self.kind, serializer);
+    sse_encode_i_64(self.timestamp, serializer);
+    sse_encode_i_64(self.emittedAt, serializer);
+    sse_encode_opt_box_autoadd_application(self.app, serializer);
+    sse_encode_opt_box_autoadd_session_summary(self.session, serializer);
+    sse_encode_opt_String(self.path, serializer);
+    sse_encode_opt_String(self.source, serializer);
+    sse_encode_opt_String(self.message, serializer);
+    sse_encode_opt_box_autoadd_i_64(self.idleSeconds, serializer);
+    sse_encode_opt_String(self.name, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.meetingId, serializer);
+    sse_encode_opt_box_autoadd_u_32(self.pid, serializer);
+    sse_encode_opt_box_autoadd_u_64(self.dropped, serializer);
   }
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
+  void sse_encode_tracker_event_kind(
+    TrackerEventKind self,
     SseSerializer serializer,
   ) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
+    sse_encode_i_32(self.index, serializer);
   }
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer) {
+  void sse_encode_u_16(int self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint16(self);
+  }
 
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putUint32(self);
+  }
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    serializer.buffer.putBigUint64(self);
   }
 
   @protected
@@ -572,8 +5041,58 @@ class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
   }
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer) {
+  void sse_encode_visible_time(VisibleTime self, SseSerializer serializer) {
     // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
+    sse_encode_String(self.name, serializer);
+    sse_encode_i_64(self.visibleSeconds, serializer);
+    sse_encode_i_64(self.focusedSeconds, serializer);
+  }
+
+  @protected
+  void sse_encode_window_info(WindowInfo self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_64(self.windowId, serializer);
+    sse_encode_bool(self.focused, serializer);
+    sse_encode_application(self.app, serializer);
+  }
+
+  @protected
+  void sse_encode_window_state(WindowState self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_bool(self.fullscreen, serializer);
+    sse_encode_bool(self.maximized, serializer);
+    sse_encode_bool(self.minimized, serializer);
+    sse_encode_bool(self.above, serializer);
+  }
+
+  @protected
+  void sse_encode_worklog_format(WorklogFormat self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_i_32(self.index, serializer);
+  }
+
+  @protected
+  void sse_encode_workspace(Workspace self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_u_32(self.index, serializer);
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_box_autoadd_app_category(self.category, serializer);
+    sse_encode_opt_String(self.project, serializer);
+  }
+
+  @protected
+  void sse_encode_workspace_rule(WorkspaceRule self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.pattern, serializer);
+    sse_encode_opt_box_autoadd_app_category(self.category, serializer);
+    sse_encode_opt_String(self.project, serializer);
+  }
+
+  @protected
+  void sse_encode_workspace_time(WorkspaceTime self, SseSerializer serializer) {
+    // Codec=Sse (Serialization based), see doc to use other codecs
+    sse_encode_String(self.name, serializer);
+    sse_encode_opt_String(self.project, serializer);
+    sse_encode_f_64(self.seconds, serializer);
   }
 }
@@ -12,7 +12,25 @@ import 'dart:async';
 import 'dart:convert';
 import 'frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
+import 'spy/bus.dart';
+import 'spy/catalog.dart';
+import 'spy/event.dart';
+import 'spy/git.dart';
+import 'spy/heartbeat.dart';
+import 'spy/input.dart';
+import 'spy/issue.dart';
+import 'spy/journal.dart';
+import 'spy/lifecycle.dart';
+import 'spy/media.dart';
+import 'spy/meeting.dart';
 import 'spy/model.dart';
+import 'spy/monitor.dart';
+import 'spy/process.dart';
+import 'spy/resource.dart';
+import 'spy/shell.dart';
+import 'spy/visibility.dart';
+import 'spy/window_state.dart';
+import 'spy/workspace.dart';
 
 abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   RustLibApiImplPlatform({
@@ -25,46 +43,365 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   @protected
   AnyhowException dco_decode_AnyhowException(dynamic raw);
 
+  @protected
+  Map<String, AppCategory> dco_decode_Map_String_app_category_None(dynamic raw);
+
   @protected
   RustStreamSink<Application> dco_decode_StreamSink_application_Sse(
     dynamic raw,
   );
 
+  @protected
+  RustStreamSink<TrackerEvent> dco_decode_StreamSink_tracker_event_Sse(
+    dynamic raw,
+  );
+
   @protected
   String dco_decode_String(dynamic raw);
 
+  @protected
+  ActivityLevel dco_decode_activity_level(dynamic raw);
+
+  @protected
+  AppCategory dco_decode_app_category(dynamic raw);
+
+  @protected
+  AppLifecycleEvent dco_decode_app_lifecycle_event(dynamic raw);
+
+  @protected
+  AppResourceUsage dco_decode_app_resource_usage(dynamic raw);
+
+  @protected
+  AppRunningTime dco_decode_app_running_time(dynamic raw);
+
   @protected
   Application dco_decode_application(dynamic raw);
 
   @protected
   bool dco_decode_bool(dynamic raw);
 
+  @protected
+  ActivityLevel dco_decode_box_autoadd_activity_level(dynamic raw);
+
+  @protected
+  AppCategory dco_decode_box_autoadd_app_category(dynamic raw);
+
+  @protected
+  Application dco_decode_box_autoadd_application(dynamic raw);
+
+  @protected
+  EventFilter dco_decode_box_autoadd_event_filter(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  ProcessInfo dco_decode_box_autoadd_process_info(dynamic raw);
+
+  @protected
+  Rect dco_decode_box_autoadd_rect(dynamic raw);
+
+  @protected
+  SessionSummary dco_decode_box_autoadd_session_summary(dynamic raw);
+
+  @protected
+  int dco_decode_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  WindowState dco_decode_box_autoadd_window_state(dynamic raw);
+
+  @protected
+  Workspace dco_decode_box_autoadd_workspace(dynamic raw);
+
+  @protected
+  CaptureMode dco_decode_capture_mode(dynamic raw);
+
+  @protected
+  CategoryTime dco_decode_category_time(dynamic raw);
+
+  @protected
+  CodingActivity dco_decode_coding_activity(dynamic raw);
+
+  @protected
+  EventFilter dco_decode_event_filter(dynamic raw);
+
+  @protected
+  double dco_decode_f_32(dynamic raw);
+
+  @protected
+  double dco_decode_f_64(dynamic raw);
+
+  @protected
+  FocusMetrics dco_decode_focus_metrics(dynamic raw);
+
+  @protected
+  FullscreenTime dco_decode_fullscreen_time(dynamic raw);
+
+  @protected
+  int dco_decode_i_32(dynamic raw);
+
+  @protected
+  PlatformInt64 dco_decode_i_64(dynamic raw);
+
+  @protected
+  InputCounts dco_decode_input_counts(dynamic raw);
+
+  @protected
+  InstalledApp dco_decode_installed_app(dynamic raw);
+
+  @protected
+  IssueTime dco_decode_issue_time(dynamic raw);
+
+  @protected
+  JournalRecovery dco_decode_journal_recovery(dynamic raw);
+
+  @protected
+  LifecycleKind dco_decode_lifecycle_kind(dynamic raw);
+
   @protected
   List<String> dco_decode_list_String(dynamic raw);
 
+  @protected
+  List<AppLifecycleEvent> dco_decode_list_app_lifecycle_event(dynamic raw);
+
+  @protected
+  List<AppResourceUsage> dco_decode_list_app_resource_usage(dynamic raw);
+
+  @protected
+  List<AppRunningTime> dco_decode_list_app_running_time(dynamic raw);
+
+  @protected
+  List<Application> dco_decode_list_application(dynamic raw);
+
+  @protected
+  List<CategoryTime> dco_decode_list_category_time(dynamic raw);
+
+  @protected
+  List<CodingActivity> dco_decode_list_coding_activity(dynamic raw);
+
+  @protected
+  List<FocusMetrics> dco_decode_list_focus_metrics(dynamic raw);
+
+  @protected
+  List<FullscreenTime> dco_decode_list_fullscreen_time(dynamic raw);
+
+  @protected
+  List<InstalledApp> dco_decode_list_installed_app(dynamic raw);
+
+  @protected
+  List<IssueTime> dco_decode_list_issue_time(dynamic raw);
+
+  @protected
+  List<MediaActivity> dco_decode_list_media_activity(dynamic raw);
+
+  @protected
+  List<MediaState> dco_decode_list_media_state(dynamic raw);
+
+  @protected
+  List<MediaTime> dco_decode_list_media_time(dynamic raw);
+
+  @protected
+  List<Meeting> dco_decode_list_meeting(dynamic raw);
+
+  @protected
+  List<Monitor> dco_decode_list_monitor(dynamic raw);
+
+  @protected
+  List<ParentProcess> dco_decode_list_parent_process(dynamic raw);
+
   @protected
   Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
 
+  @protected
+  List<(String, AppCategory)> dco_decode_list_record_string_app_category(
+    dynamic raw,
+  );
+
+  @protected
+  List<RepositoryTime> dco_decode_list_repository_time(dynamic raw);
+
+  @protected
+  List<TerminalActivity> dco_decode_list_terminal_activity(dynamic raw);
+
+  @protected
+  List<TrackerEventKind> dco_decode_list_tracker_event_kind(dynamic raw);
+
+  @protected
+  List<VisibleTime> dco_decode_list_visible_time(dynamic raw);
+
+  @protected
+  List<WindowInfo> dco_decode_list_window_info(dynamic raw);
+
+  @protected
+  List<WorkspaceRule> dco_decode_list_workspace_rule(dynamic raw);
+
+  @protected
+  List<WorkspaceTime> dco_decode_list_workspace_time(dynamic raw);
+
+  @protected
+  MediaActivity dco_decode_media_activity(dynamic raw);
+
+  @protected
+  MediaState dco_decode_media_state(dynamic raw);
+
+  @protected
+  MediaTime dco_decode_media_time(dynamic raw);
+
+  @protected
+  Meeting dco_decode_meeting(dynamic raw);
+
+  @protected
+  Monitor dco_decode_monitor(dynamic raw);
+
   @protected
   String? dco_decode_opt_String(dynamic raw);
 
+  @protected
+  ActivityLevel? dco_decode_opt_box_autoadd_activity_level(dynamic raw);
+
+  @protected
+  AppCategory? dco_decode_opt_box_autoadd_app_category(dynamic raw);
+
+  @protected
+  Application? dco_decode_opt_box_autoadd_application(dynamic raw);
+
+  @protected
+  PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+  @protected
+  ProcessInfo? dco_decode_opt_box_autoadd_process_info(dynamic raw);
+
+  @protected
+  Rect? dco_decode_opt_box_autoadd_rect(dynamic raw);
+
+  @protected
+  SessionSummary? dco_decode_opt_box_autoadd_session_summary(dynamic raw);
+
+  @protected
+  int? dco_decode_opt_box_autoadd_u_32(dynamic raw);
+
+  @protected
+  BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+  @protected
+  WindowState? dco_decode_opt_box_autoadd_window_state(dynamic raw);
+
+  @protected
+  Workspace? dco_decode_opt_box_autoadd_workspace(dynamic raw);
+
+  @protected
+  Uint8List? dco_decode_opt_list_prim_u_8_strict(dynamic raw);
+
+  @protected
+  ParentProcess dco_decode_parent_process(dynamic raw);
+
+  @protected
+  PlaybackStatus dco_decode_playback_status(dynamic raw);
+
+  @protected
+  ProcessInfo dco_decode_process_info(dynamic raw);
+
+  @protected (String, AppCategory)
+  dco_decode_record_string_app_category(dynamic raw);
+
+  @protected
+  Rect dco_decode_rect(dynamic raw);
+
+  @protected
+  RepositoryTime dco_decode_repository_time(dynamic raw);
+
+  @protected
+  ResourceStats dco_decode_resource_stats(dynamic raw);
+
+  @protected
+  SessionSummary dco_decode_session_summary(dynamic raw);
+
+  @protected
+  TerminalActivity dco_decode_terminal_activity(dynamic raw);
+
+  @protected
+  TrackerEvent dco_decode_tracker_event(dynamic raw);
+
+  @protected
+  TrackerEventKind dco_decode_tracker_event_kind(dynamic raw);
+
+  @protected
+  int dco_decode_u_16(dynamic raw);
+
+  @protected
+  int dco_decode_u_32(dynamic raw);
+
+  @protected
+  BigInt dco_decode_u_64(dynamic raw);
+
   @protected
   int dco_decode_u_8(dynamic raw);
 
   @protected
   void dco_decode_unit(dynamic raw);
 
+  @protected
+  VisibleTime dco_decode_visible_time(dynamic raw);
+
+  @protected
+  WindowInfo dco_decode_window_info(dynamic raw);
+
+  @protected
+  WindowState dco_decode_window_state(dynamic raw);
+
+  @protected
+  WorklogFormat dco_decode_worklog_format(dynamic raw);
+
+  @protected
+  Workspace dco_decode_workspace(dynamic raw);
+
+  @protected
+  WorkspaceRule dco_decode_workspace_rule(dynamic raw);
+
+  @protected
+  WorkspaceTime dco_decode_workspace_time(dynamic raw);
+
   @protected
   AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
 
+  @protected
+  Map<String, AppCategory> sse_decode_Map_String_app_category_None(
+    SseDeserializer deserializer,
+  );
+
   @protected
   RustStreamSink<Application> sse_decode_StreamSink_application_Sse(
     SseDeserializer deserializer,
   );
 
+  @protected
+  RustStreamSink<TrackerEvent> sse_decode_StreamSink_tracker_event_Sse(
+    SseDeserializer deserializer,
+  );
+
   @protected
   String sse_decode_String(SseDeserializer deserializer);
 
+  @protected
+  ActivityLevel sse_decode_activity_level(SseDeserializer deserializer);
+
+  @protected
+  AppCategory sse_decode_app_category(SseDeserializer deserializer);
+
+  @protected
+  AppLifecycleEvent sse_decode_app_lifecycle_event(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AppResourceUsage sse_decode_app_resource_usage(SseDeserializer deserializer);
+
+  @protected
+  AppRunningTime sse_decode_app_running_time(SseDeserializer deserializer);
+
   @protected
   Application sse_decode_application(SseDeserializer deserializer);
 
@@ -72,64 +409,824 @@ abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
   bool sse_decode_bool(SseDeserializer deserializer);
 
   @protected
-  List<String> sse_decode_list_String(SseDeserializer deserializer);
+  ActivityLevel sse_decode_box_autoadd_activity_level(
+    SseDeserializer deserializer,
+  );
 
   @protected
-  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+  AppCategory sse_decode_box_autoadd_app_category(SseDeserializer deserializer);
 
   @protected
-  String? sse_decode_opt_String(SseDeserializer deserializer);
+  Application sse_decode_box_autoadd_application(SseDeserializer deserializer);
 
   @protected
-  int sse_decode_u_8(SseDeserializer deserializer);
+  EventFilter sse_decode_box_autoadd_event_filter(SseDeserializer deserializer);
 
   @protected
-  void sse_decode_unit(SseDeserializer deserializer);
+  PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
 
   @protected
-  int sse_decode_i_32(SseDeserializer deserializer);
+  ProcessInfo sse_decode_box_autoadd_process_info(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_AnyhowException(
-    AnyhowException self,
-    SseSerializer serializer,
-  );
+  Rect sse_decode_box_autoadd_rect(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_StreamSink_application_Sse(
-    RustStreamSink<Application> self,
-    SseSerializer serializer,
+  SessionSummary sse_decode_box_autoadd_session_summary(
+    SseDeserializer deserializer,
   );
 
   @protected
-  void sse_encode_String(String self, SseSerializer serializer);
+  int sse_decode_box_autoadd_u_32(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_application(Application self, SseSerializer serializer);
+  BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_bool(bool self, SseSerializer serializer);
+  WindowState sse_decode_box_autoadd_window_state(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+  Workspace sse_decode_box_autoadd_workspace(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_list_prim_u_8_strict(
-    Uint8List self,
-    SseSerializer serializer,
-  );
+  CaptureMode sse_decode_capture_mode(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_opt_String(String? self, SseSerializer serializer);
+  CategoryTime sse_decode_category_time(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_u_8(int self, SseSerializer serializer);
+  CodingActivity sse_decode_coding_activity(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_unit(void self, SseSerializer serializer);
+  EventFilter sse_decode_event_filter(SseDeserializer deserializer);
 
   @protected
-  void sse_encode_i_32(int self, SseSerializer serializer);
+  double sse_decode_f_32(SseDeserializer deserializer);
+
+  @protected
+  double sse_decode_f_64(SseDeserializer deserializer);
+
+  @protected
+  FocusMetrics sse_decode_focus_metrics(SseDeserializer deserializer);
+
+  @protected
+  FullscreenTime sse_decode_fullscreen_time(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_i_32(SseDeserializer deserializer);
+
+  @protected
+  PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+  @protected
+  InputCounts sse_decode_input_counts(SseDeserializer deserializer);
+
+  @protected
+  InstalledApp sse_decode_installed_app(SseDeserializer deserializer);
+
+  @protected
+  IssueTime sse_decode_issue_time(SseDeserializer deserializer);
+
+  @protected
+  JournalRecovery sse_decode_journal_recovery(SseDeserializer deserializer);
+
+  @protected
+  LifecycleKind sse_decode_lifecycle_kind(SseDeserializer deserializer);
+
+  @protected
+  List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+  @protected
+  List<AppLifecycleEvent> sse_decode_list_app_lifecycle_event(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<AppResourceUsage> sse_decode_list_app_resource_usage(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<AppRunningTime> sse_decode_list_app_running_time(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<Application> sse_decode_list_application(SseDeserializer deserializer);
+
+  @protected
+  List<CategoryTime> sse_decode_list_category_time(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<CodingActivity> sse_decode_list_coding_activity(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<FocusMetrics> sse_decode_list_focus_metrics(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<FullscreenTime> sse_decode_list_fullscreen_time(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<InstalledApp> sse_decode_list_installed_app(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<IssueTime> sse_decode_list_issue_time(SseDeserializer deserializer);
+
+  @protected
+  List<MediaActivity> sse_decode_list_media_activity(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<MediaState> sse_decode_list_media_state(SseDeserializer deserializer);
+
+  @protected
+  List<MediaTime> sse_decode_list_media_time(SseDeserializer deserializer);
+
+  @protected
+  List<Meeting> sse_decode_list_meeting(SseDeserializer deserializer);
+
+  @protected
+  List<Monitor> sse_decode_list_monitor(SseDeserializer deserializer);
+
+  @protected
+  List<ParentProcess> sse_decode_list_parent_process(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  List<(String, AppCategory)> sse_decode_list_record_string_app_category(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<RepositoryTime> sse_decode_list_repository_time(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TerminalActivity> sse_decode_list_terminal_activity(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<TrackerEventKind> sse_decode_list_tracker_event_kind(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<VisibleTime> sse_decode_list_visible_time(SseDeserializer deserializer);
+
+  @protected
+  List<WindowInfo> sse_decode_list_window_info(SseDeserializer deserializer);
+
+  @protected
+  List<WorkspaceRule> sse_decode_list_workspace_rule(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  List<WorkspaceTime> sse_decode_list_workspace_time(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  MediaActivity sse_decode_media_activity(SseDeserializer deserializer);
+
+  @protected
+  MediaState sse_decode_media_state(SseDeserializer deserializer);
+
+  @protected
+  MediaTime sse_decode_media_time(SseDeserializer deserializer);
+
+  @protected
+  Meeting sse_decode_meeting(SseDeserializer deserializer);
+
+  @protected
+  Monitor sse_decode_monitor(SseDeserializer deserializer);
+
+  @protected
+  String? sse_decode_opt_String(SseDeserializer deserializer);
+
+  @protected
+  ActivityLevel? sse_decode_opt_box_autoadd_activity_level(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  AppCategory? sse_decode_opt_box_autoadd_app_category(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Application? sse_decode_opt_box_autoadd_application(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+  @protected
+  ProcessInfo? sse_decode_opt_box_autoadd_process_info(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Rect? sse_decode_opt_box_autoadd_rect(SseDeserializer deserializer);
+
+  @protected
+  SessionSummary? sse_decode_opt_box_autoadd_session_summary(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  int? sse_decode_opt_box_autoadd_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+  @protected
+  WindowState? sse_decode_opt_box_autoadd_window_state(
+    SseDeserializer deserializer,
+  );
+
+  @protected
+  Workspace? sse_decode_opt_box_autoadd_workspace(SseDeserializer deserializer);
+
+  @protected
+  Uint8List? sse_decode_opt_list_prim_u_8_strict(SseDeserializer deserializer);
+
+  @protected
+  ParentProcess sse_decode_parent_process(SseDeserializer deserializer);
+
+  @protected
+  PlaybackStatus sse_decode_playback_status(SseDeserializer deserializer);
+
+  @protected
+  ProcessInfo sse_decode_process_info(SseDeserializer deserializer);
+
+  @protected (String, AppCategory)
+  sse_decode_record_string_app_category(SseDeserializer deserializer);
+
+  @protected
+  Rect sse_decode_rect(SseDeserializer deserializer);
+
+  @protected
+  RepositoryTime sse_decode_repository_time(SseDeserializer deserializer);
+
+  @protected
+  ResourceStats sse_decode_resource_stats(SseDeserializer deserializer);
+
+  @protected
+  SessionSummary sse_decode_session_summary(SseDeserializer deserializer);
+
+  @protected
+  TerminalActivity sse_decode_terminal_activity(SseDeserializer deserializer);
+
+  @protected
+  TrackerEvent sse_decode_tracker_event(SseDeserializer deserializer);
+
+  @protected
+  TrackerEventKind sse_decode_tracker_event_kind(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_16(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_32(SseDeserializer deserializer);
+
+  @protected
+  BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+  @protected
+  int sse_decode_u_8(SseDeserializer deserializer);
+
+  @protected
+  void sse_decode_unit(SseDeserializer deserializer);
+
+  @protected
+  VisibleTime sse_decode_visible_time(SseDeserializer deserializer);
+
+  @protected
+  WindowInfo sse_decode_window_info(SseDeserializer deserializer);
+
+  @protected
+  WindowState sse_decode_window_state(SseDeserializer deserializer);
+
+  @protected
+  WorklogFormat sse_decode_worklog_format(SseDeserializer deserializer);
+
+  @protected
+  Workspace sse_decode_workspace(SseDeserializer deserializer);
+
+  @protected
+  WorkspaceRule sse_decode_workspace_rule(SseDeserializer deserializer);
+
+  @protected
+  WorkspaceTime sse_decode_workspace_time(SseDeserializer deserializer);
+
+  @protected
+  void sse_encode_AnyhowException(
+    AnyhowException self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_Map_String_app_category_None(
+    Map<String,
+    AppCategory> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_application_Sse(
+    RustStreamSink<Application> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_StreamSink_tracker_event_Sse(
+    RustStreamSink<TrackerEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_String(String self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_activity_level(ActivityLevel self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_app_category(AppCategory self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_app_lifecycle_event(
+    AppLifecycleEvent self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_app_resource_usage(
+    AppResourceUsage self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_app_running_time(
+    AppRunningTime self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_application(Application self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_bool(bool self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_activity_level(
+    ActivityLevel self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_app_category(
+    AppCategory self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_application(
+    Application self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_event_filter(
+    EventFilter self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_i_64(
+    PlatformInt64 self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_process_info(
+    ProcessInfo self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_rect(Rect self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_session_summary(
+    SessionSummary self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_box_autoadd_window_state(
+    WindowState self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_box_autoadd_workspace(
+    Workspace self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_capture_mode(CaptureMode self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_category_time(CategoryTime self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_coding_activity(
+    CodingActivity self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_event_filter(EventFilter self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_f_32(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_f_64(double self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_focus_metrics(FocusMetrics self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_fullscreen_time(
+    FullscreenTime self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_i_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_input_counts(InputCounts self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_installed_app(InstalledApp self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_issue_time(IssueTime self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_journal_recovery(
+    JournalRecovery self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_lifecycle_kind(LifecycleKind self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_app_lifecycle_event(
+    List<AppLifecycleEvent> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_app_resource_usage(
+    List<AppResourceUsage> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_app_running_time(
+    List<AppRunningTime> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_application(
+    List<Application> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_category_time(
+    List<CategoryTime> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_coding_activity(
+    List<CodingActivity> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_focus_metrics(
+    List<FocusMetrics> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_fullscreen_time(
+    List<FullscreenTime> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_installed_app(
+    List<InstalledApp> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_issue_time(
+    List<IssueTime> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_media_activity(
+    List<MediaActivity> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_media_state(
+    List<MediaState> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_media_time(
+    List<MediaTime> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_meeting(List<Meeting> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_monitor(List<Monitor> self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_list_parent_process(
+    List<ParentProcess> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_prim_u_8_strict(
+    Uint8List self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_record_string_app_category(
+    List<(String, AppCategory)> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_repository_time(
+    List<RepositoryTime> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_terminal_activity(
+    List<TerminalActivity> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_tracker_event_kind(
+    List<TrackerEventKind> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_visible_time(
+    List<VisibleTime> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_window_info(
+    List<WindowInfo> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_workspace_rule(
+    List<WorkspaceRule> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_list_workspace_time(
+    List<WorkspaceTime> self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_media_activity(MediaActivity self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_media_state(MediaState self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_media_time(MediaTime self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_meeting(Meeting self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_monitor(Monitor self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_activity_level(
+    ActivityLevel? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_app_category(
+    AppCategory? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_application(
+    Application? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_i_64(
+    PlatformInt64? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_process_info(
+    ProcessInfo? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_rect(Rect? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_session_summary(
+    SessionSummary? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_32(int? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_opt_box_autoadd_window_state(
+    WindowState? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_box_autoadd_workspace(
+    Workspace? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_opt_list_prim_u_8_strict(
+    Uint8List? self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_parent_process(ParentProcess self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_playback_status(
+    PlaybackStatus self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_process_info(ProcessInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_record_string_app_category(
+    (String, AppCategory) self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_rect(Rect self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_repository_time(
+    RepositoryTime self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_resource_stats(ResourceStats self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_session_summary(
+    SessionSummary self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_terminal_activity(
+    TerminalActivity self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_tracker_event(TrackerEvent self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_tracker_event_kind(
+    TrackerEventKind self,
+    SseSerializer serializer,
+  );
+
+  @protected
+  void sse_encode_u_16(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_32(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_u_8(int self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_unit(void self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_visible_time(VisibleTime self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_window_info(WindowInfo self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_window_state(WindowState self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_worklog_format(WorklogFormat self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_workspace(Workspace self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_workspace_rule(WorkspaceRule self, SseSerializer serializer);
+
+  @protected
+  void sse_encode_workspace_time(WorkspaceTime self, SseSerializer serializer);
 }
 
 // Section: wire_class
@@ -0,0 +1,27 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'event.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// 订阅过滤条件，列表为空表示不过滤
+class EventFilter {
+  final List<TrackerEventKind> kinds;
+  final List<String> apps;
+
+  const EventFilter({required this.kinds, required this.apps});
+
+  @override
+  int get hashCode => kinds.hashCode ^ apps.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is EventFilter &&
+          runtimeType == other.runtimeType &&
+          kinds == other.kinds &&
+          apps == other.apps;
+}
@@ -0,0 +1,56 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// 应用分类，与界面的 `IAppTypes` 一致
+enum AppCategory {
+  work, study, joy, others, unknown,;
+}
+
+/// 一个已安装的应用
+class InstalledApp {
+  final String id;
+  final String name;
+  final String? exec;
+  final String? icon;
+  final List<String> categories;
+  final AppCategory suggested;
+  final String? source;
+
+  const InstalledApp({
+    required this.id,
+    required this.name,
+    this.exec,
+    this.icon,
+    required this.categories,
+    required this.suggested,
+    this.source,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      name.hashCode ^
+      exec.hashCode ^
+      icon.hashCode ^
+      categories.hashCode ^
+      suggested.hashCode ^
+      source.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is InstalledApp &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          name == other.name &&
+          exec == other.exec &&
+          icon == other.icon &&
+          categories == other.categories &&
+          suggested == other.suggested &&
+          source == other.source;
+}
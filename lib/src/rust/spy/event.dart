@@ -0,0 +1,134 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'catalog.dart';
+import 'input.dart';
+import 'model.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'process.dart';
+import 'visibility.dart';
+import 'window_state.dart';
+import 'workspace.dart';
+
+/// 结束的会话
+class SessionSummary {
+  final BigInt id;
+  final String name;
+  final String title;
+  final PlatformInt64 start;
+  final PlatformInt64 end;
+  final ActivityLevel? activity;
+
+  const SessionSummary({
+    required this.id,
+    required this.name,
+    required this.title,
+    required this.start,
+    required this.end,
+    this.activity,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      name.hashCode ^
+      title.hashCode ^
+      start.hashCode ^
+      end.hashCode ^
+      activity.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is SessionSummary &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          name == other.name &&
+          title == other.title &&
+          start == other.start &&
+          end == other.end &&
+          activity == other.activity;
+}
+
+/// 追踪事件，按 kind 填写对应字段，其余为空
+class TrackerEvent {
+  final int schemaVersion;
+  final BigInt seq;
+  final TrackerEventKind kind;
+  final PlatformInt64 timestamp;
+  final PlatformInt64 emittedAt;
+  final Application? app;
+  final SessionSummary? session;
+  final String? path;
+  final String? source;
+  final String? message;
+  final PlatformInt64? idleSeconds;
+  final String? name;
+  final BigInt? meetingId;
+  final int? pid;
+  final BigInt? dropped;
+
+  const TrackerEvent({
+    required this.schemaVersion,
+    required this.seq,
+    required this.kind,
+    required this.timestamp,
+    required this.emittedAt,
+    this.app,
+    this.session,
+    this.path,
+    this.source,
+    this.message,
+    this.idleSeconds,
+    this.name,
+    this.meetingId,
+    this.pid,
+    this.dropped,
+  });
+
+  @override
+  int get hashCode =>
+      schemaVersion.hashCode ^
+      seq.hashCode ^
+      kind.hashCode ^
+      timestamp.hashCode ^
+      emittedAt.hashCode ^
+      app.hashCode ^
+      session.hashCode ^
+      path.hashCode ^
+      source.hashCode ^
+      message.hashCode ^
+      idleSeconds.hashCode ^
+      name.hashCode ^
+      meetingId.hashCode ^
+      pid.hashCode ^
+      dropped.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TrackerEvent &&
+          runtimeType == other.runtimeType &&
+          schemaVersion == other.schemaVersion &&
+          seq == other.seq &&
+          kind == other.kind &&
+          timestamp == other.timestamp &&
+          emittedAt == other.emittedAt &&
+          app == other.app &&
+          session == other.session &&
+          path == other.path &&
+          source == other.source &&
+          message == other.message &&
+          idleSeconds == other.idleSeconds &&
+          name == other.name &&
+          meetingId == other.meetingId &&
+          pid == other.pid &&
+          dropped == other.dropped;
+}
+
+enum TrackerEventKind {
+  focusChanged, titleChanged, sessionEnded, screenshotTaken, screenshotFailed, idle, resumed, meetingStarted, meetingEnded, appLaunched, appExited, error, lagged, heartbeat,;
+}
@@ -0,0 +1,36 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// 按仓库和分支统计的前台时间
+class RepositoryTime {
+  final String repo;
+  final String name;
+  final String? branch;
+  final double seconds;
+
+  const RepositoryTime({
+    required this.repo,
+    required this.name,
+    this.branch,
+    required this.seconds,
+  });
+
+  @override
+  int get hashCode =>
+      repo.hashCode ^ name.hashCode ^ branch.hashCode ^ seconds.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is RepositoryTime &&
+          runtimeType == other.runtimeType &&
+          repo == other.repo &&
+          name == other.name &&
+          branch == other.branch &&
+          seconds == other.seconds;
+}
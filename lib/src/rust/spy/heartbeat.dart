@@ -0,0 +1,47 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// 心跳与前台会话合并后的编码时间
+class CodingActivity {
+  final String? appName;
+  final String? project;
+  final String? branch;
+  final String entity;
+  final String? language;
+  final double seconds;
+
+  const CodingActivity({
+    this.appName,
+    this.project,
+    this.branch,
+    required this.entity,
+    this.language,
+    required this.seconds,
+  });
+
+  @override
+  int get hashCode =>
+      appName.hashCode ^
+      project.hashCode ^
+      branch.hashCode ^
+      entity.hashCode ^
+      language.hashCode ^
+      seconds.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CodingActivity &&
+          runtimeType == other.runtimeType &&
+          appName == other.appName &&
+          project == other.project &&
+          branch == other.branch &&
+          entity == other.entity &&
+          language == other.language &&
+          seconds == other.seconds;
+}
@@ -0,0 +1,84 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// 活跃程度，按每个活跃分钟的平均输入次数划分
+enum ActivityLevel {
+  idle, low, medium, high,;
+}
+
+/// 按应用统计的专注指标
+class FocusMetrics {
+  final String name;
+  final double seconds;
+  final double activeSeconds;
+  final double idleSeconds;
+  final InputCounts input;
+  final ActivityLevel level;
+
+  const FocusMetrics({
+    required this.name,
+    required this.seconds,
+    required this.activeSeconds,
+    required this.idleSeconds,
+    required this.input,
+    required this.level,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      seconds.hashCode ^
+      activeSeconds.hashCode ^
+      idleSeconds.hashCode ^
+      input.hashCode ^
+      level.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FocusMetrics &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          seconds == other.seconds &&
+          activeSeconds == other.activeSeconds &&
+          idleSeconds == other.idleSeconds &&
+          input == other.input &&
+          level == other.level;
+}
+
+/// 一段时间内的输入次数
+class InputCounts {
+  final int keystrokes;
+  final int clicks;
+  final int scrolls;
+  final int mouseDistance;
+
+  const InputCounts({
+    required this.keystrokes,
+    required this.clicks,
+    required this.scrolls,
+    required this.mouseDistance,
+  });
+
+  @override
+  int get hashCode =>
+      keystrokes.hashCode ^
+      clicks.hashCode ^
+      scrolls.hashCode ^
+      mouseDistance.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is InputCounts &&
+          runtimeType == other.runtimeType &&
+          keystrokes == other.keystrokes &&
+          clicks == other.clicks &&
+          scrolls == other.scrolls &&
+          mouseDistance == other.mouseDistance;
+}
@@ -0,0 +1,37 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// 按工单统计的时间
+class IssueTime {
+  final String key;
+  final List<String> apps;
+  final double seconds;
+
+  const IssueTime({
+    required this.key,
+    required this.apps,
+    required this.seconds,
+  });
+
+  @override
+  int get hashCode => key.hashCode ^ apps.hashCode ^ seconds.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is IssueTime &&
+          runtimeType == other.runtimeType &&
+          key == other.key &&
+          apps == other.apps &&
+          seconds == other.seconds;
+}
+
+/// 工作日志导出格式
+enum WorklogFormat {
+  csv, json,;
+}
@@ -0,0 +1,33 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// 启动时恢复日志的结果
+class JournalRecovery {
+  final int records;
+  final BigInt truncatedBytes;
+  final int sessions;
+
+  const JournalRecovery({
+    required this.records,
+    required this.truncatedBytes,
+    required this.sessions,
+  });
+
+  @override
+  int get hashCode =>
+      records.hashCode ^ truncatedBytes.hashCode ^ sessions.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is JournalRecovery &&
+          runtimeType == other.runtimeType &&
+          records == other.records &&
+          truncatedBytes == other.truncatedBytes &&
+          sessions == other.sessions;
+}
@@ -0,0 +1,66 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// 应用启动或退出事件
+class AppLifecycleEvent {
+  final LifecycleKind kind;
+  final String name;
+  final int pid;
+  final PlatformInt64 timestamp;
+
+  const AppLifecycleEvent({
+    required this.kind,
+    required this.name,
+    required this.pid,
+    required this.timestamp,
+  });
+
+  @override
+  int get hashCode =>
+      kind.hashCode ^ name.hashCode ^ pid.hashCode ^ timestamp.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AppLifecycleEvent &&
+          runtimeType == other.runtimeType &&
+          kind == other.kind &&
+          name == other.name &&
+          pid == other.pid &&
+          timestamp == other.timestamp;
+}
+
+/// 应用在一段时间内的运行时间和前台时间
+class AppRunningTime {
+  final String name;
+  final PlatformInt64 runningSeconds;
+  final PlatformInt64 focusedSeconds;
+
+  const AppRunningTime({
+    required this.name,
+    required this.runningSeconds,
+    required this.focusedSeconds,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^ runningSeconds.hashCode ^ focusedSeconds.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AppRunningTime &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          runningSeconds == other.runningSeconds &&
+          focusedSeconds == other.focusedSeconds;
+}
+
+enum LifecycleKind {
+  launched, exited,;
+}
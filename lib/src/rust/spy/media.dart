@@ -0,0 +1,107 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// 一段连续播放同一曲目的区间
+class MediaActivity {
+  final String player;
+  final String? title;
+  final String? artist;
+  final PlatformInt64 start;
+  final PlatformInt64 end;
+
+  const MediaActivity({
+    required this.player,
+    this.title,
+    this.artist,
+    required this.start,
+    required this.end,
+  });
+
+  @override
+  int get hashCode =>
+      player.hashCode ^
+      title.hashCode ^
+      artist.hashCode ^
+      start.hashCode ^
+      end.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MediaActivity &&
+          runtimeType == other.runtimeType &&
+          player == other.player &&
+          title == other.title &&
+          artist == other.artist &&
+          start == other.start &&
+          end == other.end;
+}
+
+/// 一个播放器当前的状态
+class MediaState {
+  final String player;
+  final PlaybackStatus status;
+  final String? title;
+  final String? artist;
+  final String? album;
+  final String? url;
+
+  const MediaState({
+    required this.player,
+    required this.status,
+    this.title,
+    this.artist,
+    this.album,
+    this.url,
+  });
+
+  @override
+  int get hashCode =>
+      player.hashCode ^
+      status.hashCode ^
+      title.hashCode ^
+      artist.hashCode ^
+      album.hashCode ^
+      url.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MediaState &&
+          runtimeType == other.runtimeType &&
+          player == other.player &&
+          status == other.status &&
+          title == other.title &&
+          artist == other.artist &&
+          album == other.album &&
+          url == other.url;
+}
+
+/// 按播放器统计的播放时间
+class MediaTime {
+  final String player;
+  final double seconds;
+
+  const MediaTime({required this.player, required this.seconds});
+
+  @override
+  int get hashCode => player.hashCode ^ seconds.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is MediaTime &&
+          runtimeType == other.runtimeType &&
+          player == other.player &&
+          seconds == other.seconds;
+}
+
+/// 播放状态，与 MPRIS PlaybackStatus 一致
+enum PlaybackStatus {
+  playing, paused, stopped,;
+}
@@ -0,0 +1,51 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// 一次会议
+class Meeting {
+  final BigInt id;
+  final String? app;
+  final PlatformInt64 start;
+  final PlatformInt64 end;
+  final bool camera;
+  final bool screenShare;
+  final bool ongoing;
+
+  const Meeting({
+    required this.id,
+    this.app,
+    required this.start,
+    required this.end,
+    required this.camera,
+    required this.screenShare,
+    required this.ongoing,
+  });
+
+  @override
+  int get hashCode =>
+      id.hashCode ^
+      app.hashCode ^
+      start.hashCode ^
+      end.hashCode ^
+      camera.hashCode ^
+      screenShare.hashCode ^
+      ongoing.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Meeting &&
+          runtimeType == other.runtimeType &&
+          id == other.id &&
+          app == other.app &&
+          start == other.start &&
+          end == other.end &&
+          camera == other.camera &&
+          screenShare == other.screenShare &&
+          ongoing == other.ongoing;
+}
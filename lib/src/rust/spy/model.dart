@@ -4,7 +4,12 @@
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../frb_generated.dart';
+import 'catalog.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'process.dart';
+import 'visibility.dart';
+import 'window_state.dart';
+import 'workspace.dart';
 
 class Application {
   final String? icon;
@@ -12,6 +17,18 @@ class Application {
   final String title;
   final String path;
   final String? screenShotPath;
+  final String? repo;
+  final String? branch;
+  final String? appId;
+  final String exePath;
+  final String? runtime;
+  final String? package;
+  final String? version;
+  final ProcessInfo? process;
+  final String? monitor;
+  final Workspace? workspace;
+  final WindowState? state;
+  final Rect? geometry;
 
   const Application({
     this.icon,
@@ -19,6 +36,18 @@ class Application {
     required this.title,
     required this.path,
     this.screenShotPath,
+    this.repo,
+    this.branch,
+    this.appId,
+    required this.exePath,
+    this.runtime,
+    this.package,
+    this.version,
+    this.process,
+    this.monitor,
+    this.workspace,
+    this.state,
+    this.geometry,
   });
 
   @override
@@ -27,7 +56,19 @@ class Application {
       name.hashCode ^
       title.hashCode ^
       path.hashCode ^
-      screenShotPath.hashCode;
+      screenShotPath.hashCode ^
+      repo.hashCode ^
+      branch.hashCode ^
+      appId.hashCode ^
+      exePath.hashCode ^
+      runtime.hashCode ^
+      package.hashCode ^
+      version.hashCode ^
+      process.hashCode ^
+      monitor.hashCode ^
+      workspace.hashCode ^
+      state.hashCode ^
+      geometry.hashCode;
 
   @override
   bool operator ==(Object other) =>
@@ -38,5 +79,42 @@ class Application {
           name == other.name &&
           title == other.title &&
           path == other.path &&
-          screenShotPath == other.screenShotPath;
+          screenShotPath == other.screenShotPath &&
+          repo == other.repo &&
+          branch == other.branch &&
+          appId == other.appId &&
+          exePath == other.exePath &&
+          runtime == other.runtime &&
+          package == other.package &&
+          version == other.version &&
+          process == other.process &&
+          monitor == other.monitor &&
+          workspace == other.workspace &&
+          state == other.state &&
+          geometry == other.geometry;
+}
+
+/// 打开的窗口及其所属应用
+class WindowInfo {
+  final BigInt windowId;
+  final bool focused;
+  final Application app;
+
+  const WindowInfo({
+    required this.windowId,
+    required this.focused,
+    required this.app,
+  });
+
+  @override
+  int get hashCode => windowId.hashCode ^ focused.hashCode ^ app.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WindowInfo &&
+          runtimeType == other.runtimeType &&
+          windowId == other.windowId &&
+          focused == other.focused &&
+          app == other.app;
 }
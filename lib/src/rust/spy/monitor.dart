@@ -0,0 +1,52 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// 截图模式
+enum CaptureMode {
+  window, focusedMonitor, allMonitors,;
+}
+
+/// 一个显示器在虚拟屏幕坐标系中的位置
+class Monitor {
+  final String name;
+  final int x;
+  final int y;
+  final int width;
+  final int height;
+  final bool primary;
+
+  const Monitor({
+    required this.name,
+    required this.x,
+    required this.y,
+    required this.width,
+    required this.height,
+    required this.primary,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      x.hashCode ^
+      y.hashCode ^
+      width.hashCode ^
+      height.hashCode ^
+      primary.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Monitor &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          x == other.x &&
+          y == other.y &&
+          width == other.width &&
+          height == other.height &&
+          primary == other.primary;
+}
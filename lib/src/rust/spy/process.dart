@@ -0,0 +1,60 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+class ParentProcess {
+  final int pid;
+  final String name;
+
+  const ParentProcess({required this.pid, required this.name});
+
+  @override
+  int get hashCode => pid.hashCode ^ name.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ParentProcess &&
+          runtimeType == other.runtimeType &&
+          pid == other.pid &&
+          name == other.name;
+}
+
+class ProcessInfo {
+  final int pid;
+  final List<ParentProcess> parents;
+  final List<String> cmdline;
+  final BigInt startTime;
+  final String? user;
+
+  const ProcessInfo({
+    required this.pid,
+    required this.parents,
+    required this.cmdline,
+    required this.startTime,
+    this.user,
+  });
+
+  @override
+  int get hashCode =>
+      pid.hashCode ^
+      parents.hashCode ^
+      cmdline.hashCode ^
+      startTime.hashCode ^
+      user.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ProcessInfo &&
+          runtimeType == other.runtimeType &&
+          pid == other.pid &&
+          parents == other.parents &&
+          cmdline == other.cmdline &&
+          startTime == other.startTime &&
+          user == other.user;
+}
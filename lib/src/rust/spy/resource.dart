@@ -0,0 +1,80 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// 按应用汇总的资源占用
+class AppResourceUsage {
+  final String name;
+  final ResourceStats foreground;
+  final ResourceStats background;
+
+  const AppResourceUsage({
+    required this.name,
+    required this.foreground,
+    required this.background,
+  });
+
+  @override
+  int get hashCode => name.hashCode ^ foreground.hashCode ^ background.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is AppResourceUsage &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          foreground == other.foreground &&
+          background == other.background;
+}
+
+/// 会话内多次采样的累计
+class ResourceStats {
+  final int samples;
+  final double cpuAvg;
+  final double cpuMax;
+  final BigInt memoryAvg;
+  final BigInt memoryMax;
+  final BigInt diskRead;
+  final BigInt diskWrite;
+  final int threadsMax;
+
+  const ResourceStats({
+    required this.samples,
+    required this.cpuAvg,
+    required this.cpuMax,
+    required this.memoryAvg,
+    required this.memoryMax,
+    required this.diskRead,
+    required this.diskWrite,
+    required this.threadsMax,
+  });
+
+  @override
+  int get hashCode =>
+      samples.hashCode ^
+      cpuAvg.hashCode ^
+      cpuMax.hashCode ^
+      memoryAvg.hashCode ^
+      memoryMax.hashCode ^
+      diskRead.hashCode ^
+      diskWrite.hashCode ^
+      threadsMax.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is ResourceStats &&
+          runtimeType == other.runtimeType &&
+          samples == other.samples &&
+          cpuAvg == other.cpuAvg &&
+          cpuMax == other.cpuMax &&
+          memoryAvg == other.memoryAvg &&
+          memoryMax == other.memoryMax &&
+          diskRead == other.diskRead &&
+          diskWrite == other.diskWrite &&
+          threadsMax == other.threadsMax;
+}
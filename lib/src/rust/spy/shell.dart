@@ -0,0 +1,36 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// 终端会话按命令和目录拆分后的时间
+class TerminalActivity {
+  final String appName;
+  final String? command;
+  final String cwd;
+  final double seconds;
+
+  const TerminalActivity({
+    required this.appName,
+    this.command,
+    required this.cwd,
+    required this.seconds,
+  });
+
+  @override
+  int get hashCode =>
+      appName.hashCode ^ command.hashCode ^ cwd.hashCode ^ seconds.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is TerminalActivity &&
+          runtimeType == other.runtimeType &&
+          appName == other.appName &&
+          command == other.command &&
+          cwd == other.cwd &&
+          seconds == other.seconds;
+}
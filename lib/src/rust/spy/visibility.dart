@@ -0,0 +1,62 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// 屏幕坐标系中的矩形
+class Rect {
+  final int x;
+  final int y;
+  final int width;
+  final int height;
+
+  const Rect({
+    required this.x,
+    required this.y,
+    required this.width,
+    required this.height,
+  });
+
+  @override
+  int get hashCode =>
+      x.hashCode ^ y.hashCode ^ width.hashCode ^ height.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Rect &&
+          runtimeType == other.runtimeType &&
+          x == other.x &&
+          y == other.y &&
+          width == other.width &&
+          height == other.height;
+}
+
+/// 应用的可见时间和前台时间
+class VisibleTime {
+  final String name;
+  final PlatformInt64 visibleSeconds;
+  final PlatformInt64 focusedSeconds;
+
+  const VisibleTime({
+    required this.name,
+    required this.visibleSeconds,
+    required this.focusedSeconds,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^ visibleSeconds.hashCode ^ focusedSeconds.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is VisibleTime &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          visibleSeconds == other.visibleSeconds &&
+          focusedSeconds == other.focusedSeconds;
+}
@@ -0,0 +1,71 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// 按应用统计的全屏时间
+class FullscreenTime {
+  final String name;
+  final double fullscreenSeconds;
+  final double seconds;
+  final bool immersive;
+
+  const FullscreenTime({
+    required this.name,
+    required this.fullscreenSeconds,
+    required this.seconds,
+    required this.immersive,
+  });
+
+  @override
+  int get hashCode =>
+      name.hashCode ^
+      fullscreenSeconds.hashCode ^
+      seconds.hashCode ^
+      immersive.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is FullscreenTime &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          fullscreenSeconds == other.fullscreenSeconds &&
+          seconds == other.seconds &&
+          immersive == other.immersive;
+}
+
+/// 窗口状态
+class WindowState {
+  final bool fullscreen;
+  final bool maximized;
+  final bool minimized;
+  final bool above;
+
+  const WindowState({
+    required this.fullscreen,
+    required this.maximized,
+    required this.minimized,
+    required this.above,
+  });
+
+  @override
+  int get hashCode =>
+      fullscreen.hashCode ^
+      maximized.hashCode ^
+      minimized.hashCode ^
+      above.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WindowState &&
+          runtimeType == other.runtimeType &&
+          fullscreen == other.fullscreen &&
+          maximized == other.maximized &&
+          minimized == other.minimized &&
+          above == other.above;
+}
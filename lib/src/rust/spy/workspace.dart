@@ -0,0 +1,102 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.11.1.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'catalog.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+/// 按分类统计的前台时间
+class CategoryTime {
+  final AppCategory category;
+  final double seconds;
+
+  const CategoryTime({required this.category, required this.seconds});
+
+  @override
+  int get hashCode => category.hashCode ^ seconds.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is CategoryTime &&
+          runtimeType == other.runtimeType &&
+          category == other.category &&
+          seconds == other.seconds;
+}
+
+/// 当前虚拟桌面
+class Workspace {
+  final int index;
+  final String name;
+  final AppCategory? category;
+  final String? project;
+
+  const Workspace({
+    required this.index,
+    required this.name,
+    this.category,
+    this.project,
+  });
+
+  @override
+  int get hashCode =>
+      index.hashCode ^ name.hashCode ^ category.hashCode ^ project.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is Workspace &&
+          runtimeType == other.runtimeType &&
+          index == other.index &&
+          name == other.name &&
+          category == other.category &&
+          project == other.project;
+}
+
+/// 工作区规则：名称匹配正则时使用对应的分类和项目
+class WorkspaceRule {
+  final String pattern;
+  final AppCategory? category;
+  final String? project;
+
+  const WorkspaceRule({required this.pattern, this.category, this.project});
+
+  @override
+  int get hashCode => pattern.hashCode ^ category.hashCode ^ project.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WorkspaceRule &&
+          runtimeType == other.runtimeType &&
+          pattern == other.pattern &&
+          category == other.category &&
+          project == other.project;
+}
+
+/// 按工作区统计的前台时间
+class WorkspaceTime {
+  final String name;
+  final String? project;
+  final double seconds;
+
+  const WorkspaceTime({
+    required this.name,
+    this.project,
+    required this.seconds,
+  });
+
+  @override
+  int get hashCode => name.hashCode ^ project.hashCode ^ seconds.hashCode;
+
+  @override
+  bool operator ==(Object other) =>
+      identical(this, other) ||
+      other is WorkspaceTime &&
+          runtimeType == other.runtimeType &&
+          name == other.name &&
+          project == other.project &&
+          seconds == other.seconds;
+}
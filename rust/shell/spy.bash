@@ -0,0 +1,29 @@
+# spy_on_your_work shell integration for bash
+# 在 ~/.bashrc 末尾添加: source /path/to/spy.bash
+
+__spy_preexec() {
+    [ -n "$COMP_LINE" ] && return
+    [[ "$BASH_COMMAND" == __spy_precmd* ]] && return
+    [ -n "$__spy_in_prompt" ] && return
+    [ -n "$__spy_running" ] && return
+    __spy_running=1
+    local cmd
+    cmd=$(HISTTIMEFORMAT= builtin history 1 | sed 's/^ *[0-9]* *//')
+    spy-shell-hook start "$$" "$PWD" "$cmd" 2>/dev/null
+}
+
+__spy_precmd() {
+    local ret=$?
+    __spy_in_prompt=1
+    if [ -n "$__spy_running" ]; then
+        spy-shell-hook end "$$" "$PWD" "$ret" 2>/dev/null
+    fi
+    unset __spy_running
+}
+
+__spy_prompt_done() {
+    unset __spy_in_prompt
+}
+
+trap '__spy_preexec' DEBUG
+PROMPT_COMMAND="__spy_precmd${PROMPT_COMMAND:+;$PROMPT_COMMAND};__spy_prompt_done"
@@ -0,0 +1,10 @@
+# spy_on_your_work shell integration for fish
+# 在 ~/.config/fish/config.fish 末尾添加: source /path/to/spy.fish
+
+function __spy_preexec --on-event fish_preexec
+    spy-shell-hook start $fish_pid $PWD "$argv" 2>/dev/null
+end
+
+function __spy_postexec --on-event fish_postexec
+    spy-shell-hook end $fish_pid $PWD $status 2>/dev/null
+end
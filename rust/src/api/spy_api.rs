@@ -1,3 +1,4 @@
+use std::collections::HashMap;
 use std::sync::Mutex;
 
 use flutter_rust_bridge::frb;
@@ -5,18 +6,107 @@ use once_cell::sync::Lazy;
 
 use crate::{
     frb_generated::StreamSink,
-    spy::{api::EVENT_SINK, model::Application},
+    spy::{
+        bus::{EventFilter, Replay, QUEUE_SIZE},
+        catalog::{AppCategory, InstalledApp},
+        event::TrackerEvent,
+        git::RepositoryTime,
+        heartbeat::CodingActivity,
+        input::FocusMetrics,
+        issue::{IssueTime, WorklogFormat},
+        journal::JournalRecovery,
+        lifecycle::{AppLifecycleEvent, AppRunningTime},
+        media::{MediaActivity, MediaState, MediaTime},
+        meeting::Meeting,
+        model::{Application, WindowInfo},
+        monitor::{CaptureMode, Monitor},
+        resource::AppResourceUsage,
+        shell::TerminalActivity,
+        visibility::VisibleTime,
+        window_state::FullscreenTime,
+        workspace::{CategoryTime, WorkspaceRule, WorkspaceTime},
+    },
 };
 
 pub static SCREENSHOT_SAVE_FOLDER: Lazy<Mutex<String>> = Lazy::new(|| Mutex::new(String::new()));
 
+/// 前台应用采样流，可以同时有多个监听者。client_id 标识监听者（例如主界面、托盘），
+/// 同一个 client 重新监听时先补发上次断开后（例如热重启期间）错过的采样
 #[frb(sync)]
-pub fn application_info_stream(s: StreamSink<Application>) -> anyhow::Result<()> {
-    let mut stream = EVENT_SINK.write().unwrap();
-    *stream = Some(s);
+pub fn application_info_stream(
+    s: StreamSink<Application>,
+    client_id: String,
+) -> anyhow::Result<()> {
+    crate::spy::bus::APPLICATIONS.subscribe_client(
+        &client_id,
+        |_| true,
+        QUEUE_SIZE,
+        move |app| s.add(app).is_ok(),
+    );
     anyhow::Ok(())
 }
 
+/// 前台应用采样流，先补发 since（unix 毫秒）及之后的采样，用于 UI 重新连接后补齐
+#[frb(sync)]
+pub fn application_info_stream_since(
+    s: StreamSink<Application>,
+    since: Option<i64>,
+) -> anyhow::Result<()> {
+    crate::spy::bus::APPLICATIONS.subscribe(
+        |_| true,
+        QUEUE_SIZE,
+        replay(None, since),
+        move |app| s.add(app).is_ok(),
+    );
+    anyhow::Ok(())
+}
+
+/// 追踪事件流：焦点切换、标题变化、会话结束、截图、离开和回来、会议、应用启动和退出、错误
+#[frb(sync)]
+pub fn tracker_event_stream(s: StreamSink<TrackerEvent>) -> anyhow::Result<()> {
+    subscribe_tracker_events(s, EventFilter::default(), None, None)
+}
+
+/// 按事件类型和应用过滤的追踪事件流，Dart 端取消监听后自动退订。
+/// after_seq 为上次收到的事件序号，since 为时间（unix 毫秒），先补发之后的事件；都为空时只接收新事件
+#[frb(sync)]
+pub fn subscribe_tracker_events(
+    s: StreamSink<TrackerEvent>,
+    filter: EventFilter,
+    after_seq: Option<u64>,
+    since: Option<i64>,
+) -> anyhow::Result<()> {
+    crate::spy::bus::EVENTS.subscribe(
+        move |event| filter.matches(event),
+        QUEUE_SIZE,
+        replay(after_seq, since),
+        move |event| s.add(event).is_ok(),
+    );
+    anyhow::Ok(())
+}
+
+/// 打开事件日志，截掉崩溃时写了一半的记录，按日志重建会话并恢复最近事件。
+/// 必须在 start_spy 之前调用：已经有会话时不会重建
+#[frb(sync)]
+pub fn init_journal(path: String) -> anyhow::Result<JournalRecovery> {
+    println!("[rust] init journal: {}", path);
+    crate::spy::journal::init(&path)
+}
+
+/// 最后发布的追踪事件序号
+#[frb(sync)]
+pub fn get_event_cursor() -> u64 {
+    crate::spy::bus::EVENTS.cursor()
+}
+
+fn replay(after_seq: Option<u64>, since: Option<i64>) -> Replay {
+    match (after_seq, since) {
+        (Some(cursor), _) => Replay::After(cursor),
+        (None, Some(ts)) => Replay::Since(ts),
+        (None, None) => Replay::None,
+    }
+}
+
 #[frb(sync)]
 pub fn start_spy() {
     crate::spy::api::start_spy();
@@ -34,6 +124,31 @@ pub fn init_save_path(path: String) {
     println!("[rust] init save path: {}", s);
 }
 
+/// 设置图标缓存目录，图标按内容哈希保存为 `<id>.png`
+#[frb(sync)]
+pub fn init_icon_cache_path(path: String) {
+    println!("[rust] init icon cache path: {}", path);
+    crate::spy::icon::init_cache_folder(path);
+}
+
+/// 按图标 id 获取 PNG，size 为 0 时返回原始尺寸
+#[frb(sync)]
+pub fn get_icon(id: String, size: u32) -> Option<Vec<u8>> {
+    crate::spy::icon::get_icon(&id, size)
+}
+
+/// 截图范围：前台窗口、窗口所在显示器或所有显示器
+#[frb(sync)]
+pub fn set_capture_mode(mode: CaptureMode) {
+    crate::spy::monitor::set_capture_mode(mode);
+}
+
+/// 显示器布局（虚拟屏幕坐标）
+#[frb(sync)]
+pub fn list_monitors() -> Vec<Monitor> {
+    crate::spy::monitor::list_monitors()
+}
+
 #[frb(sync)]
 pub fn init_screenshot_apps(v: Vec<String>) {
     println!("[rust] init_screenshot_apps: {:?}", v);
@@ -51,3 +166,254 @@ pub fn remove_screenshot_apps(v: String) {
     println!("[rust] remove_screenshot_apps: {}", v);
     crate::spy::platform::remove_screenshot_app(v);
 }
+
+/// 正在运行的应用（每个应用一条），可在应用获得焦点前配置截图、分析等设置
+#[frb(sync)]
+pub fn list_running_applications() -> Vec<Application> {
+    crate::spy::platform::list_running_applications()
+}
+
+/// 所有打开的窗口及其所属应用
+#[frb(sync)]
+pub fn list_windows() -> Vec<WindowInfo> {
+    crate::spy::platform::list_windows()
+}
+
+/// 已安装的应用及分类建议，用于在应用获得焦点前预先设置分类；
+/// 需要扫描 desktop 文件，不在 UI 线程执行
+pub fn list_installed_applications() -> Vec<InstalledApp> {
+    crate::spy::catalog::list_installed_applications()
+}
+
+/// 已安装应用的图标 id，第一次调用时提取，列表滚动到该应用时再获取
+pub fn get_installed_app_icon(id: String) -> Option<String> {
+    crate::spy::catalog::installed_app_icon(&id)
+}
+
+/// 开始跟踪应用的启动和退出，返回使用的方式：`netlink` 或 `poll`
+#[frb(sync)]
+pub fn start_lifecycle_watcher() -> anyhow::Result<String> {
+    crate::spy::lifecycle::start_watcher()
+}
+
+#[frb(sync)]
+pub fn stop_lifecycle_watcher() {
+    crate::spy::lifecycle::stop_watcher();
+}
+
+/// [start, end]（unix 毫秒）内的应用启动和退出事件
+#[frb(sync)]
+pub fn get_lifecycle_events(start: i64, end: i64) -> Vec<AppLifecycleEvent> {
+    crate::spy::lifecycle::get_lifecycle_events(start, end)
+}
+
+/// 统计 [start, end]（unix 毫秒）内各应用的运行时间和前台时间
+#[frb(sync)]
+pub fn get_running_time(start: i64, end: i64) -> Vec<AppRunningTime> {
+    crate::spy::lifecycle::get_running_time(start, end)
+}
+
+/// 启动本地 WakaTime 兼容心跳服务，返回实际监听端口（传 0 时由系统分配）。
+/// 编辑器插件需把 api key 设为 api_key，不带正确 api key 或来自网页的请求会被拒绝
+#[frb(sync)]
+pub fn start_heartbeat_server(port: u16, api_key: String) -> anyhow::Result<u16> {
+    crate::spy::heartbeat::start_server(port, api_key)
+}
+
+#[frb(sync)]
+pub fn stop_heartbeat_server() {
+    crate::spy::heartbeat::stop_server();
+}
+
+/// 统计 [start, end]（unix 毫秒）内按文件、分支、项目归属的编码时间；
+/// 心跳期间前台不是发送心跳的编辑器或终端时，app_name 为空
+#[frb(sync)]
+pub fn get_coding_activity(start: i64, end: i64) -> Vec<CodingActivity> {
+    crate::spy::heartbeat::get_coding_activity(start, end)
+}
+
+/// 启动 shell 钩子事件监听，返回 unix socket 路径（不传时使用默认路径）
+#[frb(sync)]
+pub fn start_shell_listener(path: Option<String>) -> anyhow::Result<String> {
+    crate::spy::shell::start_listener(path)
+}
+
+#[frb(sync)]
+pub fn stop_shell_listener() {
+    crate::spy::shell::stop_listener();
+}
+
+/// 获取 bash / zsh / fish 的钩子脚本
+#[frb(sync)]
+pub fn get_shell_hook_script(shell: String) -> Option<String> {
+    crate::spy::shell::hook_script(&shell).map(|s| s.to_string())
+}
+
+/// 统计 [start, end]（unix 毫秒）内终端会话按命令、目录拆分的时间
+#[frb(sync)]
+pub fn get_terminal_activity(start: i64, end: i64) -> Vec<TerminalActivity> {
+    crate::spy::shell::get_terminal_activity(start, end)
+}
+
+/// 统计 [start, end]（unix 毫秒）内按 git 仓库和分支归属的前台时间
+#[frb(sync)]
+pub fn get_repository_time(start: i64, end: i64) -> Vec<RepositoryTime> {
+    crate::spy::git::get_repository_time(start, end)
+}
+
+/// 设置工单号正则，空字符串恢复默认的 `PROJ-1234` 格式
+#[frb(sync)]
+pub fn set_issue_key_pattern(pattern: String) -> anyhow::Result<()> {
+    crate::spy::issue::set_pattern(&pattern)
+}
+
+/// 只识别这些 Jira 项目键的工单，空列表表示不限制
+#[frb(sync)]
+pub fn set_issue_projects(projects: Vec<String>) {
+    crate::spy::issue::set_projects(projects);
+}
+
+/// 统计 [start, end]（unix 毫秒）内各工单的时间
+#[frb(sync)]
+pub fn get_issue_time(start: i64, end: i64) -> Vec<IssueTime> {
+    crate::spy::issue::get_issue_time(start, end)
+}
+
+/// 导出 [start, end]（unix 毫秒）内的工作日志，author 为空时使用系统用户名
+#[frb(sync)]
+pub fn export_worklogs(
+    start: i64,
+    end: i64,
+    format: WorklogFormat,
+    author: Option<String>,
+) -> anyhow::Result<String> {
+    crate::spy::issue::export_worklogs(start, end, format, author)
+}
+
+/// 设置需要在后台持续采样资源占用的应用（进程名或可执行文件名）
+#[frb(sync)]
+pub fn set_background_apps(apps: Vec<String>) {
+    crate::spy::resource::set_background_apps(apps);
+}
+
+/// 统计 [start, end]（unix 毫秒）内各应用在前台和后台时的资源占用
+#[frb(sync)]
+pub fn get_resource_usage(start: i64, end: i64) -> Vec<AppResourceUsage> {
+    crate::spy::resource::get_resource_usage(start, end)
+}
+
+/// 开启后每次采样同时统计可见但不在前台的窗口
+#[frb(sync)]
+pub fn set_visible_tracking(enabled: bool) {
+    crate::spy::visibility::set_tracking(enabled);
+}
+
+/// 统计 [start, end]（unix 毫秒）内各应用可见但不在前台的时间
+#[frb(sync)]
+pub fn get_visible_time(start: i64, end: i64) -> Vec<VisibleTime> {
+    crate::spy::visibility::get_visible_time(start, end)
+}
+
+/// 设置工作区规则：按名称匹配工作区，得到分类和项目
+#[frb(sync)]
+pub fn set_workspace_rules(rules: Vec<WorkspaceRule>) -> anyhow::Result<()> {
+    crate::spy::workspace::set_rules(rules)
+}
+
+/// 按工作区统计的前台时间
+#[frb(sync)]
+pub fn get_workspace_time(start: i64, end: i64) -> Vec<WorkspaceTime> {
+    crate::spy::workspace::get_workspace_time(start, end)
+}
+
+/// 按分类统计的前台时间，app_categories 为界面设置的应用分类（应用名称 → 分类），
+/// 工作区规则给出的分类优先
+#[frb(sync)]
+pub fn get_category_time(
+    start: i64,
+    end: i64,
+    app_categories: HashMap<String, AppCategory>,
+) -> Vec<CategoryTime> {
+    crate::spy::workspace::get_category_time(start, end, app_categories)
+}
+
+/// 按应用统计的全屏时间，immersive 标记全屏占比高的应用（游戏、视频）
+#[frb(sync)]
+pub fn get_fullscreen_time(start: i64, end: i64) -> Vec<FullscreenTime> {
+    crate::spy::window_state::get_fullscreen_time(start, end)
+}
+
+/// 开始通过 MPRIS 记录媒体播放（仅 Linux）
+#[frb(sync)]
+pub fn start_media_watcher() -> anyhow::Result<()> {
+    crate::spy::media::start_watcher()
+}
+
+#[frb(sync)]
+pub fn stop_media_watcher() {
+    crate::spy::media::stop_watcher();
+}
+
+/// 最近一次读取到的各播放器状态
+#[frb(sync)]
+pub fn get_now_playing() -> Vec<MediaState> {
+    crate::spy::media::now_playing()
+}
+
+/// [start, end]（unix 毫秒）内的播放区间，与前台会话并行
+#[frb(sync)]
+pub fn get_media_activity(start: i64, end: i64) -> Vec<MediaActivity> {
+    crate::spy::media::get_media_activity(start, end)
+}
+
+/// 按播放器统计的播放时间
+#[frb(sync)]
+pub fn get_media_time(start: i64, end: i64) -> Vec<MediaTime> {
+    crate::spy::media::get_media_time(start, end)
+}
+
+/// 开始检测会议：会议窗口标题、麦克风、摄像头和屏幕共享
+#[frb(sync)]
+pub fn start_meeting_detector() -> anyhow::Result<()> {
+    crate::spy::meeting::start_detector()
+}
+
+#[frb(sync)]
+pub fn stop_meeting_detector() {
+    crate::spy::meeting::stop_detector();
+}
+
+/// [start, end]（unix 毫秒）内的会议
+#[frb(sync)]
+pub fn get_meetings(start: i64, end: i64) -> Vec<Meeting> {
+    crate::spy::meeting::get_meetings(start, end)
+}
+
+/// 开始监控输入活跃度（只计数，不记录按键内容），返回使用的方式
+#[frb(sync)]
+pub fn start_input_monitor() -> anyhow::Result<String> {
+    crate::spy::input::start_monitor()
+}
+
+#[frb(sync)]
+pub fn stop_input_monitor() {
+    crate::spy::input::stop_monitor();
+}
+
+/// 设置空闲阈值（秒），超过阈值没有输入时不计入前台时间
+#[frb(sync)]
+pub fn set_idle_threshold(secs: i64) {
+    crate::spy::input::set_idle_threshold(secs);
+}
+
+/// 距离最后一次输入的秒数，没有开启输入监控时为 None
+#[frb(sync)]
+pub fn get_idle_seconds() -> Option<i64> {
+    crate::spy::input::get_idle_seconds()
+}
+
+/// 按应用统计的专注指标：活跃时间、空闲时间、输入次数和活跃程度
+#[frb(sync)]
+pub fn get_focus_metrics(start: i64, end: i64) -> Vec<FocusMetrics> {
+    crate::spy::input::get_focus_metrics(start, end)
+}
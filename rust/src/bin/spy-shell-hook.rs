@@ -0,0 +1,74 @@
+//! Shell 钩子客户端：把命令开始/结束事件发送给追踪器
+//!
+//! 用法:
+//!   spy-shell-hook start <pid> <cwd> <command...>
+//!   spy-shell-hook end <pid> <cwd> <exit_code>
+
+#[path = "../spy/shell_protocol.rs"]
+#[allow(dead_code)]
+mod shell_protocol;
+
+use shell_protocol::ShellMessage;
+
+fn now_millis() -> i64 {
+    std::time::SystemTime::now()
+        .duration_since(std::time::UNIX_EPOCH)
+        .map(|d| d.as_millis() as i64)
+        .unwrap_or_default()
+}
+
+fn parse_args(args: &[String]) -> Option<ShellMessage> {
+    let pid = args.get(1)?.parse().ok()?;
+    let cwd = args.get(2)?.clone();
+    match args.first()?.as_str() {
+        "start" => Some(ShellMessage::Start {
+            pid,
+            cwd,
+            command: args[3..].join(" "),
+            time: now_millis(),
+        }),
+        "end" => Some(ShellMessage::End {
+            pid,
+            cwd,
+            exit_code: args.get(3).and_then(|s| s.parse().ok()).unwrap_or_default(),
+            time: now_millis(),
+        }),
+        _ => None,
+    }
+}
+
+#[cfg(unix)]
+fn send(message: &ShellMessage) -> std::io::Result<()> {
+    use std::io::Write;
+    use std::os::unix::net::UnixStream;
+
+    // socket 目录不安全时不发送，避免把命令行发给其他用户
+    let path = shell_protocol::default_socket_path();
+    if let Some(dir) = path.parent() {
+        shell_protocol::check_socket_dir(dir)?;
+    }
+    let mut stream = UnixStream::connect(path)?;
+    stream.set_write_timeout(Some(std::time::Duration::from_millis(200)))?;
+    let mut line = serde_json::to_string(message)?;
+    line.push('\n');
+    stream.write_all(line.as_bytes())
+}
+
+#[cfg(not(unix))]
+fn send(_message: &ShellMessage) -> std::io::Result<()> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "shell integration requires unix domain sockets",
+    ))
+}
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let Some(message) = parse_args(&args) else {
+        eprintln!("usage: spy-shell-hook start <pid> <cwd> <command...>");
+        eprintln!("       spy-shell-hook end <pid> <cwd> <exit_code>");
+        std::process::exit(2);
+    };
+    // 追踪器未运行时静默失败，不影响 shell
+    let _ = send(&message);
+}
@@ -1,18 +1,57 @@
-use std::sync::RwLock;
+use std::sync::{Mutex, RwLock};
 
-use crate::frb_generated::StreamSink;
+use crate::spy::event::{self, TrackerEvent, TrackerEventKind};
 use crate::spy::model::Application;
 
 const SLEEP_SECS: u64 = 60;
 
-pub static EVENT_SINK: RwLock<Option<StreamSink<Application>>> = RwLock::new(None);
-
 pub static SPY_ON: RwLock<bool> = RwLock::new(false);
 
+/// 用户离开的开始时间（最后一次输入），未离开时为 None
+static AWAY_SINCE: Mutex<Option<i64>> = Mutex::new(None);
+
 pub fn send_application_message(message: Application) {
-    if let Some(sink) = &*EVENT_SINK.read().unwrap() {
-        let _ = sink.add(message);
+    let now = chrono::Local::now().timestamp_millis();
+    // 开启输入监控时，长时间没有输入视为离开，不计入前台时间
+    if crate::spy::input::is_idle(now) {
+        let mut away = AWAY_SINCE.lock().unwrap();
+        if away.is_none() {
+            let idle_ms = crate::spy::input::INPUT
+                .lock()
+                .unwrap()
+                .idle_millis(now)
+                .unwrap_or_default();
+            *away = Some(now - idle_ms);
+            if let Some(ended) = crate::spy::session::close(now - idle_ms) {
+                event::emit(TrackerEvent::session_ended(&ended));
+            }
+            event::FOCUS.lock().unwrap().reset();
+            event::emit(TrackerEvent::idle(
+                TrackerEventKind::Idle,
+                now - idle_ms,
+                idle_ms / 1000,
+            ));
+        }
+    } else {
+        if let Some(since) = AWAY_SINCE.lock().unwrap().take() {
+            event::emit(TrackerEvent::idle(
+                TrackerEventKind::Resumed,
+                now,
+                (now - since) / 1000,
+            ));
+        }
+        if let Some(ended) = crate::spy::session::record_sample(&message, now) {
+            event::emit(TrackerEvent::session_ended(&ended));
+        }
+        if let Some(kind) = event::FOCUS.lock().unwrap().update(&message) {
+            event::emit(TrackerEvent::app(kind, &message, now));
+        }
+        if let Some(path) = &message.screen_shot_path {
+            event::emit(TrackerEvent::screenshot_taken(&message, path, now));
+        }
     }
+    crate::spy::visibility::record_sample(&message.name);
+    crate::spy::bus::APPLICATIONS.publish(message);
 }
 
 #[cfg(target_os = "windows")]
@@ -54,7 +93,33 @@ pub fn start_spy() {
 }
 
 #[cfg(target_os = "linux")]
-pub fn start_spy() {}
+pub fn start_spy() {
+    {
+        let spy_on = SPY_ON.read().unwrap();
+        if *spy_on {
+            println!("Spy already started");
+            return;
+        }
+    }
+
+    std::thread::spawn(move || {
+        // set spy on
+        {
+            *SPY_ON.write().unwrap() = true;
+        }
+
+        loop {
+            use crate::spy::model::ApplicationProvider;
+
+            match Application::from_process(()) {
+                Some(app) => send_application_message(app),
+                None => println!("没有找到应用"),
+            }
+
+            std::thread::sleep(std::time::Duration::from_secs(SLEEP_SECS));
+        }
+    });
+}
 
 #[cfg(target_os = "macos")]
 pub fn start_spy() {
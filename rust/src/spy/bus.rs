@@ -0,0 +1,594 @@
+//! 事件总线
+//!
+//! 任意数量的订阅者可以同时接收事件，每个订阅者有自己的过滤条件（事件类型、应用）和有界队列，
+//! 由单独的线程把队列中的事件发给 Dart。慢订阅者的队列满了时丢弃最旧的事件，之后先补发一个
+//! 丢失通知，订阅本身不会断开。Dart 端取消订阅后发送失败的订阅者在下一次发布时移除；
+//! 过滤条件很窄、长时间收不到事件的订阅者定期收到心跳，借此发现已经关闭的订阅者。
+//!
+//! 总线保留最近 HISTORY_SIZE 个事件，新的订阅者可以从某个序号或时间开始补发，补齐 Flutter 启动、
+//! 热重启或 UI 断开期间发布的事件。打开事件日志时用日志中的记录恢复最近事件和序号，进程重启后
+//! 序号接着上一次运行继续，Dart 端保存的游标仍然有效。按 client 订阅时总线为每个 client 记录
+//! 已经发给它的最后一个序号，重新订阅时从这里补发，不受其他订阅者影响。
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, Mutex};
+
+use once_cell::sync::Lazy;
+
+use crate::spy::event::{TrackerEvent, TrackerEventKind};
+use crate::spy::journal::{self, JournalEntry};
+use crate::spy::model::Application;
+
+/// 每个订阅者最多积压的事件数量
+pub const QUEUE_SIZE: usize = 256;
+
+/// 保留的最近事件数量
+pub const HISTORY_SIZE: usize = 4096;
+
+/// 订阅者超过这个时长没有收到事件时，下一次发布给它发心跳
+const KEEPALIVE_MS: i64 = 60_000;
+
+/// 订阅过滤条件，列表为空表示不过滤
+#[derive(Debug, Clone, Default)]
+pub struct EventFilter {
+    pub kinds: Vec<TrackerEventKind>, // 只接收这些类型的事件
+    pub apps: Vec<String>,            // 只接收这些应用的事件，按应用名称匹配
+}
+
+impl EventFilter {
+    fn matches_name(&self, name: Option<&str>) -> bool {
+        self.apps.is_empty() || name.is_some_and(|n| self.apps.iter().any(|a| a == n))
+    }
+
+    pub fn matches(&self, event: &TrackerEvent) -> bool {
+        let name = event
+            .app
+            .as_ref()
+            .map(|a| a.name.as_str())
+            .or(event.session.as_ref().map(|s| s.name.as_str()))
+            .or(event.name.as_deref());
+        (self.kinds.is_empty() || self.kinds.contains(&event.kind)) && self.matches_name(name)
+    }
+}
+
+/// 可以在总线上发布的事件
+pub trait BusEvent: Clone + Send + 'static {
+    /// 记录发布时分配的序号，作为重放的游标
+    fn set_seq(&mut self, _seq: u64) {}
+
+    /// 发给订阅者之前写入事件日志
+    fn journal(&self) {}
+
+    /// 队列满了丢弃最旧的事件后，在下一个事件之前补发的丢失通知
+    fn lagged(_dropped: u64) -> Option<Self> {
+        None
+    }
+
+    /// 过滤条件长时间没有匹配时发给订阅者的心跳，用来发现已经关闭的订阅者
+    fn keepalive(_now: i64) -> Option<Self> {
+        None
+    }
+}
+
+impl BusEvent for TrackerEvent {
+    fn set_seq(&mut self, seq: u64) {
+        self.seq = seq;
+    }
+
+    fn journal(&self) {
+        journal::append(&JournalEntry::Event {
+            event: Box::new(self.clone()),
+        });
+    }
+
+    fn lagged(dropped: u64) -> Option<Self> {
+        let now = chrono::Local::now().timestamp_millis();
+        Some(TrackerEvent {
+            dropped: Some(dropped),
+            ..TrackerEvent::new(TrackerEventKind::Lagged, now)
+        })
+    }
+
+    fn keepalive(now: i64) -> Option<Self> {
+        Some(TrackerEvent::new(TrackerEventKind::Heartbeat, now))
+    }
+}
+
+// 前台采样连同资源占用在记录会话时写入日志
+impl BusEvent for Application {}
+
+/// 订阅时重放哪些最近的事件
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Replay {
+    None,
+    After(u64), // 序号大于游标的事件
+    Since(i64), // 在这个时间（unix 毫秒）及之后发布的事件
+}
+
+/// 订阅者的有界队列，由发布方写入、投递线程取出
+struct Queue<T> {
+    events: VecDeque<(u64, T)>, // (序号, 事件)，总线生成的丢失通知和心跳序号为 0
+    dropped: u64,               // 队列满了丢弃、还没有通知订阅者的事件数量
+    closed: bool,               // 对方已关闭，投递线程已退出
+    detached: bool,             // 已从总线移除，投递线程取完剩余事件后退出
+}
+
+type SharedQueue<T> = Arc<(Mutex<Queue<T>>, Condvar)>;
+
+struct Subscriber<T> {
+    id: u64,
+    matches: Box<dyn Fn(&T) -> bool + Send>,
+    capacity: usize,
+    queue: SharedQueue<T>,
+    last_sent: i64, // 最近一次入队的时间（unix 毫秒）
+}
+
+impl<T> Subscriber<T> {
+    /// 加入队列，满了丢弃最旧的事件
+    fn push(&mut self, seq: u64, event: T, now: i64) {
+        let (queue, ready) = &*self.queue;
+        let mut queue = queue.lock().unwrap();
+        if queue.events.len() >= self.capacity {
+            queue.events.pop_front();
+            if queue.dropped == 0 {
+                println!(
+                    "[bus] subscriber {} is too slow, dropping oldest events",
+                    self.id
+                );
+            }
+            queue.dropped += 1;
+        }
+        queue.events.push_back((seq, event));
+        self.last_sent = now;
+        ready.notify_one();
+    }
+
+    fn is_closed(&self) -> bool {
+        self.queue.0.lock().unwrap().closed
+    }
+}
+
+impl<T> Drop for Subscriber<T> {
+    fn drop(&mut self) {
+        let (queue, ready) = &*self.queue;
+        queue.lock().unwrap().detached = true;
+        ready.notify_one();
+    }
+}
+
+/// 投递线程：按顺序取出事件发给订阅者，有丢失时先发丢失通知，投递成功后推进 client 的游标
+fn run_delivery<T: BusEvent>(
+    queue: SharedQueue<T>,
+    cursor: Option<Arc<AtomicU64>>,
+    mut deliver: impl FnMut(T) -> bool,
+) {
+    let (lock, ready) = &*queue;
+    loop {
+        let (dropped, (seq, event)) = {
+            let mut queue = lock.lock().unwrap();
+            while queue.events.is_empty() && !queue.detached {
+                queue = ready.wait(queue).unwrap();
+            }
+            let Some(event) = queue.events.pop_front() else {
+                return;
+            };
+            (std::mem::take(&mut queue.dropped), event)
+        };
+        let lagged = match T::lagged(dropped).filter(|_| dropped > 0) {
+            Some(notice) => deliver(notice),
+            None => true,
+        };
+        if !lagged || !deliver(event) {
+            lock.lock().unwrap().closed = true;
+            return;
+        }
+        if let Some(cursor) = &cursor {
+            cursor.fetch_max(seq, Ordering::SeqCst);
+        }
+    }
+}
+
+/// 最近发布的一个事件
+struct Recorded<T> {
+    seq: u64,
+    published_at: i64,
+    event: T,
+}
+
+struct BusState<T> {
+    seq: u64, // 已经记录到最近事件的最后一个序号
+    subscribers: Vec<Subscriber<T>>,
+    history: VecDeque<Recorded<T>>, // 没有订阅者时也保留，UI 重新连接后补发
+}
+
+pub struct EventBus<T> {
+    next_id: AtomicU64,
+    clients: Mutex<HashMap<String, Arc<AtomicU64>>>, // 每个 client 已经收到的最大序号
+    restored: AtomicU64, // 从日志恢复的最后一个序号，新的 client 从这里之后开始接收
+    history_size: usize,
+    // 发布顺序：分配序号、写入日志和投递都在这把锁内完成，写日志时不持有 state，
+    // 订阅、查询不会等待磁盘同步
+    published: Mutex<u64>,
+    state: Mutex<BusState<T>>,
+}
+
+impl<T: BusEvent> EventBus<T> {
+    pub fn new(history_size: usize) -> EventBus<T> {
+        EventBus {
+            next_id: AtomicU64::new(1),
+            clients: Mutex::new(HashMap::new()),
+            restored: AtomicU64::new(0),
+            history_size,
+            published: Mutex::new(0),
+            state: Mutex::new(BusState {
+                seq: 0,
+                subscribers: Vec::new(),
+                history: VecDeque::new(),
+            }),
+        }
+    }
+
+    /// 添加订阅者，先补发 replay 指定的最近事件；deliver 返回 false 表示对方已经关闭，之后不再投递
+    pub fn subscribe(
+        &self,
+        matches: impl Fn(&T) -> bool + Send + 'static,
+        capacity: usize,
+        replay: Replay,
+        deliver: impl FnMut(T) -> bool + Send + 'static,
+    ) {
+        self.add(matches, capacity, replay, None, deliver);
+    }
+
+    /// 以 client 的身份订阅：先补发这个 client 之前的订阅断开后错过的事件，
+    /// 第一次订阅时补发这次运行中发布的事件
+    pub fn subscribe_client(
+        &self,
+        client: &str,
+        matches: impl Fn(&T) -> bool + Send + 'static,
+        capacity: usize,
+        deliver: impl FnMut(T) -> bool + Send + 'static,
+    ) {
+        let cursor = self
+            .clients
+            .lock()
+            .unwrap()
+            .entry(client.to_string())
+            .or_insert_with(|| Arc::new(AtomicU64::new(self.restored.load(Ordering::SeqCst))))
+            .clone();
+        let replay = Replay::After(cursor.load(Ordering::SeqCst));
+        self.add(matches, capacity, replay, Some(cursor), deliver);
+    }
+
+    fn add(
+        &self,
+        matches: impl Fn(&T) -> bool + Send + 'static,
+        capacity: usize,
+        replay: Replay,
+        cursor: Option<Arc<AtomicU64>>,
+        deliver: impl FnMut(T) -> bool + Send + 'static,
+    ) {
+        let id = self.next_id.fetch_add(1, Ordering::SeqCst);
+        // 持有锁直到加入订阅者，补发和实时事件之间不会重复或遗漏
+        let mut state = self.state.lock().unwrap();
+        let backlog: Vec<(u64, T)> = state
+            .history
+            .iter()
+            .filter(|r| match replay {
+                Replay::None => false,
+                Replay::After(cursor) => r.seq > cursor,
+                Replay::Since(ts) => r.published_at >= ts,
+            })
+            .filter(|r| matches(&r.event))
+            .map(|r| (r.seq, r.event.clone()))
+            .collect();
+        let capacity = capacity.max(1) + backlog.len();
+        let queue: SharedQueue<T> = Arc::new((
+            Mutex::new(Queue {
+                events: backlog.into(),
+                dropped: 0,
+                closed: false,
+                detached: false,
+            }),
+            Condvar::new(),
+        ));
+        let worker = queue.clone();
+        std::thread::spawn(move || run_delivery(worker, cursor, deliver));
+        state.subscribers.push(Subscriber {
+            id,
+            matches: Box::new(matches),
+            capacity,
+            queue,
+            last_sent: chrono::Local::now().timestamp_millis(),
+        });
+    }
+
+    /// 写入日志、记录到最近事件并发给所有匹配的订阅者，给长时间没有事件的订阅者发心跳，
+    /// 移除已关闭的订阅者（不论过滤条件是否匹配）
+    pub fn publish(&self, mut event: T) {
+        let mut published = self.published.lock().unwrap();
+        *published += 1;
+        let seq = *published;
+        event.set_seq(seq);
+        event.journal();
+
+        let mut state = self.state.lock().unwrap();
+        let published_at = chrono::Local::now().timestamp_millis();
+        state.seq = seq;
+        state.subscribers.retain_mut(|s| {
+            if s.is_closed() {
+                println!("[bus] subscriber {} closed, removed", s.id);
+                return false;
+            }
+            if (s.matches)(&event) {
+                s.push(seq, event.clone(), published_at);
+            } else if published_at - s.last_sent >= KEEPALIVE_MS {
+                if let Some(heartbeat) = T::keepalive(published_at) {
+                    s.push(0, heartbeat, published_at);
+                }
+            }
+            true
+        });
+        state.history.push_back(Recorded {
+            seq,
+            published_at,
+            event,
+        });
+        if state.history.len() > self.history_size {
+            state.history.pop_front();
+        }
+    }
+
+    /// 最后发布的事件序号，还没有事件时为 0
+    pub fn cursor(&self) -> u64 {
+        self.state.lock().unwrap().seq
+    }
+
+    /// 用日志中的 (序号, 发布时间, 事件) 恢复最近事件和序号，只能在发布第一个事件之前调用
+    pub fn restore(&self, records: Vec<(u64, i64, T)>) {
+        let mut published = self.published.lock().unwrap();
+        if *published != 0 {
+            println!("[bus] events already published, skip restoring");
+            return;
+        }
+        let mut state = self.state.lock().unwrap();
+        let skip = records.len().saturating_sub(self.history_size);
+        state.seq = records.iter().map(|r| r.0).max().unwrap_or_default();
+        *published = state.seq;
+        state.history = records
+            .into_iter()
+            .skip(skip)
+            .map(|(seq, published_at, event)| Recorded {
+                seq,
+                published_at,
+                event,
+            })
+            .collect();
+        // 恢复之前就订阅的 client 不补发上一次运行的事件
+        self.restored.store(state.seq, Ordering::SeqCst);
+        for cursor in self.clients.lock().unwrap().values() {
+            cursor.fetch_max(state.seq, Ordering::SeqCst);
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.state.lock().unwrap().subscribers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// 追踪事件
+pub static EVENTS: Lazy<EventBus<TrackerEvent>> = Lazy::new(|| EventBus::new(HISTORY_SIZE));
+
+/// 前台应用采样
+pub static APPLICATIONS: Lazy<EventBus<Application>> = Lazy::new(|| EventBus::new(HISTORY_SIZE));
+
+/// 用事件日志恢复两条总线的最近事件：追踪事件使用日志中的序号，采样按顺序编号
+pub fn restore(entries: &[JournalEntry]) {
+    let mut samples = Vec::new();
+    let mut events = Vec::new();
+    for entry in entries {
+        match entry {
+            JournalEntry::Sample { at, app, .. } => {
+                samples.push((samples.len() as u64 + 1, *at, (**app).clone()))
+            }
+            JournalEntry::Event { event } => {
+                events.push((event.seq, event.emitted_at, (**event).clone()))
+            }
+            JournalEntry::Opened { .. } => {}
+        }
+    }
+    APPLICATIONS.restore(samples);
+    EVENTS.restore(events);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::mpsc;
+    use std::time::{Duration, Instant};
+
+    // 丢失通知为 1000 加丢失数量，心跳为 0
+    impl BusEvent for u32 {
+        fn lagged(dropped: u64) -> Option<u32> {
+            Some(1000 + dropped as u32)
+        }
+
+        fn keepalive(_now: i64) -> Option<u32> {
+            Some(0)
+        }
+    }
+
+    fn event(kind: TrackerEventKind, name: &str) -> TrackerEvent {
+        TrackerEvent {
+            session: Some(crate::spy::event::SessionSummary {
+                id: 1,
+                name: name.to_string(),
+                title: String::new(),
+                start: 0,
+                end: 0,
+                activity: None,
+            }),
+            ..TrackerEvent::new(kind, 0)
+        }
+    }
+
+    #[test]
+    fn test_filtered_subscribers() {
+        let bus = EventBus::new(HISTORY_SIZE);
+        let (all_tx, all) = mpsc::channel();
+        let (code_tx, code) = mpsc::channel();
+        bus.subscribe(
+            |_| true,
+            QUEUE_SIZE,
+            Replay::None,
+            move |e: TrackerEvent| all_tx.send(e.kind).is_ok(),
+        );
+        let filter = EventFilter {
+            kinds: vec![TrackerEventKind::SessionEnded],
+            apps: vec!["code".to_string()],
+        };
+        bus.subscribe(
+            move |e| filter.matches(e),
+            QUEUE_SIZE,
+            Replay::None,
+            move |e: TrackerEvent| code_tx.send(e.kind).is_ok(),
+        );
+
+        bus.publish(event(TrackerEventKind::SessionEnded, "code"));
+        bus.publish(event(TrackerEventKind::SessionEnded, "firefox"));
+        bus.publish(TrackerEvent::new(TrackerEventKind::Idle, 0));
+
+        let timeout = Duration::from_secs(1);
+        assert_eq!(all.iter().take(3).count(), 3);
+        assert_eq!(
+            code.recv_timeout(timeout),
+            Ok(TrackerEventKind::SessionEnded)
+        );
+        assert!(code.recv_timeout(Duration::from_millis(100)).is_err());
+        assert_eq!(bus.len(), 2);
+    }
+
+    #[test]
+    fn test_slow_subscriber_drops_oldest_events() {
+        let bus = EventBus::new(HISTORY_SIZE);
+        let (started_tx, started) = mpsc::channel();
+        let (release, blocked) = mpsc::channel::<()>();
+        let (tx, rx) = mpsc::channel();
+        bus.subscribe(
+            |_| true,
+            2,
+            Replay::None,
+            move |e: u32| {
+                if e == 1 {
+                    started_tx.send(()).unwrap();
+                    blocked.recv().unwrap();
+                }
+                tx.send(e).is_ok()
+            },
+        );
+        bus.publish(1);
+        // 订阅者阻塞在第一个事件上时，队列只保留最新的两个事件
+        started.recv_timeout(Duration::from_secs(1)).unwrap();
+        for i in 2..=5 {
+            bus.publish(i);
+        }
+        assert_eq!(bus.len(), 1);
+        release.send(()).unwrap();
+        let received: Vec<u32> = rx.iter().take(4).collect();
+        assert_eq!(received, [1, 1002, 4, 5]);
+    }
+
+    #[test]
+    fn test_remove_closed_subscriber_with_narrow_filter() {
+        let bus = EventBus::new(HISTORY_SIZE);
+        // 只接收事件 1，收到后关闭
+        bus.subscribe(|e| *e == 1, QUEUE_SIZE, Replay::None, |_: u32| false);
+        bus.publish(1);
+        // 之后的事件都不匹配它的过滤条件，也要把它移除
+        let deadline = Instant::now() + Duration::from_secs(1);
+        while !bus.is_empty() && Instant::now() < deadline {
+            bus.publish(2);
+            std::thread::yield_now();
+        }
+        assert!(bus.is_empty());
+    }
+
+    #[test]
+    fn test_replay_recent_events() {
+        let bus = EventBus::new(3);
+        // 没有订阅者时发布的事件保留最近 3 个
+        for i in 1..=5 {
+            bus.publish(TrackerEvent::new(TrackerEventKind::TitleChanged, i));
+        }
+        assert_eq!(bus.cursor(), 5);
+
+        let (tx, rx) = mpsc::channel();
+        bus.subscribe(
+            |_| true,
+            QUEUE_SIZE,
+            Replay::After(3),
+            move |e: TrackerEvent| tx.send(e.seq).is_ok(),
+        );
+        bus.publish(TrackerEvent::new(TrackerEventKind::Idle, 6));
+        let seqs: Vec<u64> = rx.iter().take(3).collect();
+        assert_eq!(seqs, [4, 5, 6]);
+
+        let (tx, rx) = mpsc::channel();
+        bus.subscribe(
+            |_| true,
+            QUEUE_SIZE,
+            Replay::Since(0),
+            move |e: TrackerEvent| tx.send(e.seq).is_ok(),
+        );
+        let seqs: Vec<u64> = rx.iter().take(3).collect();
+        assert_eq!(seqs, [4, 5, 6]);
+    }
+
+    #[test]
+    fn test_resume_client_cursor() {
+        let bus = EventBus::new(HISTORY_SIZE);
+        // 上一次运行的事件，新的 client 不补发
+        bus.restore(vec![(1, 0, 1), (2, 0, 2)]);
+        assert_eq!(bus.cursor(), 2);
+
+        // 托盘一直在线，主界面收到事件 4 时已经关闭（热重启）
+        let (tray_tx, tray) = mpsc::channel();
+        bus.subscribe_client(
+            "tray",
+            |_| true,
+            QUEUE_SIZE,
+            move |e: u32| tray_tx.send(e).is_ok(),
+        );
+        let (tx, rx) = mpsc::channel();
+        bus.subscribe_client(
+            "main",
+            |_| true,
+            QUEUE_SIZE,
+            move |e: u32| {
+                tx.send(e).unwrap();
+                e < 4
+            },
+        );
+        for i in 3..=4 {
+            bus.publish(i);
+        }
+        assert_eq!(rx.iter().take(2).collect::<Vec<u32>>(), [3, 4]);
+        assert_eq!(tray.iter().take(2).collect::<Vec<u32>>(), [3, 4]);
+        bus.publish(5);
+        assert_eq!(tray.recv_timeout(Duration::from_secs(1)), Ok(5));
+
+        // 托盘推进的序号不影响主界面的补发
+        let (tx, rx) = mpsc::channel();
+        bus.subscribe_client(
+            "main",
+            |_| true,
+            QUEUE_SIZE,
+            move |e: u32| tx.send(e).is_ok(),
+        );
+        assert_eq!(rx.iter().take(2).collect::<Vec<u32>>(), [4, 5]);
+    }
+}
@@ -0,0 +1,152 @@
+//! 已安装应用清单
+//!
+//! 列出系统中安装的应用，供应用目录在应用获得焦点前预先设置分类和截图等选项。
+//! Linux 上扫描 XDG 数据目录、Flatpak 导出目录和 Snap 的 `.desktop` 文件，
+//! 并根据 freedesktop `Categories=` 给出 work / study / joy 分类建议。
+
+use serde::{Deserialize, Serialize};
+
+/// 应用分类，与界面的 `IAppTypes` 一致
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum AppCategory {
+    Work,
+    Study,
+    Joy,
+    Others,
+    Unknown,
+}
+
+/// 一个已安装的应用
+#[derive(Debug, Clone, PartialEq)]
+pub struct InstalledApp {
+    pub id: String,              // desktop-file id，与 Application.app_id 一致
+    pub name: String,            // 显示名称
+    pub exec: Option<String>,    // 可执行文件名
+    pub icon: Option<String>,    // 已提取的图标 id，没有时通过 installed_app_icon 按需提取
+    pub categories: Vec<String>, // freedesktop 分类
+    pub suggested: AppCategory,  // 根据分类给出的建议
+    pub source: Option<String>,  // 安装来源（flatpak、snap），系统包为空
+}
+
+/// freedesktop 分类到应用分类的映射，靠前的优先，
+/// 例如同时带有 Education 和 Game 的教育游戏归为 study
+const CATEGORY_RULES: &[(&str, AppCategory)] = &[
+    ("Education", AppCategory::Study),
+    ("Science", AppCategory::Study),
+    ("Math", AppCategory::Study),
+    ("Languages", AppCategory::Study),
+    ("Dictionary", AppCategory::Study),
+    ("Literature", AppCategory::Study),
+    ("ComputerScience", AppCategory::Study),
+    ("Game", AppCategory::Joy),
+    ("Development", AppCategory::Work),
+    ("IDE", AppCategory::Work),
+    ("Office", AppCategory::Work),
+    ("ProjectManagement", AppCategory::Work),
+    ("TerminalEmulator", AppCategory::Work),
+    ("Email", AppCategory::Work),
+    ("Calendar", AppCategory::Work),
+    ("Finance", AppCategory::Work),
+    ("AudioVideo", AppCategory::Joy),
+    ("Audio", AppCategory::Joy),
+    ("Video", AppCategory::Joy),
+    ("Player", AppCategory::Joy),
+    ("Music", AppCategory::Joy),
+    ("TV", AppCategory::Joy),
+    ("Amusement", AppCategory::Joy),
+];
+
+/// 根据 freedesktop 分类建议应用分类，没有分类时为 Unknown
+pub fn suggest_category(categories: &[String]) -> AppCategory {
+    if categories.is_empty() {
+        return AppCategory::Unknown;
+    }
+    CATEGORY_RULES
+        .iter()
+        .find(|(name, _)| categories.iter().any(|c| c == name))
+        .map(|(_, category)| *category)
+        .unwrap_or(AppCategory::Others)
+}
+
+/// 列出已安装的应用，按名称排序；会重新扫描 desktop 文件。
+/// 不在这里栅格化图标，只带上已经提取过的图标 id
+#[cfg(target_os = "linux")]
+pub fn list_installed_applications() -> Vec<InstalledApp> {
+    use crate::spy::platform::desktop_entry;
+
+    desktop_entry::refresh();
+    let mut apps: Vec<InstalledApp> = desktop_entry::entries()
+        .into_iter()
+        .filter(|e| !e.no_display)
+        .map(|e| {
+            // 与前台采样使用相同的 key，已提取过的直接复用
+            let icon = crate::spy::icon::icon_for(&e.id, || None);
+            let path = e.path.to_string_lossy();
+            let source = if path.contains("/flatpak/exports/") {
+                Some("flatpak".to_string())
+            } else if path.starts_with("/var/lib/snapd/") {
+                Some("snap".to_string())
+            } else {
+                None
+            };
+            InstalledApp {
+                suggested: suggest_category(&e.categories),
+                id: e.id,
+                name: e.name,
+                exec: e.exec,
+                icon,
+                categories: e.categories,
+                source,
+            }
+        })
+        .collect();
+    apps.sort_by_key(|a| a.name.to_lowercase());
+    apps
+}
+
+/// 其他平台暂不支持枚举已安装应用
+#[cfg(not(target_os = "linux"))]
+pub fn list_installed_applications() -> Vec<InstalledApp> {
+    Vec::new()
+}
+
+/// 按需提取已安装应用的图标，返回图标 id；同一应用只提取一次
+#[cfg(target_os = "linux")]
+pub fn installed_app_icon(id: &str) -> Option<String> {
+    use crate::spy::platform::{desktop_entry, icon_theme};
+
+    crate::spy::icon::icon_for(id, || {
+        let name = desktop_entry::by_id(id)?.icon?;
+        icon_theme::icon_png(&name, 128)
+    })
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn installed_app_icon(_id: &str) -> Option<String> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn categories(s: &str) -> Vec<String> {
+        s.split(';')
+            .filter(|c| !c.is_empty())
+            .map(|c| c.to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_suggest_category() {
+        let suggest = |s| suggest_category(&categories(s));
+        assert_eq!(suggest("Development;IDE;"), AppCategory::Work);
+        assert_eq!(suggest("Office;WordProcessor;"), AppCategory::Work);
+        assert_eq!(suggest("Education;Math;"), AppCategory::Study);
+        assert_eq!(suggest("Game;Education;"), AppCategory::Study);
+        assert_eq!(suggest("Game;ArcadeGame;"), AppCategory::Joy);
+        assert_eq!(suggest("AudioVideo;Player;"), AppCategory::Joy);
+        assert_eq!(suggest("Network;WebBrowser;"), AppCategory::Others);
+        assert_eq!(suggest(""), AppCategory::Unknown);
+    }
+}
@@ -0,0 +1,256 @@
+//! 追踪事件
+//!
+//! 统一的事件流：焦点切换、标题变化、会话结束、截图成功或失败、离开和回来、会议开始和结束、
+//! 应用启动和退出以及后台错误。订阅者还会收到总线生成的丢失通知和心跳，它们不分配序号。
+//! 每个事件带有 schema 版本号，事件结构变化时递增，Dart 端据此兼容旧版本保存的事件。
+
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use crate::spy::input::ActivityLevel;
+use crate::spy::lifecycle::{AppLifecycleEvent, LifecycleKind};
+use crate::spy::meeting::{MeetingEvent, MeetingEventKind};
+use crate::spy::model::Application;
+use crate::spy::session::Session;
+
+/// 事件结构版本
+pub const SCHEMA_VERSION: u32 = 5;
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum TrackerEventKind {
+    FocusChanged,     // 前台应用切换
+    TitleChanged,     // 同一应用的窗口标题变化
+    SessionEnded,     // 前台会话结束
+    ScreenshotTaken,  // 截图已保存
+    ScreenshotFailed, // 截图失败
+    Idle,             // 用户离开
+    Resumed,          // 用户回来
+    MeetingStarted,   // 会议开始
+    MeetingEnded,     // 会议结束
+    AppLaunched,      // 应用的第一个进程启动
+    AppExited,        // 应用的最后一个进程退出
+    Error,            // 后台任务出错
+    Lagged,           // 订阅者处理太慢，丢弃了部分事件
+    Heartbeat,        // 长时间没有匹配的事件时的心跳
+}
+
+/// 结束的会话
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SessionSummary {
+    pub id: u64,
+    pub name: String,
+    pub title: String,
+    pub start: i64,
+    pub end: i64,
+    pub activity: Option<ActivityLevel>, // 会话的活跃程度，没有输入监控时为空
+}
+
+/// 追踪事件，按 kind 填写对应字段，其余为空
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrackerEvent {
+    pub schema_version: u32,
+    pub seq: u64, // 发布时分配的递增序号，用作补发事件的游标
+    pub kind: TrackerEventKind,
+    pub timestamp: i64,  // 事件发生时间（unix 毫秒），离开事件为最后一次输入的时间
+    pub emitted_at: i64, // 事件发出时间（unix 毫秒）
+    pub app: Option<Application>, // FocusChanged、TitleChanged、ScreenshotTaken
+    pub session: Option<SessionSummary>, // SessionEnded
+    pub path: Option<String>, // ScreenshotTaken：截图保存路径
+    pub source: Option<String>, // ScreenshotFailed、Error：出错的模块
+    pub message: Option<String>, // ScreenshotFailed、Error：错误信息
+    pub idle_seconds: Option<i64>, // Idle：已离开的秒数；Resumed：离开总时长
+    pub name: Option<String>, // MeetingStarted、MeetingEnded：会议应用；AppLaunched、AppExited：应用名称
+    pub meeting_id: Option<u64>, // MeetingStarted、MeetingEnded
+    pub pid: Option<u32>,     // AppLaunched、AppExited：第一个启动或最后一个退出的进程
+    pub dropped: Option<u64>, // Lagged：丢弃的事件数量
+}
+
+impl TrackerEvent {
+    pub fn new(kind: TrackerEventKind, timestamp: i64) -> TrackerEvent {
+        TrackerEvent {
+            schema_version: SCHEMA_VERSION,
+            seq: 0,
+            kind,
+            timestamp,
+            emitted_at: chrono::Local::now().timestamp_millis(),
+            app: None,
+            session: None,
+            path: None,
+            source: None,
+            message: None,
+            idle_seconds: None,
+            name: None,
+            meeting_id: None,
+            pid: None,
+            dropped: None,
+        }
+    }
+
+    pub fn app(kind: TrackerEventKind, app: &Application, timestamp: i64) -> TrackerEvent {
+        TrackerEvent {
+            app: Some(app.clone()),
+            ..TrackerEvent::new(kind, timestamp)
+        }
+    }
+
+    pub fn session_ended(session: &Session) -> TrackerEvent {
+        TrackerEvent {
+            session: Some(SessionSummary {
+                id: session.id,
+                name: session.name.clone(),
+                title: session.title.clone(),
+                start: session.start,
+                end: session.end,
+                activity: session.activity,
+            }),
+            ..TrackerEvent::new(TrackerEventKind::SessionEnded, session.end)
+        }
+    }
+
+    pub fn screenshot_taken(app: &Application, path: &str, timestamp: i64) -> TrackerEvent {
+        TrackerEvent {
+            path: Some(path.to_string()),
+            ..TrackerEvent::app(TrackerEventKind::ScreenshotTaken, app, timestamp)
+        }
+    }
+
+    pub fn failed(kind: TrackerEventKind, source: &str, message: impl ToString) -> TrackerEvent {
+        let now = chrono::Local::now().timestamp_millis();
+        TrackerEvent {
+            source: Some(source.to_string()),
+            message: Some(message.to_string()),
+            ..TrackerEvent::new(kind, now)
+        }
+    }
+
+    pub fn idle(kind: TrackerEventKind, timestamp: i64, idle_seconds: i64) -> TrackerEvent {
+        TrackerEvent {
+            idle_seconds: Some(idle_seconds),
+            ..TrackerEvent::new(kind, timestamp)
+        }
+    }
+
+    pub fn meeting(event: &MeetingEvent) -> TrackerEvent {
+        let kind = match event.kind {
+            MeetingEventKind::Started => TrackerEventKind::MeetingStarted,
+            MeetingEventKind::Ended => TrackerEventKind::MeetingEnded,
+        };
+        TrackerEvent {
+            name: event.app.clone(),
+            meeting_id: Some(event.meeting_id),
+            ..TrackerEvent::new(kind, event.timestamp)
+        }
+    }
+
+    pub fn lifecycle(event: &AppLifecycleEvent) -> TrackerEvent {
+        let kind = match event.kind {
+            LifecycleKind::Launched => TrackerEventKind::AppLaunched,
+            LifecycleKind::Exited => TrackerEventKind::AppExited,
+        };
+        TrackerEvent {
+            name: Some(event.name.clone()),
+            pid: Some(event.pid),
+            ..TrackerEvent::new(kind, event.timestamp)
+        }
+    }
+}
+
+/// 根据前后两次采样判断是切换了应用还是只改了标题
+#[derive(Default)]
+pub struct FocusTracker {
+    last: Option<(String, String)>, // (应用名称, 窗口标题)
+}
+
+impl FocusTracker {
+    pub fn update(&mut self, app: &Application) -> Option<TrackerEventKind> {
+        let kind = match &self.last {
+            Some((name, _)) if name != &app.name => Some(TrackerEventKind::FocusChanged),
+            Some((_, title)) if title != &app.title => Some(TrackerEventKind::TitleChanged),
+            Some(_) => None,
+            None => Some(TrackerEventKind::FocusChanged),
+        };
+        self.last = Some((app.name.clone(), app.title.clone()));
+        kind
+    }
+
+    /// 用户离开后忘记上一次采样，回来时重新发出焦点事件
+    pub fn reset(&mut self) {
+        self.last = None;
+    }
+}
+
+pub static FOCUS: Lazy<Mutex<FocusTracker>> = Lazy::new(|| Mutex::new(FocusTracker::default()));
+
+pub fn emit(event: TrackerEvent) {
+    if matches!(
+        event.kind,
+        TrackerEventKind::Error | TrackerEventKind::ScreenshotFailed
+    ) {
+        println!(
+            "[event] {:?} {}: {}",
+            event.kind,
+            event.source.as_deref().unwrap_or_default(),
+            event.message.as_deref().unwrap_or_default()
+        );
+    }
+    crate::spy::bus::EVENTS.publish(event);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn app(name: &str, title: &str) -> Application {
+        Application {
+            name: name.to_string(),
+            title: title.to_string(),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_focus_and_title_changes() {
+        let mut focus = FocusTracker::default();
+        let first = focus.update(&app("code", "main.rs"));
+        assert_eq!(first, Some(TrackerEventKind::FocusChanged));
+        assert_eq!(focus.update(&app("code", "main.rs")), None);
+        assert_eq!(
+            focus.update(&app("code", "lib.rs")),
+            Some(TrackerEventKind::TitleChanged)
+        );
+        assert_eq!(
+            focus.update(&app("firefox", "lib.rs")),
+            Some(TrackerEventKind::FocusChanged)
+        );
+        focus.reset();
+        assert_eq!(
+            focus.update(&app("firefox", "lib.rs")),
+            Some(TrackerEventKind::FocusChanged)
+        );
+
+        let event = TrackerEvent::screenshot_taken(&app("code", "a"), "/tmp/a.png", 5);
+        assert_eq!(event.schema_version, SCHEMA_VERSION);
+        assert_eq!(event.kind, TrackerEventKind::ScreenshotTaken);
+        assert_eq!(event.app.unwrap().name, "code");
+        assert_eq!(event.path.as_deref(), Some("/tmp/a.png"));
+
+        let event = TrackerEvent::lifecycle(&AppLifecycleEvent {
+            kind: LifecycleKind::Exited,
+            name: "firefox".to_string(),
+            pid: 42,
+            timestamp: 7,
+        });
+        assert_eq!(event.kind, TrackerEventKind::AppExited);
+        assert_eq!(
+            (event.name.as_deref(), event.pid),
+            (Some("firefox"), Some(42))
+        );
+        let filter = crate::spy::bus::EventFilter {
+            kinds: Vec::new(),
+            apps: vec!["firefox".to_string()],
+        };
+        assert!(filter.matches(&event));
+    }
+}
@@ -0,0 +1,275 @@
+//! 通过直接读取 `.git/HEAD` 解析路径所在的 git 仓库与当前分支，不调用 git 命令。
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use crate::spy::session::Session;
+
+/// 仓库信息
+#[derive(Debug, Clone, PartialEq)]
+pub struct GitInfo {
+    pub root: PathBuf,          // 工作区根目录
+    pub name: String,           // 仓库名（主工作区目录名）
+    pub branch: Option<String>, // 当前分支，分离 HEAD 时为提交哈希前 7 位
+}
+
+/// 按仓库和分支统计的前台时间
+#[derive(Debug, Clone)]
+pub struct RepositoryTime {
+    pub repo: String, // 仓库根目录
+    pub name: String, // 仓库名
+    pub branch: Option<String>,
+    pub seconds: f64,
+}
+
+/// 从路径向上查找所在仓库
+pub fn resolve(path: &Path) -> Option<GitInfo> {
+    let mut dir = if path.is_dir() {
+        path.to_path_buf()
+    } else {
+        path.parent()?.to_path_buf()
+    };
+
+    loop {
+        let dot_git = dir.join(".git");
+        if dot_git.is_dir() {
+            return read_repository(&dir, &dot_git);
+        }
+        if dot_git.is_file() {
+            // worktree / submodule：.git 是一个指向真实 git 目录的文件
+            let content = std::fs::read_to_string(&dot_git).ok()?;
+            let gitdir = content.trim().strip_prefix("gitdir:")?.trim();
+            return read_repository(&dir, &dir.join(gitdir));
+        }
+        if !dir.pop() {
+            return None;
+        }
+    }
+}
+
+fn read_repository(root: &Path, git_dir: &Path) -> Option<GitInfo> {
+    let head = std::fs::read_to_string(git_dir.join("HEAD")).ok()?;
+    let head = head.trim();
+    let branch = match head.strip_prefix("ref:") {
+        Some(r) => {
+            let r = r.trim();
+            Some(r.strip_prefix("refs/heads/").unwrap_or(r).to_string())
+        }
+        // 分离 HEAD 时使用提交号前 7 位，内容损坏时按字节截取可能落在多字节字符中间
+        None => head.get(..7).map(|h| h.to_string()),
+    };
+
+    // linked worktree 的 commondir 指向主仓库的 .git 目录，用它的上级目录命名
+    let main_root = std::fs::read_to_string(git_dir.join("commondir"))
+        .ok()
+        .map(|c| git_dir.join(c.trim()))
+        .and_then(|common| common.canonicalize().ok())
+        .and_then(|common| common.parent().map(|p| p.to_path_buf()))
+        .unwrap_or_else(|| root.to_path_buf());
+    let name = main_root.file_name()?.to_string_lossy().into_owned();
+
+    Some(GitInfo {
+        root: root.to_path_buf(),
+        name,
+        branch,
+    })
+}
+
+/// 从窗口标题中提取看起来像路径的片段，例如
+/// `main.rs (~/code/spy/src) - VIM`、`user@host: ~/code/spy`
+pub fn paths_from_title(title: &str) -> Vec<PathBuf> {
+    let home = std::env::var_os("HOME").map(PathBuf::from);
+    title
+        .split(|c: char| c.is_whitespace() || "()[]<>\"'|•—".contains(c))
+        .filter_map(|token| {
+            let token = token.trim_end_matches([',', ';', ':', '*']);
+            if is_windows_path(token) {
+                return Some(PathBuf::from(token));
+            }
+            // 去掉 `user@host:` 前缀
+            let token = token.rsplit_once('@').map(|(_, t)| t).unwrap_or(token);
+            let token = token.split_once(':').map_or(token, |(host, rest)| {
+                if rest.starts_with('/') || rest.starts_with('~') {
+                    rest
+                } else {
+                    host
+                }
+            });
+            if let Some(rest) = token.strip_prefix("~/") {
+                home.as_ref().map(|h| h.join(rest))
+            } else if token == "~" {
+                home.clone()
+            } else if token.starts_with('/') && token.len() > 1 {
+                Some(PathBuf::from(token))
+            } else {
+                None
+            }
+        })
+        .collect()
+}
+
+fn is_windows_path(token: &str) -> bool {
+    let bytes = token.as_bytes();
+    bytes.len() > 3 && bytes[0].is_ascii_alphabetic() && bytes[1] == b':' && bytes[2] == b'\\'
+}
+
+/// 为前台应用解析仓库：依次尝试标题中的路径、终端的 shell 工作目录和进程工作目录
+pub fn attribute(
+    app_name: &str,
+    app_path: &str,
+    title: &str,
+    process_cwd: Option<&Path>,
+) -> Option<GitInfo> {
+    let mut candidates = paths_from_title(title);
+    if crate::spy::shell::is_terminal_app(app_name, app_path) {
+        if let Some(cwd) = crate::spy::shell::latest_cwd() {
+            candidates.push(PathBuf::from(cwd));
+        }
+    }
+    if let Some(cwd) = process_cwd {
+        candidates.push(cwd.to_path_buf());
+    }
+
+    candidates
+        .iter()
+        .find_map(|p| resolve(&nearest_existing(p)?))
+}
+
+/// 标题中的文件可能尚未保存，取最近的已存在的上级路径
+fn nearest_existing(path: &Path) -> Option<PathBuf> {
+    path.ancestors()
+        .find(|p| p.exists())
+        .map(|p| p.to_path_buf())
+}
+
+/// 统计 [start, end]（unix 毫秒）内按仓库、分支归属的前台时间
+pub fn repository_time(sessions: &[Session], start: i64, end: i64) -> Vec<RepositoryTime> {
+    let mut totals: HashMap<(String, Option<String>), f64> = HashMap::new();
+    for s in sessions {
+        let Some(repo) = &s.repo else {
+            continue;
+        };
+        let ms = s.end.min(end) - s.start.max(start);
+        if ms > 0 {
+            *totals.entry((repo.clone(), s.branch.clone())).or_default() += ms as f64 / 1000.0;
+        }
+    }
+
+    // 仓库名取主工作区目录名，linked worktree 也归到主仓库名下；仓库已删除时使用目录名
+    let mut names: HashMap<String, String> = HashMap::new();
+    let mut result: Vec<RepositoryTime> = totals
+        .into_iter()
+        .map(|((repo, branch), seconds)| RepositoryTime {
+            name: names
+                .entry(repo.clone())
+                .or_insert_with(|| {
+                    resolve(Path::new(&repo))
+                        .map(|g| g.name)
+                        .or_else(|| {
+                            Path::new(&repo)
+                                .file_name()
+                                .map(|n| n.to_string_lossy().into_owned())
+                        })
+                        .unwrap_or_else(|| repo.clone())
+                })
+                .clone(),
+            repo,
+            branch,
+            seconds,
+        })
+        .collect();
+    result.sort_by(|a, b| b.seconds.total_cmp(&a.seconds));
+    result
+}
+
+pub fn get_repository_time(start: i64, end: i64) -> Vec<RepositoryTime> {
+    let sessions = crate::spy::session::sessions_between(start, end);
+    repository_time(&sessions, start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn temp_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("spy-git-{}-{}", name, std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn test_resolve_branch_and_detached_head() {
+        let repo = temp_dir("repo");
+        std::fs::create_dir_all(repo.join(".git")).unwrap();
+        std::fs::create_dir_all(repo.join("src/deep")).unwrap();
+        std::fs::write(repo.join(".git/HEAD"), "ref: refs/heads/feature/PROJ-12\n").unwrap();
+
+        let info = resolve(&repo.join("src/deep/main.rs")).unwrap();
+        assert_eq!(info.root, repo);
+        assert_eq!(info.branch.as_deref(), Some("feature/PROJ-12"));
+
+        std::fs::write(
+            repo.join(".git/HEAD"),
+            "0123456789abcdef0123456789abcdef01234567\n",
+        )
+        .unwrap();
+        assert_eq!(
+            resolve(&repo.join("src")).unwrap().branch.as_deref(),
+            Some("0123456")
+        );
+
+        // 损坏的 HEAD：第 7 个字节落在多字节字符中间时不截取
+        std::fs::write(repo.join(".git/HEAD"), "abcdef中文\n").unwrap();
+        assert_eq!(resolve(&repo.join("src")).unwrap().branch, None);
+        let _ = std::fs::remove_dir_all(&repo);
+    }
+
+    #[test]
+    fn test_resolve_linked_worktree() {
+        let base = temp_dir("worktree");
+        let main = base.join("spy");
+        let gitdir = main.join(".git/worktrees/hotfix");
+        std::fs::create_dir_all(&gitdir).unwrap();
+        std::fs::write(gitdir.join("HEAD"), "ref: refs/heads/hotfix\n").unwrap();
+        std::fs::write(gitdir.join("commondir"), "../..\n").unwrap();
+
+        let wt = base.join("spy-hotfix");
+        std::fs::create_dir_all(&wt).unwrap();
+        std::fs::write(wt.join(".git"), format!("gitdir: {}\n", gitdir.display())).unwrap();
+
+        let info = resolve(&wt).unwrap();
+        assert_eq!(info.root, wt);
+        assert_eq!(info.name, "spy");
+        assert_eq!(info.branch.as_deref(), Some("hotfix"));
+
+        // 报表中 worktree 的时间归到主仓库名下
+        let sessions = [Session {
+            name: "code".to_string(),
+            repo: Some(wt.to_string_lossy().into_owned()),
+            branch: info.branch.clone(),
+            start: 0,
+            end: 60_000,
+            ..Default::default()
+        }];
+        let times = repository_time(&sessions, 0, 60_000);
+        let _ = std::fs::remove_dir_all(&base);
+        assert_eq!(times[0].name, "spy");
+        assert_eq!(times[0].seconds, 60.0);
+    }
+
+    #[test]
+    fn test_paths_from_title() {
+        let paths = paths_from_title("main.rs (/home/u/code/spy/src) - VIM");
+        assert_eq!(paths, vec![PathBuf::from("/home/u/code/spy/src")]);
+
+        let paths = paths_from_title("user@host: /srv/app");
+        assert_eq!(paths, vec![PathBuf::from("/srv/app")]);
+
+        assert!(paths_from_title("Inbox - Mozilla Thunderbird").is_empty());
+        assert_eq!(
+            paths_from_title(r"C:\code\spy\main.rs - Notepad++"),
+            vec![PathBuf::from(r"C:\code\spy\main.rs")]
+        );
+    }
+}
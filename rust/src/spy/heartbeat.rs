@@ -0,0 +1,632 @@
+//! 本地 WakaTime 兼容心跳接口
+//!
+//! 编辑器插件把 `api_url` 指向 `http://127.0.0.1:<port>/api/v1` 后，
+//! 心跳会发送到这里，与前台会话合并即可按文件、分支、项目统计编码时间。
+//!
+//! 只监听回环地址还挡不住网页：任何页面都能不经 CORS 预检向本机端口发送简单的 POST。
+//! 因此拒绝带 Origin 的请求，要求 Host 为本机地址、心跳请求的 Content-Type 为 JSON，
+//! 并校验插件发送的 `Authorization: Basic <api_key>` 与配置的 api key 一致。
+
+use std::collections::{HashMap, VecDeque};
+use std::io::{BufRead, BufReader, Read, Write};
+use std::net::{Ipv4Addr, SocketAddr, TcpListener, TcpStream};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use base64::Engine;
+use once_cell::sync::Lazy;
+use serde::Deserialize;
+
+use crate::spy::session::Session;
+
+/// 内存中最多保留的心跳数量
+const MAX_HEARTBEATS: usize = 20_000;
+/// 请求体大小上限
+const MAX_BODY_LEN: usize = 4 * 1024 * 1024;
+/// 两次心跳之间超过该时长视为中断（与 WakaTime 默认值一致）
+const HEARTBEAT_TIMEOUT_MS: i64 = 15 * 60 * 1000;
+
+/// 插件 User-Agent 中的编辑器标识 → 对应的应用名称（小写）
+const EDITOR_PLUGINS: &[(&str, &[&str])] = &[
+    (
+        "vscode",
+        &[
+            "code",
+            "code-oss",
+            "codium",
+            "vscodium",
+            "code - insiders",
+            "visual studio code",
+        ],
+    ),
+    ("cursor", &["cursor"]),
+    (
+        "jetbrains",
+        &[
+            "idea",
+            "idea64",
+            "intellij idea",
+            "pycharm",
+            "pycharm64",
+            "webstorm",
+            "clion",
+            "goland",
+            "rider",
+            "rustrover",
+            "phpstorm",
+            "datagrip",
+            "studio",
+            "android studio",
+        ],
+    ),
+    (
+        "intellij",
+        &[
+            "idea",
+            "idea64",
+            "intellij idea",
+            "pycharm",
+            "pycharm64",
+            "webstorm",
+            "clion",
+            "goland",
+            "rider",
+            "rustrover",
+            "phpstorm",
+            "datagrip",
+            "studio",
+            "android studio",
+        ],
+    ),
+    ("sublime", &["sublime_text", "sublime text"]),
+    ("zed", &["zed", "zed-editor"]),
+    ("emacs", &["emacs"]),
+    ("vim", &["gvim", "neovide"]),
+];
+
+/// 在终端里运行的编辑器，插件 User-Agent 中的标识
+const TERMINAL_PLUGINS: &[&str] = &["vim", "emacs", "helix", "kakoune", "micro"];
+
+/// WakaTime 心跳（只保留统计需要的字段）
+#[derive(Debug, Clone, Deserialize)]
+pub struct Heartbeat {
+    pub entity: String,
+    #[serde(rename = "type", default)]
+    pub entity_type: Option<String>,
+    #[serde(default)]
+    pub category: Option<String>,
+    pub time: f64, // unix 秒（带小数）
+    #[serde(default)]
+    pub project: Option<String>,
+    #[serde(default)]
+    pub branch: Option<String>,
+    #[serde(default)]
+    pub language: Option<String>,
+    #[serde(default)]
+    pub is_write: Option<bool>,
+    #[serde(skip)]
+    pub plugin: Option<String>, // 来自 User-Agent
+}
+
+impl Heartbeat {
+    pub fn timestamp_millis(&self) -> i64 {
+        (self.time * 1000.0) as i64
+    }
+}
+
+/// 心跳与前台会话合并后的编码时间
+#[derive(Debug, Clone)]
+pub struct CodingActivity {
+    pub app_name: Option<String>, // 心跳归属的前台编辑器或终端，为空表示当时前台不是发送心跳的编辑器
+    pub project: Option<String>,
+    pub branch: Option<String>,
+    pub entity: String, // 文件路径
+    pub language: Option<String>,
+    pub seconds: f64,
+}
+
+pub static HEARTBEATS: Lazy<Mutex<VecDeque<Heartbeat>>> = Lazy::new(|| Mutex::new(VecDeque::new()));
+
+static SERVER: Lazy<Mutex<Option<ServerHandle>>> = Lazy::new(|| Mutex::new(None));
+
+struct ServerHandle {
+    addr: SocketAddr,
+    stop: Arc<AtomicBool>,
+}
+
+/// 请求校验需要的服务配置
+struct ServerConfig {
+    port: u16,
+    api_key: String,
+}
+
+pub fn store_heartbeat(hb: Heartbeat) {
+    let mut heartbeats = HEARTBEATS.lock().unwrap();
+    heartbeats.push_back(hb);
+    if heartbeats.len() > MAX_HEARTBEATS {
+        heartbeats.pop_front();
+    }
+}
+
+/// 启动心跳服务（仅监听回环地址），插件需使用 api_key 作为 WakaTime api key，返回实际监听端口
+pub fn start_server(port: u16, api_key: String) -> anyhow::Result<u16> {
+    let mut server = SERVER.lock().unwrap();
+    if let Some(s) = &*server {
+        anyhow::bail!("heartbeat server already listening on {}", s.addr);
+    }
+    if api_key.trim().is_empty() {
+        anyhow::bail!("heartbeat server requires an api key");
+    }
+
+    let listener = TcpListener::bind((Ipv4Addr::LOCALHOST, port))?;
+    let addr = listener.local_addr()?;
+    let config = Arc::new(ServerConfig {
+        port: addr.port(),
+        api_key: api_key.trim().to_string(),
+    });
+    let stop = Arc::new(AtomicBool::new(false));
+    let stop_flag = stop.clone();
+
+    std::thread::spawn(move || {
+        for stream in listener.incoming() {
+            if stop_flag.load(Ordering::SeqCst) {
+                break;
+            }
+            match stream {
+                Ok(stream) => {
+                    let config = config.clone();
+                    std::thread::spawn(move || {
+                        if let Err(e) = handle_connection(stream, &config) {
+                            println!("[heartbeat] request error: {}", e);
+                        }
+                    });
+                }
+                Err(e) => println!("[heartbeat] accept error: {}", e),
+            }
+        }
+    });
+
+    println!("[rust] heartbeat server listening on {}", addr);
+    *server = Some(ServerHandle { addr, stop });
+    Ok(addr.port())
+}
+
+pub fn stop_server() {
+    if let Some(s) = SERVER.lock().unwrap().take() {
+        s.stop.store(true, Ordering::SeqCst);
+        // 连接一次以唤醒阻塞中的 accept
+        let _ = TcpStream::connect_timeout(&s.addr, Duration::from_millis(200));
+    }
+}
+
+#[derive(Default)]
+struct Request {
+    method: String,
+    path: String,
+    host: Option<String>,
+    origin: bool, // 带有 Origin 头，来自浏览器中的网页
+    content_type: Option<String>,
+    authorization: Option<String>,
+    user_agent: Option<String>,
+    body: Vec<u8>,
+}
+
+fn read_request(stream: &TcpStream) -> anyhow::Result<Request> {
+    let mut reader = BufReader::new(stream);
+
+    let mut line = String::new();
+    reader.read_line(&mut line)?;
+    let mut parts = line.split_whitespace();
+    let method = parts.next().unwrap_or_default().to_string();
+    let target = parts.next().unwrap_or_default();
+    let path = target.split('?').next().unwrap_or_default().to_string();
+
+    let mut content_length = 0usize;
+    let mut req = Request {
+        method,
+        path,
+        ..Default::default()
+    };
+    loop {
+        line.clear();
+        if reader.read_line(&mut line)? == 0 {
+            break;
+        }
+        let header = line.trim_end();
+        if header.is_empty() {
+            break;
+        }
+        if let Some((key, value)) = header.split_once(':') {
+            let value = value.trim();
+            match key.trim().to_ascii_lowercase().as_str() {
+                "content-length" => content_length = value.parse()?,
+                "host" => req.host = Some(value.to_string()),
+                "origin" => req.origin = true,
+                "content-type" => req.content_type = Some(value.to_ascii_lowercase()),
+                "authorization" => req.authorization = Some(value.to_string()),
+                "user-agent" => req.user_agent = Some(value.to_string()),
+                _ => {}
+            }
+        }
+    }
+
+    if content_length > MAX_BODY_LEN {
+        anyhow::bail!("request body too large: {} bytes", content_length);
+    }
+    req.body = vec![0u8; content_length];
+    reader.read_exact(&mut req.body)?;
+    Ok(req)
+}
+
+/// 校验请求来源：拒绝网页发起的请求（带 Origin 或 Host 不是本机，例如 DNS rebinding），
+/// 要求 JSON 请求体和正确的 api key
+fn check_request(req: &Request, config: &ServerConfig) -> Result<(), (&'static str, String)> {
+    let error =
+        |status, message: &str| (status, serde_json::json!({ "error": message }).to_string());
+    if req.origin {
+        return Err(error(
+            "403 Forbidden",
+            "cross-origin requests are not allowed",
+        ));
+    }
+    let host_ok = req.host.as_deref().is_some_and(|host| {
+        let (name, port) = match host.rsplit_once(':') {
+            Some((name, port)) => (name, port.parse().ok()),
+            None => (host, Some(80)),
+        };
+        matches!(name, "127.0.0.1" | "localhost") && port == Some(config.port)
+    });
+    if !host_ok {
+        return Err(error("403 Forbidden", "invalid host"));
+    }
+    let authorized = req
+        .authorization
+        .as_deref()
+        .and_then(|a| a.strip_prefix("Basic "))
+        .and_then(|token| {
+            base64::engine::general_purpose::STANDARD
+                .decode(token.trim())
+                .ok()
+        })
+        .is_some_and(|key| {
+            // 部分插件按 Basic 认证的 `用户名:密码` 格式在 key 后面加冒号
+            let key = String::from_utf8_lossy(&key);
+            key.trim_end_matches(':') == config.api_key
+        });
+    if !authorized {
+        return Err(error("401 Unauthorized", "invalid api key"));
+    }
+    let json = req
+        .content_type
+        .as_deref()
+        .is_some_and(|t| t.split(';').next() == Some("application/json"));
+    if req.method == "POST" && !json {
+        return Err(error(
+            "415 Unsupported Media Type",
+            "expected application/json",
+        ));
+    }
+    Ok(())
+}
+
+fn handle_connection(mut stream: TcpStream, config: &ServerConfig) -> anyhow::Result<()> {
+    // 只接受本机请求
+    if !stream.peer_addr()?.ip().is_loopback() {
+        return Ok(());
+    }
+    stream.set_read_timeout(Some(Duration::from_secs(10)))?;
+
+    let req = read_request(&stream)?;
+    let (status, body) = match check_request(&req, config) {
+        Ok(()) => route(&req),
+        Err(rejected) => rejected,
+    };
+    write!(
+        stream,
+        "HTTP/1.1 {}\r\nContent-Type: application/json\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+        status,
+        body.len(),
+        body
+    )?;
+    stream.flush()?;
+    Ok(())
+}
+
+fn route(req: &Request) -> (&'static str, String) {
+    let path = req.path.trim_end_matches('/');
+    match (req.method.as_str(), path) {
+        ("POST", p) if p.ends_with("/users/current/heartbeats") => {
+            match parse_heartbeats(&req.body, req.user_agent.as_deref()) {
+                Ok(heartbeats) => {
+                    let data = heartbeats_to_json(&heartbeats);
+                    heartbeats.into_iter().for_each(store_heartbeat);
+                    // 单条心跳返回对象，批量返回数组
+                    let data = match data.as_slice() {
+                        [one] => one.clone(),
+                        _ => serde_json::Value::Array(data),
+                    };
+                    (
+                        "201 Created",
+                        serde_json::json!({ "data": data }).to_string(),
+                    )
+                }
+                Err(e) => bad_request(e),
+            }
+        }
+        ("POST", p) if p.ends_with("/users/current/heartbeats.bulk") => {
+            match parse_heartbeats(&req.body, req.user_agent.as_deref()) {
+                Ok(heartbeats) => {
+                    let responses: Vec<_> = heartbeats_to_json(&heartbeats)
+                        .into_iter()
+                        .map(|d| serde_json::json!([{ "data": d }, 201]))
+                        .collect();
+                    heartbeats.into_iter().for_each(store_heartbeat);
+                    (
+                        "202 Accepted",
+                        serde_json::json!({ "responses": responses }).to_string(),
+                    )
+                }
+                Err(e) => bad_request(e),
+            }
+        }
+        ("GET", p) if p.ends_with("/users/current/statusbar/today") => (
+            "200 OK",
+            serde_json::json!({ "data": { "grand_total": { "text": "" } } }).to_string(),
+        ),
+        _ => (
+            "404 Not Found",
+            serde_json::json!({ "error": "not found" }).to_string(),
+        ),
+    }
+}
+
+fn bad_request(e: serde_json::Error) -> (&'static str, String) {
+    (
+        "400 Bad Request",
+        serde_json::json!({ "error": e.to_string() }).to_string(),
+    )
+}
+
+/// 解析心跳请求体，兼容单个对象与数组两种格式
+pub fn parse_heartbeats(
+    body: &[u8],
+    user_agent: Option<&str>,
+) -> Result<Vec<Heartbeat>, serde_json::Error> {
+    #[derive(Deserialize)]
+    #[serde(untagged)]
+    enum Payload {
+        One(Heartbeat),
+        Many(Vec<Heartbeat>),
+    }
+
+    let heartbeats = match serde_json::from_slice::<Payload>(body)? {
+        Payload::One(hb) => vec![hb],
+        Payload::Many(hbs) => hbs,
+    };
+    Ok(heartbeats
+        .into_iter()
+        .map(|mut hb| {
+            hb.plugin = user_agent.map(|s| s.to_string());
+            hb
+        })
+        .collect())
+}
+
+fn heartbeats_to_json(heartbeats: &[Heartbeat]) -> Vec<serde_json::Value> {
+    heartbeats
+        .iter()
+        .map(|hb| {
+            serde_json::json!({
+                "entity": hb.entity,
+                "type": hb.entity_type,
+                "time": hb.time,
+                "project": hb.project,
+                "branch": hb.branch,
+                "language": hb.language,
+            })
+        })
+        .collect()
+}
+
+/// 插件是否属于会话的应用：按 User-Agent 中的编辑器标识匹配应用名称，
+/// 终端编辑器的插件匹配任意终端
+fn plugin_matches(plugin: &str, session: &Session) -> bool {
+    let plugin = plugin.to_lowercase();
+    let name = session.name.to_lowercase();
+    EDITOR_PLUGINS
+        .iter()
+        .any(|(id, apps)| plugin.contains(id) && apps.contains(&name.as_str()))
+        || (TERMINAL_PLUGINS.iter().any(|id| plugin.contains(id))
+            && crate::spy::shell::is_terminal_app(&session.name, &session.path))
+}
+
+/// 将心跳按前台会话归属，统计 [start, end]（unix 毫秒）内的编码时间
+///
+/// 每条心跳持续到下一条心跳为止，但不超过超时时长。前台是发送心跳的编辑器（或运行终端编辑器的终端）时
+/// 归属到该会话并且不超出会话；否则（切到了浏览器、插件无法识别等）计为未归属的时间。
+pub fn coding_activity(
+    heartbeats: &[Heartbeat],
+    sessions: &[Session],
+    start: i64,
+    end: i64,
+) -> Vec<CodingActivity> {
+    let mut sorted: Vec<&Heartbeat> = heartbeats.iter().collect();
+    sorted.sort_by_key(|hb| hb.timestamp_millis());
+
+    type Key = (Option<String>, Option<String>, Option<String>, String);
+    let mut totals: HashMap<Key, CodingActivity> = HashMap::new();
+
+    for (i, hb) in sorted.iter().enumerate() {
+        let ts = hb.timestamp_millis();
+        let session = sessions
+            .iter()
+            .find(|s| s.contains(ts) && hb.plugin.as_deref().is_some_and(|p| plugin_matches(p, s)));
+
+        let mut until = ts + HEARTBEAT_TIMEOUT_MS;
+        if let Some(next) = sorted.get(i + 1) {
+            until = until.min(next.timestamp_millis());
+        }
+        if let Some(session) = session {
+            until = until.min(session.end);
+        }
+        let from = ts.max(start);
+        let until = until.min(end);
+        if until <= from {
+            continue;
+        }
+
+        let app_name = session.map(|s| s.name.clone());
+        let key = (
+            app_name.clone(),
+            hb.project.clone(),
+            hb.branch.clone(),
+            hb.entity.clone(),
+        );
+        let entry = totals.entry(key).or_insert_with(|| CodingActivity {
+            app_name,
+            project: hb.project.clone(),
+            branch: hb.branch.clone(),
+            entity: hb.entity.clone(),
+            language: hb.language.clone(),
+            seconds: 0.0,
+        });
+        entry.seconds += (until - from) as f64 / 1000.0;
+        if hb.language.is_some() {
+            entry.language = hb.language.clone();
+        }
+    }
+
+    let mut result: Vec<CodingActivity> = totals.into_values().collect();
+    result.sort_by(|a, b| b.seconds.total_cmp(&a.seconds));
+    result
+}
+
+/// 基于已存储的心跳和会话统计编码时间
+pub fn get_coding_activity(start: i64, end: i64) -> Vec<CodingActivity> {
+    let heartbeats: Vec<Heartbeat> = HEARTBEATS.lock().unwrap().iter().cloned().collect();
+    let sessions = crate::spy::session::sessions_between(start, end);
+    coding_activity(&heartbeats, &sessions, start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(name: &str, start: i64, end: i64) -> Session {
+        Session {
+            name: name.to_string(),
+            start,
+            end,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_parse_single_and_bulk() {
+        let one = br#"{"entity":"/src/main.rs","type":"file","time":1700000000.5,"project":"spy","branch":"main","language":"Rust"}"#;
+        let hbs = parse_heartbeats(one, Some("wakatime/v1.0 vscode")).unwrap();
+        assert_eq!(hbs.len(), 1);
+        assert_eq!(hbs[0].timestamp_millis(), 1_700_000_000_500);
+        assert_eq!(hbs[0].plugin.as_deref(), Some("wakatime/v1.0 vscode"));
+
+        let bulk =
+            br#"[{"entity":"a.rs","time":1.0},{"entity":"b.rs","time":2.0,"is_write":true}]"#;
+        assert_eq!(parse_heartbeats(bulk, None).unwrap().len(), 2);
+        assert!(parse_heartbeats(b"{}", None).is_err());
+    }
+
+    #[test]
+    fn test_coding_activity_clipped_to_sessions() {
+        let hb = |entity: &str, secs: f64, plugin: &str| Heartbeat {
+            entity: entity.to_string(),
+            entity_type: Some("file".to_string()),
+            category: None,
+            time: secs,
+            project: Some("spy".to_string()),
+            branch: Some("main".to_string()),
+            language: None,
+            is_write: None,
+            plugin: Some(plugin.to_string()),
+        };
+        let vscode = "wakatime/v1.86.1 (linux) go1.21 vscode/1.85.0 vscode-wakatime/24.4.0";
+        let vim = "wakatime/v1.86.1 (linux) go1.21 vim/9.0 vim-wakatime/11.1.1";
+        let heartbeats = vec![
+            hb("a.rs", 10.0, vscode),
+            hb("b.rs", 40.0, vscode),
+            hb("a.rs", 200.0, vscode),
+            hb("c.rs", 300.0, vim),
+            hb("d.rs", 400.0, vim),
+        ];
+        let mut terminal = session("kitty", 300_000, 450_000);
+        terminal.path = "/usr/bin/kitty".to_string();
+        let sessions = vec![
+            session("code", 0, 100_000),
+            session("firefox", 100_000, 300_000),
+            terminal,
+        ];
+
+        let activity = coding_activity(&heartbeats, &sessions, 0, 500_000);
+        let find = |app: Option<&str>, entity: &str| {
+            activity
+                .iter()
+                .find(|a| a.app_name.as_deref() == app && a.entity == entity)
+                .map(|a| a.seconds)
+        };
+        // a.rs: 10s → 40s；b.rs: 40s → 会话结束 100s
+        assert_eq!(find(Some("code"), "a.rs"), Some(30.0));
+        assert_eq!(find(Some("code"), "b.rs"), Some(60.0));
+        // 200s 时前台是浏览器，不算编辑器的时间，计为未归属，持续到下一条心跳
+        assert_eq!(find(Some("firefox"), "a.rs"), None);
+        assert_eq!(find(None, "a.rs"), Some(100.0));
+        // 终端里的 vim：300s → 400s，400s → 终端会话结束 450s
+        assert_eq!(find(Some("kitty"), "c.rs"), Some(100.0));
+        assert_eq!(find(Some("kitty"), "d.rs"), Some(50.0));
+    }
+
+    #[test]
+    fn test_server_accepts_heartbeats() {
+        let port = start_server(0, "secret".to_string()).unwrap();
+        let body = r#"[{"entity":"/tmp/x.rs","type":"file","time":1700000000.0}]"#;
+        let post = |headers: &str| {
+            let mut stream = TcpStream::connect((Ipv4Addr::LOCALHOST, port)).unwrap();
+            write!(
+                stream,
+                "POST /api/v1/users/current/heartbeats.bulk HTTP/1.1\r\n{}Content-Length: {}\r\n\r\n{}",
+                headers,
+                body.len(),
+                body
+            )
+            .unwrap();
+            let mut response = String::new();
+            stream.read_to_string(&mut response).unwrap();
+            response
+        };
+        let host = format!("Host: 127.0.0.1:{}\r\n", port);
+        let auth = "Authorization: Basic c2VjcmV0\r\n"; // base64("secret")
+        let json = "Content-Type: application/json\r\n";
+
+        let accepted = post(&format!("{}{}{}", host, auth, json));
+        // 网页发起的简单请求：带 Origin、text/plain
+        let cross_origin = post(&format!(
+            "{}{}{}Origin: http://evil.test\r\n",
+            host, auth, json
+        ));
+        let plain = post(&format!("{}{}Content-Type: text/plain\r\n", host, auth));
+        // DNS rebinding：Host 为其他域名
+        let rebound = post(&format!("Host: evil.test:{}\r\n{}{}", port, auth, json));
+        let no_key = post(&format!("{}{}", host, json));
+        stop_server();
+
+        assert!(accepted.starts_with("HTTP/1.1 202"));
+        assert!(cross_origin.starts_with("HTTP/1.1 403"));
+        assert!(plain.starts_with("HTTP/1.1 415"));
+        assert!(rebound.starts_with("HTTP/1.1 403"));
+        assert!(no_key.starts_with("HTTP/1.1 401"));
+        assert!(HEARTBEATS
+            .lock()
+            .unwrap()
+            .iter()
+            .any(|hb| hb.entity == "/tmp/x.rs"));
+    }
+}
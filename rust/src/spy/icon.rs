@@ -0,0 +1,175 @@
+//! 图标缓存
+//!
+//! 图标按可执行文件路径或应用标识缓存，同一应用只提取一次；图标以 PNG 内容的哈希作为 id，
+//! 事件中只携带 id，界面通过 `get_icon(id, size)` 按需获取图片。
+
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::path::PathBuf;
+use std::sync::Mutex;
+
+use image::imageops::FilterType;
+use once_cell::sync::Lazy;
+use sha2::{Digest, Sha256};
+
+#[derive(Default)]
+pub struct IconCache {
+    keys: HashMap<String, String>,            // 路径或应用标识 → 图标 id
+    icons: HashMap<String, Vec<u8>>,          // 图标 id → 原始 PNG
+    resized: HashMap<(String, u32), Vec<u8>>, // (图标 id, 尺寸) → 缩放后的 PNG
+}
+
+pub static ICONS: Lazy<Mutex<IconCache>> = Lazy::new(|| Mutex::new(IconCache::default()));
+
+/// 图标持久化目录，为空时只缓存在内存中
+pub static ICON_CACHE_FOLDER: Lazy<Mutex<String>> = Lazy::new(|| Mutex::new(String::new()));
+
+/// 图标 id：PNG 内容 SHA-256 的前 16 字节（十六进制）
+pub fn icon_id(png: &[u8]) -> String {
+    Sha256::digest(png)[..16]
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect()
+}
+
+fn icon_path(id: &str) -> Option<PathBuf> {
+    let folder = ICON_CACHE_FOLDER.lock().unwrap().clone();
+    if folder.is_empty() {
+        return None;
+    }
+    Some(PathBuf::from(folder).join(format!("{}.png", id)))
+}
+
+impl IconCache {
+    /// 保存图标并关联到 key，返回图标 id
+    pub fn insert(&mut self, key: &str, png: Vec<u8>) -> String {
+        let id = icon_id(&png);
+        if !self.icons.contains_key(&id) {
+            if let Some(path) = icon_path(&id) {
+                if !path.exists() {
+                    if let Err(e) = std::fs::write(&path, &png) {
+                        println!("[icon] save {} error: {}", path.display(), e);
+                    }
+                }
+            }
+            self.icons.insert(id.clone(), png);
+        }
+        self.keys.insert(key.to_string(), id.clone());
+        id
+    }
+
+    /// 原始 PNG，内存中没有时从持久化目录读取
+    fn original(&mut self, id: &str) -> Option<Vec<u8>> {
+        if let Some(png) = self.icons.get(id) {
+            return Some(png.clone());
+        }
+        // id 只包含十六进制字符，避免拼出任意路径
+        if id.is_empty() || !id.chars().all(|c| c.is_ascii_hexdigit()) {
+            return None;
+        }
+        let png = std::fs::read(icon_path(id)?).ok()?;
+        self.icons.insert(id.to_string(), png.clone());
+        Some(png)
+    }
+
+    /// 按尺寸返回 PNG，size 为 0 时返回原图
+    pub fn get(&mut self, id: &str, size: u32) -> Option<Vec<u8>> {
+        let png = self.original(id)?;
+        if size == 0 {
+            return Some(png);
+        }
+        let key = (id.to_string(), size);
+        if let Some(resized) = self.resized.get(&key) {
+            return Some(resized.clone());
+        }
+
+        let image = image::load_from_memory(&png).ok()?;
+        let resized = if image.width() == size && image.height() == size {
+            png
+        } else {
+            let mut buf = Cursor::new(Vec::new());
+            image
+                .resize_exact(size, size, FilterType::Lanczos3)
+                .write_to(&mut buf, image::ImageFormat::Png)
+                .ok()?;
+            buf.into_inner()
+        };
+        self.resized.insert(key, resized.clone());
+        Some(resized)
+    }
+}
+
+/// 返回 key 对应的图标 id，缓存中没有时调用 extract 提取一次
+pub fn icon_for(key: &str, extract: impl FnOnce() -> Option<Vec<u8>>) -> Option<String> {
+    if let Some(id) = ICONS.lock().unwrap().keys.get(key) {
+        return Some(id.clone());
+    }
+    // 提取图标可能较慢，不持有锁
+    let png = extract()?;
+    Some(ICONS.lock().unwrap().insert(key, png))
+}
+
+pub fn get_icon(id: &str, size: u32) -> Option<Vec<u8>> {
+    ICONS.lock().unwrap().get(id, size)
+}
+
+pub fn init_cache_folder(path: String) {
+    if !path.is_empty() {
+        if let Err(e) = std::fs::create_dir_all(&path) {
+            println!("[icon] create {} error: {}", path, e);
+        }
+    }
+    *ICON_CACHE_FOLDER.lock().unwrap() = path;
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn png(size: u32, color: [u8; 4]) -> Vec<u8> {
+        let mut buf = Cursor::new(Vec::new());
+        image::RgbaImage::from_pixel(size, size, image::Rgba(color))
+            .write_to(&mut buf, image::ImageFormat::Png)
+            .unwrap();
+        buf.into_inner()
+    }
+
+    #[test]
+    fn test_content_addressed_ids() {
+        let mut cache = IconCache::default();
+        let red = cache.insert("/usr/bin/a", png(32, [255, 0, 0, 255]));
+        let same = cache.insert("/usr/bin/b", png(32, [255, 0, 0, 255]));
+        let blue = cache.insert("/usr/bin/c", png(32, [0, 0, 255, 255]));
+        assert_eq!(red, same);
+        assert_ne!(red, blue);
+        assert_eq!(red.len(), 32);
+        assert_eq!(cache.icons.len(), 2);
+    }
+
+    #[test]
+    fn test_get_resized() {
+        let mut cache = IconCache::default();
+        let id = cache.insert("app", png(32, [0, 255, 0, 255]));
+
+        let original = cache.get(&id, 0).unwrap();
+        assert_eq!(icon_id(&original), id);
+        let big = image::load_from_memory(&cache.get(&id, 64).unwrap()).unwrap();
+        assert_eq!((big.width(), big.height()), (64, 64));
+        assert!(cache.get("../../etc/passwd", 0).is_none());
+        assert!(cache.get("0123456789abcdef", 16).is_none());
+    }
+
+    #[test]
+    fn test_extract_once_per_key() {
+        let mut calls = 0;
+        let key = format!("test-extract-once-{}", std::process::id());
+        for _ in 0..3 {
+            icon_for(&key, || {
+                calls += 1;
+                Some(png(8, [1, 2, 3, 255]))
+            })
+            .unwrap();
+        }
+        assert_eq!(calls, 1);
+    }
+}
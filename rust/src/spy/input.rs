@@ -0,0 +1,356 @@
+//! 输入活跃度
+//!
+//! 可选的输入监控：按分钟统计按键次数、点击次数、滚轮次数和鼠标移动距离，用于判断用户是否
+//! 离开（空闲检测）以及计算各应用的专注指标。只统计次数，从不记录按了哪个键。
+//! Linux X11 上通过 XInput2 raw 事件获取，不影响其他程序接收输入。
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, AtomicI64, Ordering};
+use std::sync::{Arc, Mutex};
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use crate::spy::session::Session;
+
+/// 统计区间长度
+const BUCKET_MS: i64 = 60_000;
+
+/// 内存中最多保留的区间数量（一周）
+const MAX_BUCKETS: usize = 7 * 24 * 60;
+
+/// 默认空闲阈值
+const DEFAULT_IDLE_SECS: i64 = 300;
+
+/// 鼠标每移动这么多像素算一次输入
+const PIXELS_PER_EVENT: u32 = 100;
+
+/// 一段时间内的输入次数
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct InputCounts {
+    pub keystrokes: u32,
+    pub clicks: u32,
+    pub scrolls: u32,
+    pub mouse_distance: u32, // 像素
+}
+
+impl InputCounts {
+    pub fn add(&mut self, other: &InputCounts) {
+        self.keystrokes += other.keystrokes;
+        self.clicks += other.clicks;
+        self.scrolls += other.scrolls;
+        self.mouse_distance += other.mouse_distance;
+    }
+
+    /// 折算的输入次数
+    pub fn events(&self) -> u32 {
+        self.keystrokes + self.clicks + self.scrolls + self.mouse_distance / PIXELS_PER_EVENT
+    }
+
+    /// 按 ratio 比例折算，用于区间只有一部分落在会话内的情况
+    fn scaled(&self, ratio: f64) -> InputCounts {
+        let scale = |v: u32| (v as f64 * ratio).round() as u32;
+        InputCounts {
+            keystrokes: scale(self.keystrokes),
+            clicks: scale(self.clicks),
+            scrolls: scale(self.scrolls),
+            mouse_distance: scale(self.mouse_distance),
+        }
+    }
+}
+
+/// 活跃程度，按每个活跃分钟的平均输入次数划分
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ActivityLevel {
+    Idle,
+    Low,    // 阅读、偶尔滚动
+    Medium, // 浏览、少量输入
+    High,   // 持续输入，例如写代码
+}
+
+impl ActivityLevel {
+    pub fn from_events_per_minute(events: f64) -> ActivityLevel {
+        match events {
+            e if e <= 0.0 => ActivityLevel::Idle,
+            e if e < 20.0 => ActivityLevel::Low,
+            e if e < 120.0 => ActivityLevel::Medium,
+            _ => ActivityLevel::High,
+        }
+    }
+}
+
+/// 按应用统计的专注指标
+#[derive(Debug, Clone)]
+pub struct FocusMetrics {
+    pub name: String,
+    pub seconds: f64,        // 有输入监控的前台时间
+    pub active_seconds: f64, // 其中有输入的时间
+    pub idle_seconds: f64,   // 其中没有任何输入的时间
+    pub input: InputCounts,
+    pub level: ActivityLevel,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct InputBucket {
+    start: i64, // 分钟起点
+    counts: InputCounts,
+}
+
+#[derive(Default)]
+pub struct InputStore {
+    buckets: VecDeque<InputBucket>, // 监控期间每分钟一个，没有输入的分钟次数为 0
+    last_input: Option<i64>,
+}
+
+impl InputStore {
+    /// 当前分钟的区间，监控期间每分钟调用以记录没有输入的分钟
+    pub fn tick(&mut self, now: i64) -> &mut InputCounts {
+        let start = now - now.rem_euclid(BUCKET_MS);
+        if self.buckets.back().map(|b| b.start) != Some(start) {
+            self.buckets.push_back(InputBucket {
+                start,
+                counts: InputCounts::default(),
+            });
+            if self.buckets.len() > MAX_BUCKETS {
+                self.buckets.pop_front();
+            }
+        }
+        &mut self.buckets.back_mut().unwrap().counts
+    }
+
+    pub fn key(&mut self, now: i64) {
+        self.tick(now).keystrokes += 1;
+        self.last_input = Some(now);
+    }
+
+    pub fn click(&mut self, now: i64) {
+        self.tick(now).clicks += 1;
+        self.last_input = Some(now);
+    }
+
+    pub fn scroll(&mut self, now: i64) {
+        self.tick(now).scrolls += 1;
+        self.last_input = Some(now);
+    }
+
+    pub fn moved(&mut self, distance: u32, now: i64) {
+        if distance == 0 {
+            return;
+        }
+        self.tick(now).mouse_distance += distance;
+        self.last_input = Some(now);
+    }
+
+    /// 距离最后一次输入的毫秒数，还没有输入时为 None
+    pub fn idle_millis(&self, now: i64) -> Option<i64> {
+        self.last_input.map(|t| now - t)
+    }
+
+    /// [start, end] 内的输入次数
+    pub fn counts_between(&self, start: i64, end: i64) -> InputCounts {
+        let mut total = InputCounts::default();
+        for b in &self.buckets {
+            let overlap = (b.start + BUCKET_MS).min(end) - b.start.max(start);
+            if overlap > 0 {
+                total.add(&b.counts.scaled(overlap as f64 / BUCKET_MS as f64));
+            }
+        }
+        total
+    }
+}
+
+/// 合并会话和输入区间得到各应用的专注指标，按前台时间降序；没有输入监控的时间不计入
+pub fn focus_metrics(
+    store: &InputStore,
+    sessions: &[Session],
+    start: i64,
+    end: i64,
+) -> Vec<FocusMetrics> {
+    // 应用 → (监控时间, 活跃时间, 输入次数)
+    let mut totals: HashMap<String, (i64, i64, InputCounts)> = HashMap::new();
+    for s in sessions {
+        let from = s.start.max(start);
+        let to = s.end.min(end);
+        for b in &store.buckets {
+            let overlap = (b.start + BUCKET_MS).min(to) - b.start.max(from);
+            if overlap <= 0 {
+                continue;
+            }
+            let entry = totals.entry(s.name.clone()).or_default();
+            entry.0 += overlap;
+            if b.counts.events() > 0 {
+                entry.1 += overlap;
+            }
+            entry
+                .2
+                .add(&b.counts.scaled(overlap as f64 / BUCKET_MS as f64));
+        }
+    }
+
+    let mut result: Vec<FocusMetrics> = totals
+        .into_iter()
+        .map(|(name, (monitored, active, input))| {
+            let per_minute = if active > 0 {
+                input.events() as f64 * 60_000.0 / active as f64
+            } else {
+                0.0
+            };
+            FocusMetrics {
+                name,
+                seconds: monitored as f64 / 1000.0,
+                active_seconds: active as f64 / 1000.0,
+                idle_seconds: (monitored - active) as f64 / 1000.0,
+                input,
+                level: ActivityLevel::from_events_per_minute(per_minute),
+            }
+        })
+        .collect();
+    result.sort_by(|a, b| b.seconds.total_cmp(&a.seconds));
+    result
+}
+
+pub static INPUT: Lazy<Mutex<InputStore>> = Lazy::new(|| Mutex::new(InputStore::default()));
+
+static IDLE_SECS: AtomicI64 = AtomicI64::new(DEFAULT_IDLE_SECS);
+
+/// 本次监控的停止标志，每次启动新建一个；监控线程出错退出时只停止自己这一次
+static MONITOR: Lazy<Mutex<Option<Arc<AtomicBool>>>> = Lazy::new(|| Mutex::new(None));
+
+/// 输入监控是否在运行，未运行时不做空闲判断
+fn monitoring() -> bool {
+    MONITOR
+        .lock()
+        .unwrap()
+        .as_ref()
+        .is_some_and(|stop| !stop.load(Ordering::SeqCst))
+}
+
+pub fn set_idle_threshold(secs: i64) {
+    IDLE_SECS.store(secs.max(1), Ordering::SeqCst);
+}
+
+/// 输入监控运行中且超过阈值没有任何输入时视为离开
+pub fn is_idle(now: i64) -> bool {
+    if !monitoring() {
+        return false;
+    }
+    let threshold = IDLE_SECS.load(Ordering::SeqCst) * 1000;
+    INPUT
+        .lock()
+        .unwrap()
+        .idle_millis(now)
+        .is_some_and(|idle| idle >= threshold)
+}
+
+/// 开始监控输入，返回使用的方式
+#[cfg(target_os = "linux")]
+pub fn start_monitor() -> anyhow::Result<String> {
+    let mut monitor = MONITOR.lock().unwrap();
+    if monitor
+        .as_ref()
+        .is_some_and(|stop| !stop.load(Ordering::SeqCst))
+    {
+        anyhow::bail!("input monitor already started");
+    }
+    let listener = crate::spy::platform::xinput::RawInputListener::open(None)?;
+    let stop = Arc::new(AtomicBool::new(false));
+    *monitor = Some(stop.clone());
+    // 从开始监控时计算空闲时间
+    INPUT
+        .lock()
+        .unwrap()
+        .last_input
+        .get_or_insert(chrono::Local::now().timestamp_millis());
+    std::thread::spawn(move || {
+        if let Err(e) = listener.run(&stop, &INPUT) {
+            crate::spy::event::emit(crate::spy::event::TrackerEvent::failed(
+                crate::spy::event::TrackerEventKind::Error,
+                "input",
+                e,
+            ));
+        }
+        stop.store(true, Ordering::SeqCst);
+    });
+    Ok("xinput2".to_string())
+}
+
+/// 其他平台暂不支持输入监控
+#[cfg(not(target_os = "linux"))]
+pub fn start_monitor() -> anyhow::Result<String> {
+    anyhow::bail!("input monitor is only supported on Linux X11")
+}
+
+pub fn stop_monitor() {
+    if let Some(stop) = MONITOR.lock().unwrap().take() {
+        stop.store(true, Ordering::SeqCst);
+    }
+}
+
+/// 距离最后一次输入的秒数，没有在监控时为 None
+pub fn get_idle_seconds() -> Option<i64> {
+    if !monitoring() {
+        return None;
+    }
+    let now = chrono::Local::now().timestamp_millis();
+    INPUT.lock().unwrap().idle_millis(now).map(|ms| ms / 1000)
+}
+
+/// 一个会话的输入次数和活跃程度，会话期间没有输入监控时为 None
+pub fn session_activity(session: &Session) -> Option<FocusMetrics> {
+    let store = INPUT.lock().unwrap();
+    focus_metrics(
+        &store,
+        std::slice::from_ref(session),
+        session.start,
+        session.end,
+    )
+    .pop()
+}
+
+pub fn get_focus_metrics(start: i64, end: i64) -> Vec<FocusMetrics> {
+    let sessions = crate::spy::session::sessions_between(start, end);
+    focus_metrics(&INPUT.lock().unwrap(), &sessions, start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(name: &str, start: i64, end: i64) -> Session {
+        Session {
+            name: name.to_string(),
+            start,
+            end,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_focus_metrics() {
+        let mut store = InputStore::default();
+        // 第 0 分钟持续打字，第 1 分钟只动鼠标，第 2 分钟没有输入
+        for i in 0..200 {
+            store.key(i * 100);
+        }
+        store.moved(500, 70_000);
+        store.tick(130_000);
+        assert_eq!(store.idle_millis(180_000), Some(110_000));
+
+        let sessions = [
+            session("code", 0, 60_000),
+            session("evince", 60_000, 180_000),
+        ];
+        let metrics = focus_metrics(&store, &sessions, 0, 180_000);
+        let code = metrics.iter().find(|m| m.name == "code").unwrap();
+        assert_eq!(code.input.keystrokes, 200);
+        assert_eq!(code.level, ActivityLevel::High);
+        assert_eq!(code.idle_seconds, 0.0);
+
+        let evince = metrics.iter().find(|m| m.name == "evince").unwrap();
+        assert_eq!(evince.seconds, 120.0);
+        assert_eq!(evince.active_seconds, 60.0);
+        assert_eq!(evince.idle_seconds, 60.0);
+        assert_eq!(evince.input.mouse_distance, 500);
+        assert_eq!(evince.level, ActivityLevel::Low);
+    }
+}
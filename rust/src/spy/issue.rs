@@ -0,0 +1,369 @@
+//! 从窗口标题和分支名中提取 `PROJ-1234` 这样的工单号，
+//! 把会话时间归属到工单，并导出 Jira 可导入的工作日志。
+
+use std::collections::{BTreeMap, HashMap, HashSet};
+use std::sync::Mutex;
+
+use chrono::TimeZone;
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::Serialize;
+
+use crate::spy::session::Session;
+
+/// 默认的工单号格式：大写项目键 + `-` + 数字
+const DEFAULT_PATTERN: &str = r"\b[A-Z][A-Z0-9_]+-[0-9]+\b";
+/// 同一工单的两段会话间隔不超过该时长时合并为一条工作日志
+const MERGE_GAP_MS: i64 = 5 * 60 * 1000;
+/// Jira 工作日志的最小单位是分钟
+const MIN_WORKLOG_SECS: i64 = 60;
+
+/// 工作日志导出格式
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WorklogFormat {
+    Csv,  // Jira CSV 导入（Issue Key + Worklog 列）
+    Json, // Jira JSON 导入
+}
+
+/// 按工单统计的时间
+#[derive(Debug, Clone)]
+pub struct IssueTime {
+    pub key: String,
+    pub apps: Vec<String>, // 涉及的应用
+    pub seconds: f64,
+}
+
+/// 一条工作日志
+#[derive(Debug, Clone, PartialEq)]
+pub struct Worklog {
+    pub issue_key: String,
+    pub started: i64, // unix 毫秒
+    pub seconds: i64, // 按分钟取整
+    pub comment: String,
+}
+
+struct IssueExtractor {
+    pattern: Regex,
+    projects: HashSet<String>, // 为空时接受所有项目
+}
+
+impl IssueExtractor {
+    fn keys(&self, text: &str) -> Vec<String> {
+        self.pattern
+            .find_iter(text)
+            .map(|m| m.as_str().to_string())
+            .filter(|key| {
+                self.projects.is_empty()
+                    || key
+                        .rsplit_once('-')
+                        .is_some_and(|(project, _)| self.projects.contains(project))
+            })
+            .collect()
+    }
+}
+
+static EXTRACTOR: Lazy<Mutex<IssueExtractor>> = Lazy::new(|| {
+    Mutex::new(IssueExtractor {
+        pattern: Regex::new(DEFAULT_PATTERN).unwrap(),
+        projects: HashSet::new(),
+    })
+});
+
+/// 设置工单号正则，空字符串恢复默认格式
+pub fn set_pattern(pattern: &str) -> anyhow::Result<()> {
+    let pattern = if pattern.is_empty() {
+        DEFAULT_PATTERN
+    } else {
+        pattern
+    };
+    EXTRACTOR.lock().unwrap().pattern = Regex::new(pattern)?;
+    Ok(())
+}
+
+/// 只识别这些项目键的工单，空列表表示不限制
+pub fn set_projects(projects: Vec<String>) {
+    EXTRACTOR.lock().unwrap().projects = projects.into_iter().map(|p| p.to_uppercase()).collect();
+}
+
+/// 提取文本中的所有工单号
+pub fn extract_keys(text: &str) -> Vec<String> {
+    EXTRACTOR.lock().unwrap().keys(text)
+}
+
+/// 确定采样所属的工单：分支名优先（分支常为小写，统一转大写后匹配），其次窗口标题
+pub fn issue_for(title: &str, branch: Option<&str>) -> Option<String> {
+    let extractor = EXTRACTOR.lock().unwrap();
+    branch
+        .and_then(|b| extractor.keys(&b.to_uppercase()).into_iter().next())
+        .or_else(|| extractor.keys(title).into_iter().next())
+}
+
+/// 统计 [start, end]（unix 毫秒）内各工单的时间
+pub fn issue_time(sessions: &[Session], start: i64, end: i64) -> Vec<IssueTime> {
+    let mut totals: HashMap<String, (Vec<String>, f64)> = HashMap::new();
+    for s in sessions {
+        let Some(key) = &s.issue else {
+            continue;
+        };
+        let ms = s.end.min(end) - s.start.max(start);
+        if ms <= 0 {
+            continue;
+        }
+        let entry = totals.entry(key.clone()).or_default();
+        if !entry.0.contains(&s.name) {
+            entry.0.push(s.name.clone());
+        }
+        entry.1 += ms as f64 / 1000.0;
+    }
+
+    let mut result: Vec<IssueTime> = totals
+        .into_iter()
+        .map(|(key, (apps, seconds))| IssueTime { key, apps, seconds })
+        .collect();
+    result.sort_by(|a, b| b.seconds.total_cmp(&a.seconds));
+    result
+}
+
+/// 把会话合并为工作日志：同一工单相邻且间隔较短的会话合并为一条
+pub fn worklogs(sessions: &[Session], start: i64, end: i64) -> Vec<Worklog> {
+    let mut sorted: Vec<&Session> = sessions.iter().filter(|s| s.issue.is_some()).collect();
+    sorted.sort_by_key(|s| s.start);
+
+    // (工单, 开始, 结束, 累计毫秒, 应用)
+    let mut runs: Vec<(String, i64, i64, i64, Vec<String>)> = Vec::new();
+    for s in sorted {
+        let from = s.start.max(start);
+        let until = s.end.min(end);
+        if until <= from {
+            continue;
+        }
+        let key = s.issue.clone().unwrap();
+        match runs.last_mut() {
+            Some(run) if run.0 == key && from - run.2 <= MERGE_GAP_MS => {
+                run.2 = until;
+                run.3 += until - from;
+                if !run.4.contains(&s.name) {
+                    run.4.push(s.name.clone());
+                }
+            }
+            _ => runs.push((key, from, until, until - from, vec![s.name.clone()])),
+        }
+    }
+
+    runs.into_iter()
+        .filter_map(|(issue_key, started, _, ms, apps)| {
+            let seconds = (ms / 1000 + 30) / 60 * 60;
+            (seconds >= MIN_WORKLOG_SECS).then(|| Worklog {
+                issue_key,
+                started,
+                seconds,
+                comment: apps.join(", "),
+            })
+        })
+        .collect()
+}
+
+fn local_time(ts: i64) -> chrono::DateTime<chrono::Local> {
+    chrono::Local
+        .timestamp_millis_opt(ts)
+        .single()
+        .unwrap_or_else(chrono::Local::now)
+}
+
+fn csv_field(value: &str) -> String {
+    if value.contains([',', '"', '\n']) {
+        format!("\"{}\"", value.replace('"', "\"\""))
+    } else {
+        value.to_string()
+    }
+}
+
+/// Jira CSV 导入格式，Worklog 列为 `comment;yyyy-MM-dd HH:mm;author;seconds`
+pub fn to_csv(worklogs: &[Worklog], author: &str) -> String {
+    let mut out = String::from("Issue Key,Worklog\n");
+    for w in worklogs {
+        let worklog = format!(
+            "{};{};{};{}",
+            w.comment.replace(';', ","),
+            local_time(w.started).format("%Y-%m-%d %H:%M"),
+            author,
+            w.seconds
+        );
+        out.push_str(&format!(
+            "{},{}\n",
+            csv_field(&w.issue_key),
+            csv_field(&worklog)
+        ));
+    }
+    out
+}
+
+#[derive(Serialize)]
+struct JiraImport {
+    projects: Vec<JiraProject>,
+}
+
+#[derive(Serialize)]
+struct JiraProject {
+    key: String,
+    issues: Vec<JiraIssue>,
+}
+
+#[derive(Serialize)]
+struct JiraIssue {
+    key: String,
+    worklogs: Vec<JiraWorklog>,
+}
+
+#[derive(Serialize)]
+#[serde(rename_all = "camelCase")]
+struct JiraWorklog {
+    author: String,
+    comment: String,
+    start_date: String, // ISO 8601，例如 2024-05-01T09:30:00.000+0800
+    time_spent: String, // ISO 8601 时长，例如 PT1H30M
+}
+
+/// Jira JSON 导入格式（projects → issues → worklogs）
+pub fn to_json(worklogs: &[Worklog], author: &str) -> anyhow::Result<String> {
+    let mut projects: BTreeMap<String, BTreeMap<String, Vec<JiraWorklog>>> = BTreeMap::new();
+    for w in worklogs {
+        let project = w
+            .issue_key
+            .rsplit_once('-')
+            .map(|(p, _)| p)
+            .unwrap_or(&w.issue_key);
+        projects
+            .entry(project.to_string())
+            .or_default()
+            .entry(w.issue_key.clone())
+            .or_default()
+            .push(JiraWorklog {
+                author: author.to_string(),
+                comment: w.comment.clone(),
+                start_date: local_time(w.started)
+                    .format("%Y-%m-%dT%H:%M:%S%.3f%z")
+                    .to_string(),
+                time_spent: format!("PT{}H{}M", w.seconds / 3600, w.seconds % 3600 / 60),
+            });
+    }
+
+    let import = JiraImport {
+        projects: projects
+            .into_iter()
+            .map(|(key, issues)| JiraProject {
+                key,
+                issues: issues
+                    .into_iter()
+                    .map(|(key, worklogs)| JiraIssue { key, worklogs })
+                    .collect(),
+            })
+            .collect(),
+    };
+    Ok(serde_json::to_string_pretty(&import)?)
+}
+
+/// 默认作者为当前系统用户名
+fn default_author() -> String {
+    std::env::var("USER")
+        .or_else(|_| std::env::var("USERNAME"))
+        .unwrap_or_default()
+}
+
+pub fn get_issue_time(start: i64, end: i64) -> Vec<IssueTime> {
+    let sessions = crate::spy::session::sessions_between(start, end);
+    issue_time(&sessions, start, end)
+}
+
+pub fn export_worklogs(
+    start: i64,
+    end: i64,
+    format: WorklogFormat,
+    author: Option<String>,
+) -> anyhow::Result<String> {
+    let sessions = crate::spy::session::sessions_between(start, end);
+    let worklogs = worklogs(&sessions, start, end);
+    let author = author.unwrap_or_else(default_author);
+    match format {
+        WorklogFormat::Csv => Ok(to_csv(&worklogs, &author)),
+        WorklogFormat::Json => to_json(&worklogs, &author),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(name: &str, issue: Option<&str>, start: i64, end: i64) -> Session {
+        Session {
+            name: name.to_string(),
+            issue: issue.map(|i| i.to_string()),
+            start,
+            end,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_extract_keys() {
+        let extractor = IssueExtractor {
+            pattern: Regex::new(DEFAULT_PATTERN).unwrap(),
+            projects: HashSet::new(),
+        };
+        assert_eq!(
+            extractor.keys("[PROJ-1234] Fix login - Jira, see also OPS-7"),
+            vec!["PROJ-1234", "OPS-7"]
+        );
+        assert!(extractor.keys("proj-1234 utf8 x-1").is_empty());
+
+        let extractor = IssueExtractor {
+            projects: HashSet::from(["PROJ".to_string()]),
+            ..extractor
+        };
+        assert_eq!(extractor.keys("UTF-8 PROJ-9"), vec!["PROJ-9"]);
+    }
+
+    #[test]
+    fn test_worklogs_merge_and_round() {
+        let sessions = vec![
+            session("code", Some("PROJ-1"), 0, 600_000),
+            session("firefox", Some("PROJ-1"), 600_000, 900_000),
+            session("slack", None, 900_000, 1_000_000),
+            session("code", Some("PROJ-1"), 1_000_000, 1_200_000),
+            session("code", Some("PROJ-2"), 1_200_000, 1_220_000),
+            session("code", Some("PROJ-1"), 9_000_000, 9_100_000),
+        ];
+        let logs = worklogs(&sessions, 0, 10_000_000);
+        assert_eq!(logs.len(), 2);
+        assert_eq!(logs[0].issue_key, "PROJ-1");
+        assert_eq!(logs[0].started, 0);
+        assert_eq!(logs[0].seconds, 1_080);
+        assert_eq!(logs[0].comment, "code, firefox");
+        assert_eq!(logs[1].seconds, 120);
+
+        let total = issue_time(&sessions, 0, 10_000_000);
+        assert_eq!(total[0].key, "PROJ-1");
+        assert_eq!(total[0].seconds, 1_200.0);
+    }
+
+    #[test]
+    fn test_export_formats() {
+        let logs = vec![Worklog {
+            issue_key: "PROJ-1".to_string(),
+            started: 0,
+            seconds: 5_400,
+            comment: "code; firefox".to_string(),
+        }];
+        let csv = to_csv(&logs, "bob");
+        let line = csv.lines().nth(1).unwrap();
+        assert!(line.starts_with("PROJ-1,\"code, firefox;"));
+        assert!(line.ends_with(";bob;5400\""));
+
+        let json: serde_json::Value =
+            serde_json::from_str(&to_json(&logs, "bob").unwrap()).unwrap();
+        let worklog = &json["projects"][0]["issues"][0]["worklogs"][0];
+        assert_eq!(json["projects"][0]["key"], "PROJ");
+        assert_eq!(worklog["timeSpent"], "PT1H30M");
+        assert_eq!(worklog["author"], "bob");
+    }
+}
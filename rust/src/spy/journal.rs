@@ -0,0 +1,570 @@
+//! 事件日志
+//!
+//! 只追加的日志文件，每条记录为 `长度(u32 LE) | CRC32(u32 LE) | JSON`。前台采样（连同资源占用）
+//! 和追踪事件在发给订阅者之前先写入并同步到磁盘，事件总线写日志时不持有自己的状态锁。
+//! 进程崩溃或断电后启动时校验所有记录，截掉末尾写了一半或校验失败的记录，再按记录重建会话。
+//!
+//! 会话最多保留 MAX_SESSIONS 个，日志记录数增长到一定数量后在后台压缩，只保留最早的会话开始之后的
+//! 记录，以及此前仍然有效的状态（进行中的会议、离开）。压缩时只在最后替换文件时持有日志锁。
+
+use std::fs::{File, OpenOptions};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use crate::spy::event::{TrackerEvent, TrackerEventKind};
+use crate::spy::model::Application;
+use crate::spy::resource::ResourceUsage;
+use crate::spy::session::{SessionStore, SESSIONS};
+
+/// 记录头：长度和校验和
+const HEADER_LEN: usize = 8;
+
+/// 单条记录的最大长度，超过时视为损坏
+const MAX_RECORD_LEN: usize = 16 * 1024 * 1024;
+
+/// 上次压缩后又写入这么多条记录时压缩日志
+const COMPACT_RECORDS: usize = 16_384;
+
+/// 一条日志记录
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+pub enum JournalEntry {
+    // 前台采样，时间为采样时间
+    Sample {
+        at: i64,
+        app: Box<Application>,
+        #[serde(default)]
+        usage: Option<ResourceUsage>, // 前台进程树的资源占用
+        #[serde(default)]
+        background: Vec<(String, ResourceUsage)>, // 后台应用的资源占用
+    },
+    // 追踪事件
+    Event {
+        event: Box<TrackerEvent>,
+    },
+    // 进程启动后打开日志，之前的会话都已经结束
+    Opened {
+        at: i64,
+    },
+}
+
+impl JournalEntry {
+    /// 记录的时间（unix 毫秒）
+    pub fn at(&self) -> i64 {
+        match self {
+            JournalEntry::Sample { at, .. } | JournalEntry::Opened { at } => *at,
+            JournalEntry::Event { event } => event.timestamp,
+        }
+    }
+}
+
+/// 启动时恢复日志的结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct JournalRecovery {
+    pub records: u32,         // 完好的记录数
+    pub truncated_bytes: u64, // 截掉的损坏字节数
+    pub sessions: u32,        // 重建的会话数，已有会话时不重建，为 0
+}
+
+pub fn encode(entry: &JournalEntry) -> anyhow::Result<Vec<u8>> {
+    let payload = serde_json::to_vec(entry)?;
+    let mut record = Vec::with_capacity(HEADER_LEN + payload.len());
+    record.extend_from_slice(&(payload.len() as u32).to_le_bytes());
+    record.extend_from_slice(&crc32fast::hash(&payload).to_le_bytes());
+    record.extend_from_slice(&payload);
+    Ok(record)
+}
+
+/// 读取 data 开头连续的完好记录，返回记录和完好部分的字节数
+pub fn decode(data: &[u8]) -> (Vec<JournalEntry>, usize) {
+    let mut entries = Vec::new();
+    let mut offset = 0;
+    while let Some(header) = data.get(offset..offset + HEADER_LEN) {
+        let len = u32::from_le_bytes(header[0..4].try_into().unwrap()) as usize;
+        let checksum = u32::from_le_bytes(header[4..8].try_into().unwrap());
+        if len > MAX_RECORD_LEN {
+            break;
+        }
+        let start = offset + HEADER_LEN;
+        let Some(payload) = data.get(start..start + len) else {
+            break;
+        };
+        if crc32fast::hash(payload) != checksum {
+            break;
+        }
+        let Ok(entry) = serde_json::from_slice(payload) else {
+            break;
+        };
+        entries.push(entry);
+        offset = start + len;
+    }
+    (entries, offset)
+}
+
+pub struct Journal {
+    path: PathBuf,
+    file: File,
+    len: u64,          // 完好部分的长度
+    records: usize,    // 记录数
+    compact_at: usize, // 记录数达到这个数量时压缩
+}
+
+impl Journal {
+    /// 打开或创建日志，截掉末尾损坏的记录，返回日志、已有记录和截掉的字节数
+    pub fn open(path: &Path) -> anyhow::Result<(Journal, Vec<JournalEntry>, u64)> {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create(true)
+            .truncate(false)
+            .open(path)?;
+        let mut data = Vec::new();
+        file.read_to_end(&mut data)?;
+        let (entries, valid) = decode(&data);
+        let truncated = (data.len() - valid) as u64;
+        if truncated > 0 {
+            file.set_len(valid as u64)?;
+            file.sync_all()?;
+        }
+        file.seek(SeekFrom::End(0))?;
+        let journal = Journal {
+            path: path.to_path_buf(),
+            file,
+            len: valid as u64,
+            records: entries.len(),
+            compact_at: entries.len() + COMPACT_RECORDS,
+        };
+        Ok((journal, entries, truncated))
+    }
+
+    /// 追加一条记录并同步到磁盘；写入失败时退回到写入前的长度，不留下半条记录
+    pub fn append(&mut self, entry: &JournalEntry) -> anyhow::Result<()> {
+        let record = encode(entry)?;
+        let written = self
+            .file
+            .write_all(&record)
+            .and_then(|_| self.file.sync_data());
+        if let Err(e) = written {
+            let _ = self.file.set_len(self.len);
+            let _ = self.file.seek(SeekFrom::Start(self.len));
+            return Err(e.into());
+        }
+        self.len += record.len() as u64;
+        self.records += 1;
+        Ok(())
+    }
+
+    /// 写入的记录数是否已经需要压缩
+    pub fn should_compact(&self) -> bool {
+        self.records >= self.compact_at
+    }
+}
+
+/// 压缩时保留的记录：keep_from 之后的所有记录（按文件顺序），加上之前仍然有效的状态——
+/// 还没结束的会议和还没回来的离开，重建会话时需要它们
+pub fn compacted(entries: &[JournalEntry], keep_from: i64) -> Vec<&JournalEntry> {
+    let cut = entries
+        .iter()
+        .position(|e| e.at() >= keep_from)
+        .unwrap_or(entries.len());
+    let mut meeting = None;
+    let mut away = None;
+    for (i, entry) in entries[..cut].iter().enumerate() {
+        match entry {
+            JournalEntry::Opened { .. } => (meeting, away) = (None, None),
+            JournalEntry::Event { event } => match event.kind {
+                TrackerEventKind::MeetingStarted => meeting = Some(i),
+                TrackerEventKind::MeetingEnded => meeting = None,
+                TrackerEventKind::Idle => away = Some(i),
+                TrackerEventKind::Resumed => away = None,
+                _ => {}
+            },
+            JournalEntry::Sample { .. } => {}
+        }
+    }
+    let mut state: Vec<usize> = meeting.into_iter().chain(away).collect();
+    state.sort();
+    state
+        .into_iter()
+        .map(|i| &entries[i])
+        .chain(&entries[cut..])
+        .collect()
+}
+
+/// 压缩分两步：先不持有日志锁，把已有记录中需要保留的写入临时文件；再持有日志锁，把这期间追加的
+/// 记录接到临时文件末尾，替换原文件
+pub struct Compaction {
+    tmp: PathBuf,
+    file: File,
+    len: u64,       // 准备时原文件的长度
+    records: usize, // 准备时原文件的记录数
+    kept_len: u64,
+    kept_records: usize,
+}
+
+impl Compaction {
+    pub fn prepare(
+        path: &Path,
+        len: u64,
+        records: usize,
+        keep_from: i64,
+    ) -> anyhow::Result<Compaction> {
+        let mut data = Vec::new();
+        File::open(path)?.take(len).read_to_end(&mut data)?;
+        let (entries, _) = decode(&data);
+        let mut kept = Vec::new();
+        let kept_entries = compacted(&entries, keep_from);
+        for entry in &kept_entries {
+            kept.extend_from_slice(&encode(entry)?);
+        }
+
+        let tmp = path.with_extension("tmp");
+        let mut file = OpenOptions::new()
+            .write(true)
+            .create(true)
+            .truncate(true)
+            .open(&tmp)?;
+        file.write_all(&kept)?;
+        Ok(Compaction {
+            tmp,
+            file,
+            len,
+            records,
+            kept_len: kept.len() as u64,
+            kept_records: kept_entries.len(),
+        })
+    }
+
+    /// 接上准备之后追加的记录，同步后替换原文件并同步目录，再重新打开；
+    /// 替换失败时原文件保持不变，继续使用
+    pub fn finish(mut self, journal: &mut Journal) -> anyhow::Result<()> {
+        let mut tail = Vec::new();
+        journal.file.seek(SeekFrom::Start(self.len))?;
+        journal.file.read_to_end(&mut tail)?;
+        self.file.write_all(&tail)?;
+        self.file.sync_all()?;
+        drop(self.file);
+        if let Err(e) = std::fs::rename(&self.tmp, &journal.path) {
+            let _ = std::fs::remove_file(&self.tmp);
+            return Err(e.into());
+        }
+        sync_dir(&journal.path)?;
+
+        let mut file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .open(&journal.path)?;
+        file.seek(SeekFrom::End(0))?;
+        journal.file = file;
+        journal.len = self.kept_len + tail.len() as u64;
+        journal.records = self.kept_records + (journal.records - self.records);
+        journal.compact_at = journal.records + COMPACT_RECORDS;
+        Ok(())
+    }
+}
+
+/// 同步日志所在目录，保证替换文件的 rename 落盘
+#[cfg(unix)]
+fn sync_dir(path: &Path) -> std::io::Result<()> {
+    match path.parent().filter(|d| !d.as_os_str().is_empty()) {
+        Some(dir) => File::open(dir)?.sync_all(),
+        None => Ok(()),
+    }
+}
+
+/// Windows 不能打开目录同步，rename 由文件系统日志保证
+#[cfg(not(unix))]
+fn sync_dir(_path: &Path) -> std::io::Result<()> {
+    Ok(())
+}
+
+/// 按日志重建会话，与实时采样的处理一致：离开期间的采样不计入会话，会议期间的会话按会议切分。
+/// 重建出的最后一个会话属于上一次运行，同样结束，之后的采样开启新会话
+pub fn rebuild_sessions(entries: &[JournalEntry]) -> SessionStore {
+    let mut store = SessionStore::default();
+    let mut away = false;
+    let mut meeting: Option<(u64, i64)> = None; // 进行中的会议 (id, 开始时间)
+    for entry in entries {
+        match entry {
+            JournalEntry::Sample {
+                at,
+                app,
+                usage,
+                background,
+            } if !away => {
+                store.record(app, *at);
+                store.record_usage(usage.as_ref(), background);
+                if let Some((id, start)) = meeting {
+                    store.tag_meeting(id, start, *at, true);
+                }
+            }
+            JournalEntry::Sample { .. } => {}
+            JournalEntry::Event { event } => match (event.kind, event.meeting_id) {
+                (TrackerEventKind::Idle, _) => {
+                    away = true;
+                    store.close_at(event.timestamp);
+                }
+                (TrackerEventKind::Resumed, _) => away = false,
+                (TrackerEventKind::MeetingStarted, Some(id)) => {
+                    meeting = Some((id, event.timestamp));
+                    store.tag_meeting(id, event.timestamp, event.timestamp, true);
+                }
+                (TrackerEventKind::MeetingEnded, Some(id)) => {
+                    if let Some((_, start)) = meeting.take().filter(|(m, _)| *m == id) {
+                        store.tag_meeting(id, start, event.timestamp, false);
+                    }
+                }
+                _ => {}
+            },
+            JournalEntry::Opened { .. } => {
+                store.close();
+                away = false;
+                meeting = None;
+            }
+        }
+    }
+    store.close();
+    store
+}
+
+static JOURNAL: Lazy<Mutex<Option<Journal>>> = Lazy::new(|| Mutex::new(None));
+
+/// 在后台压缩日志，只在最后替换文件时持有日志锁
+fn compact_journal() {
+    let Some((path, len, records)) = JOURNAL
+        .lock()
+        .unwrap()
+        .as_ref()
+        .map(|j| (j.path.clone(), j.len, j.records))
+    else {
+        return;
+    };
+    let Some(keep_from) = SESSIONS.lock().unwrap().oldest_start() else {
+        return;
+    };
+    let result = Compaction::prepare(&path, len, records, keep_from).and_then(|prepared| {
+        match JOURNAL.lock().unwrap().as_mut() {
+            Some(journal) => prepared.finish(journal).map(|_| journal.records),
+            None => Ok(0),
+        }
+    });
+    match result {
+        Ok(records) => println!("[journal] compacted to {} records", records),
+        Err(e) => println!("[journal] compact error: {}", e),
+    }
+}
+
+/// 打开日志并恢复，还没有会话时按日志重建会话，恢复总线的最近事件，然后记录这次启动
+pub fn init(path: &str) -> anyhow::Result<JournalRecovery> {
+    if JOURNAL.lock().unwrap().is_some() {
+        anyhow::bail!("journal already opened");
+    }
+    let (mut opened, entries, truncated_bytes) = Journal::open(Path::new(path))?;
+    if truncated_bytes > 0 {
+        println!(
+            "[journal] truncated {} bytes of torn records",
+            truncated_bytes
+        );
+    }
+    let mut sessions = 0;
+    let mut current = SESSIONS.lock().unwrap();
+    if current.is_empty() {
+        *current = rebuild_sessions(&entries);
+        sessions = current.between(i64::MIN, i64::MAX).len() as u32;
+    }
+    drop(current);
+    // 恢复总线时不持有日志锁：发布事件时先持有总线的发布锁再写日志
+    crate::spy::bus::restore(&entries);
+    let mut journal = JOURNAL.lock().unwrap();
+    if journal.is_some() {
+        anyhow::bail!("journal already opened");
+    }
+    let now = chrono::Local::now().timestamp_millis();
+    opened.append(&JournalEntry::Opened { at: now })?;
+    *journal = Some(opened);
+    Ok(JournalRecovery {
+        records: entries.len() as u32,
+        truncated_bytes,
+        sessions,
+    })
+}
+
+/// 写入日志，记录数达到压缩阈值时在后台压缩；日志没有打开时忽略
+pub fn append(entry: &JournalEntry) {
+    if let Some(journal) = JOURNAL.lock().unwrap().as_mut() {
+        if let Err(e) = journal.append(entry) {
+            println!("[journal] append error: {}", e);
+            return;
+        }
+        if journal.should_compact() {
+            // 压缩完成前不再触发
+            journal.compact_at = journal.records + COMPACT_RECORDS;
+            std::thread::spawn(compact_journal);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::spy::meeting::{MeetingEvent, MeetingEventKind};
+
+    fn app(name: &str) -> Box<Application> {
+        Box::new(Application {
+            name: name.to_string(),
+            title: format!("{} window", name),
+            ..Default::default()
+        })
+    }
+
+    fn sample(name: &str, at: i64) -> JournalEntry {
+        JournalEntry::Sample {
+            at,
+            app: app(name),
+            usage: None,
+            background: Vec::new(),
+        }
+    }
+
+    fn event(kind: TrackerEventKind, at: i64) -> JournalEntry {
+        JournalEntry::Event {
+            event: Box::new(TrackerEvent::new(kind, at)),
+        }
+    }
+
+    fn meeting(kind: MeetingEventKind, timestamp: i64) -> JournalEntry {
+        JournalEntry::Event {
+            event: Box::new(TrackerEvent::meeting(&MeetingEvent {
+                kind,
+                meeting_id: 1,
+                app: None,
+                timestamp,
+            })),
+        }
+    }
+
+    #[test]
+    fn test_recover_torn_journal() {
+        let path = std::env::temp_dir().join(format!("spy-journal-{}.log", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let (mut journal, entries, truncated) = Journal::open(&path).unwrap();
+        assert!(entries.is_empty());
+        assert_eq!(truncated, 0);
+        journal.append(&sample("code", 0)).unwrap();
+        journal.append(&sample("code", 60_000)).unwrap();
+        drop(journal);
+
+        // 模拟崩溃：最后一条记录只写了一半
+        let torn = encode(&sample("firefox", 120_000)).unwrap();
+        let mut file = OpenOptions::new().append(true).open(&path).unwrap();
+        file.write_all(&torn[..torn.len() - 5]).unwrap();
+        drop(file);
+
+        let (mut journal, entries, truncated) = Journal::open(&path).unwrap();
+        assert_eq!(entries.len(), 2);
+        assert_eq!(truncated, torn.len() as u64 - 5);
+        journal.append(&sample("firefox", 120_000)).unwrap();
+        drop(journal);
+
+        // 校验和不对的记录及其之后的内容都丢弃
+        let mut data = std::fs::read(&path).unwrap();
+        let last = data.len() - 1;
+        data[last] ^= 0xff;
+        let (entries, valid) = decode(&data);
+        assert_eq!(entries.len(), 2);
+        assert_eq!(valid, data.len() - torn.len());
+
+        let (_, entries, _) = Journal::open(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        assert_eq!(entries.len(), 3);
+    }
+
+    #[test]
+    fn test_compact_journal() {
+        let path = std::env::temp_dir().join(format!("spy-compact-{}.log", std::process::id()));
+        let _ = std::fs::remove_file(&path);
+
+        let (mut journal, _, _) = Journal::open(&path).unwrap();
+        for entry in [
+            sample("code", 0),
+            event(TrackerEventKind::Idle, 30_000),
+            event(TrackerEventKind::Resumed, 50_000),
+            meeting(MeetingEventKind::Started, 60_000), // 压缩点时仍在进行的会议
+            sample("zoom", 90_000),
+            sample("zoom", 120_000),
+        ] {
+            journal.append(&entry).unwrap();
+        }
+        let prepared = Compaction::prepare(&path, journal.len, journal.records, 120_000).unwrap();
+        // 准备之后追加的记录也要保留
+        journal.append(&sample("zoom", 150_000)).unwrap();
+        prepared.finish(&mut journal).unwrap();
+        assert_eq!(journal.records, 3);
+        // 压缩后继续追加到新文件
+        journal.append(&sample("zoom", 180_000)).unwrap();
+        drop(journal);
+
+        let (_, entries, truncated) = Journal::open(&path).unwrap();
+        let _ = std::fs::remove_file(&path);
+        let ats: Vec<i64> = entries.iter().map(|e| e.at()).collect();
+        assert_eq!(ats, [60_000, 120_000, 150_000, 180_000]);
+        assert_eq!(truncated, 0);
+        assert!(!path.with_extension("tmp").exists());
+    }
+
+    #[test]
+    fn test_rebuild_sessions() {
+        let entries = [
+            sample("code", 0),
+            sample("code", 60_000),
+            event(TrackerEventKind::Idle, 60_000),
+            sample("code", 120_000), // 离开期间的采样
+            event(TrackerEventKind::Resumed, 180_000),
+            sample("code", 180_000),
+            JournalEntry::Sample {
+                at: 240_000,
+                app: app("firefox"),
+                usage: Some(ResourceUsage {
+                    cpu: 10.0,
+                    ..Default::default()
+                }),
+                background: Vec::new(),
+            },
+            meeting(MeetingEventKind::Started, 270_000),
+            sample("firefox", 300_000),
+            meeting(MeetingEventKind::Ended, 300_000),
+            // 重新启动：上一次运行的会话已经结束
+            JournalEntry::Opened { at: 900_000 },
+            sample("firefox", 960_000),
+        ];
+        let mut store = rebuild_sessions(&entries);
+        // 重建之后同一应用的采样也开启新会话，不跨过停止运行的时间
+        store.record(&app("firefox"), 2_000_000);
+
+        let sessions = store.between(i64::MIN, i64::MAX);
+        let spans: Vec<(&str, i64, i64, Option<u64>)> = sessions
+            .iter()
+            .map(|s| (s.name.as_str(), s.start, s.end, s.meeting))
+            .collect();
+        assert_eq!(
+            spans,
+            [
+                ("code", 0, 60_000, None),
+                ("code", 180_000, 240_000, None),
+                ("firefox", 240_000, 270_000, None),
+                ("firefox", 270_000, 300_000, Some(1)),
+                ("firefox", 960_000, 960_000, None),
+                ("firefox", 2_000_000, 2_000_000, None),
+            ]
+        );
+        assert_eq!(sessions[3].usage.samples, 1);
+    }
+}
@@ -0,0 +1,565 @@
+//! 应用启动与退出
+//!
+//! 前台时间统计不到在后台运行的应用（构建、下载、音乐播放器），这里跟踪应用进程的启动和退出，
+//! 得到每个应用的运行时间。同名应用的多个进程合并计算：第一个进程启动时在追踪事件流上发出
+//! `AppLaunched`，最后一个进程退出时发出 `AppExited`。
+//!
+//! Linux 上优先使用 netlink proc connector 实时接收事件，没有权限时退回定时扫描进程列表。
+
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+use sysinfo::{ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
+
+use crate::spy::event::{self, TrackerEvent};
+use crate::spy::session::Session;
+
+/// 轮询进程列表的间隔
+const POLL_SECS: u64 = 5;
+
+/// 内存中最多保留的事件和运行区间数量
+const MAX_RECORDS: usize = 4096;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum LifecycleKind {
+    Launched,
+    Exited,
+}
+
+/// 应用启动或退出事件
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppLifecycleEvent {
+    pub kind: LifecycleKind,
+    pub name: String,   // 与 Application.name 一致的应用名称
+    pub pid: u32,       // 第一个启动或最后一个退出的进程
+    pub timestamp: i64, // unix 毫秒
+}
+
+/// 应用在一段时间内的运行时间和前台时间
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppRunningTime {
+    pub name: String,
+    pub running_seconds: i64,
+    pub focused_seconds: i64,
+}
+
+/// 应用的一段运行区间，end 为空表示仍在运行
+#[derive(Debug, Clone, PartialEq)]
+struct RunningInterval {
+    name: String,
+    start: i64,
+    end: Option<i64>,
+}
+
+#[derive(Default)]
+pub struct LifecycleTracker {
+    pids: HashMap<u32, String>,             // 被跟踪的进程 → 应用名
+    running: HashMap<String, HashSet<u32>>, // 正在运行的应用 → 进程
+    intervals: VecDeque<RunningInterval>,
+    events: VecDeque<AppLifecycleEvent>,
+}
+
+impl LifecycleTracker {
+    fn push_event(&mut self, event: AppLifecycleEvent) -> AppLifecycleEvent {
+        self.events.push_back(event.clone());
+        if self.events.len() > MAX_RECORDS {
+            self.events.pop_front();
+        }
+        event
+    }
+
+    /// 进程启动（或 exec 成了另一个程序），应用的第一个进程启动时返回 Launched
+    pub fn started(&mut self, pid: u32, name: &str, now: i64) -> Vec<AppLifecycleEvent> {
+        let mut events = Vec::new();
+        match self.pids.get(&pid) {
+            Some(current) if current == name => return events,
+            Some(_) => events.extend(self.exited(pid, now)),
+            None => {}
+        }
+        self.pids.insert(pid, name.to_string());
+        let pids = self.running.entry(name.to_string()).or_default();
+        pids.insert(pid);
+        if pids.len() == 1 {
+            self.intervals.push_back(RunningInterval {
+                name: name.to_string(),
+                start: now,
+                end: None,
+            });
+            if self.intervals.len() > MAX_RECORDS {
+                self.intervals.pop_front();
+            }
+            events.push(self.push_event(AppLifecycleEvent {
+                kind: LifecycleKind::Launched,
+                name: name.to_string(),
+                pid,
+                timestamp: now,
+            }));
+        }
+        events
+    }
+
+    /// 进程退出，应用的最后一个进程退出时返回 Exited
+    pub fn exited(&mut self, pid: u32, now: i64) -> Option<AppLifecycleEvent> {
+        let name = self.pids.remove(&pid)?;
+        let pids = self.running.get_mut(&name)?;
+        pids.remove(&pid);
+        if !pids.is_empty() {
+            return None;
+        }
+        self.running.remove(&name);
+        if let Some(interval) = self
+            .intervals
+            .iter_mut()
+            .rev()
+            .find(|i| i.name == name && i.end.is_none())
+        {
+            interval.end = Some(now);
+        }
+        Some(self.push_event(AppLifecycleEvent {
+            kind: LifecycleKind::Exited,
+            name,
+            pid,
+            timestamp: now,
+        }))
+    }
+
+    /// 用完整的进程快照（pid → 应用名）同步状态，返回变化产生的事件
+    pub fn sync(&mut self, snapshot: &HashMap<u32, String>, now: i64) -> Vec<AppLifecycleEvent> {
+        let gone: Vec<u32> = self
+            .pids
+            .keys()
+            .filter(|pid| !snapshot.contains_key(pid))
+            .copied()
+            .collect();
+        let mut events: Vec<AppLifecycleEvent> = gone
+            .into_iter()
+            .filter_map(|pid| self.exited(pid, now))
+            .collect();
+        for (pid, name) in snapshot {
+            events.extend(self.started(*pid, name, now));
+        }
+        events
+    }
+
+    pub fn events_between(&self, start: i64, end: i64) -> Vec<AppLifecycleEvent> {
+        self.events
+            .iter()
+            .filter(|e| e.timestamp >= start && e.timestamp <= end)
+            .cloned()
+            .collect()
+    }
+
+    /// 各应用在 [start, end] 内的运行毫秒数，未结束的区间计算到 now
+    fn running_millis(&self, start: i64, end: i64, now: i64) -> HashMap<String, i64> {
+        let mut result: HashMap<String, i64> = HashMap::new();
+        for interval in &self.intervals {
+            let from = interval.start.max(start);
+            let to = interval.end.unwrap_or(now).min(end);
+            if to > from {
+                *result.entry(interval.name.clone()).or_default() += to - from;
+            }
+        }
+        result
+    }
+}
+
+/// 合并运行时间与前台会话时间，按运行时间降序
+pub fn running_time(
+    tracker: &LifecycleTracker,
+    sessions: &[Session],
+    start: i64,
+    end: i64,
+    now: i64,
+) -> Vec<AppRunningTime> {
+    let running = tracker.running_millis(start, end, now);
+    let mut focused: HashMap<String, i64> = HashMap::new();
+    for session in sessions {
+        let from = session.start.max(start);
+        let to = session.end.min(end);
+        if to > from {
+            *focused.entry(session.name.clone()).or_default() += to - from;
+        }
+    }
+
+    let names: HashSet<&String> = running.keys().chain(focused.keys()).collect();
+    let mut result: Vec<AppRunningTime> = names
+        .into_iter()
+        .map(|name| AppRunningTime {
+            name: name.clone(),
+            running_seconds: running.get(name).copied().unwrap_or(0) / 1000,
+            focused_seconds: focused.get(name).copied().unwrap_or(0) / 1000,
+        })
+        .collect();
+    result.sort_by(|a, b| {
+        b.running_seconds
+            .cmp(&a.running_seconds)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    result
+}
+
+pub static TRACKER: Lazy<Mutex<LifecycleTracker>> =
+    Lazy::new(|| Mutex::new(LifecycleTracker::default()));
+
+static WATCHER: Lazy<Mutex<Option<Arc<AtomicBool>>>> = Lazy::new(|| Mutex::new(None));
+
+fn now_millis() -> i64 {
+    chrono::Local::now().timestamp_millis()
+}
+
+fn send(events: Vec<AppLifecycleEvent>) {
+    for event in events {
+        event::emit(TrackerEvent::lifecycle(&event));
+    }
+}
+
+/// 进程解析出的应用：与前台采样命名一致的可执行文件名，Linux 上还有 desktop 文件给出的名称
+#[derive(Debug, Clone, PartialEq)]
+struct ResolvedApp {
+    exe: String,
+    desktop: Option<String>,
+}
+
+/// 解析进程对应的应用，结果可以按进程缓存；没有可执行文件路径时返回 None
+fn resolve_app(pid: u32, exe_path: &str) -> Option<ResolvedApp> {
+    if exe_path.is_empty() {
+        return None;
+    }
+    let runtime = crate::spy::runtime::resolve(exe_path, Some(pid));
+    let path = std::path::Path::new(exe_path);
+    let exe = match &runtime {
+        Some(r) => r.name.clone(),
+        #[cfg(target_os = "windows")]
+        None => path.file_stem()?.to_string_lossy().into_owned(),
+        #[cfg(not(target_os = "windows"))]
+        None => path.file_name()?.to_string_lossy().into_owned(),
+    };
+
+    #[cfg(target_os = "linux")]
+    let desktop = crate::spy::platform::desktop_entry::lookup("", "", &exe)
+        .filter(|entry| !entry.no_display)
+        .map(|entry| entry.name);
+    #[cfg(not(target_os = "linux"))]
+    let desktop = None;
+    Some(ResolvedApp { exe, desktop })
+}
+
+/// 需要跟踪时返回应用名称。跟踪的应用：Linux 上有 desktop 文件的、配置为后台采样的，
+/// 以及已经在前台出现过的——前台应用随时会增加，不能按进程缓存这个结果
+fn tracked_name(app: &ResolvedApp, focused: &HashSet<String>) -> Option<String> {
+    if let Some(name) = &app.desktop {
+        return Some(name.clone());
+    }
+    let background = crate::spy::resource::BACKGROUND_APPS.lock().unwrap();
+    if focused.contains(&app.exe) || background.iter().any(|b| b.eq_ignore_ascii_case(&app.exe)) {
+        return Some(app.exe.clone());
+    }
+    None
+}
+
+/// 曾经在前台出现过的应用名称
+fn focused_names() -> HashSet<String> {
+    crate::spy::session::sessions_between(i64::MIN, i64::MAX)
+        .into_iter()
+        .map(|s| s.name)
+        .collect()
+}
+
+/// 扫描当前用户的所有进程，返回 pid → 解析出的应用
+fn snapshot(
+    system: &mut System,
+    apps: &mut HashMap<(u32, u64), Option<ResolvedApp>>,
+) -> HashMap<u32, ResolvedApp> {
+    system.refresh_processes_specifics(
+        ProcessesToUpdate::All,
+        true,
+        ProcessRefreshKind::nothing()
+            .with_exe(UpdateKind::OnlyIfNotSet)
+            .with_user(UpdateKind::OnlyIfNotSet),
+    );
+    let me = sysinfo::get_current_pid()
+        .ok()
+        .and_then(|pid| system.process(pid))
+        .and_then(|p| p.user_id().cloned());
+
+    // 解析结果按 (pid, 启动时间) 缓存，pid 复用时重新解析
+    let alive: HashSet<(u32, u64)> = system
+        .processes()
+        .iter()
+        .map(|(pid, p)| (pid.as_u32(), p.start_time()))
+        .collect();
+    apps.retain(|key, _| alive.contains(key));
+
+    let mut result = HashMap::new();
+    for (pid, process) in system.processes() {
+        if process.thread_kind().is_some() || (me.is_some() && process.user_id() != me.as_ref()) {
+            continue;
+        }
+        let key = (pid.as_u32(), process.start_time());
+        let app = apps.entry(key).or_insert_with(|| {
+            let exe = process
+                .exe()
+                .map(|e| e.to_string_lossy().into_owned())
+                .unwrap_or_default();
+            resolve_app(pid.as_u32(), &exe)
+        });
+        if let Some(app) = app {
+            result.insert(pid.as_u32(), app.clone());
+        }
+    }
+    result
+}
+
+/// 按当前的前台应用挑出需要跟踪的进程
+fn tracked(apps: &HashMap<u32, ResolvedApp>, focused: &HashSet<String>) -> HashMap<u32, String> {
+    apps.iter()
+        .filter_map(|(pid, app)| Some((*pid, tracked_name(app, focused)?)))
+        .collect()
+}
+
+/// 定时扫描进程列表
+fn poll(stop: Arc<AtomicBool>) {
+    let mut system = System::new();
+    let mut apps = HashMap::new();
+    while !stop.load(Ordering::SeqCst) {
+        let snapshot = tracked(&snapshot(&mut system, &mut apps), &focused_names());
+        let events = TRACKER.lock().unwrap().sync(&snapshot, now_millis());
+        send(events);
+        std::thread::sleep(Duration::from_secs(POLL_SECS));
+    }
+}
+
+/// 通过 netlink proc connector 接收事件；先扫描一次得到已在运行的进程。
+/// 当前用户的、还不需要跟踪的进程记下来，前台应用变化时重新检查，之后第一次到前台的应用也能跟踪
+#[cfg(target_os = "linux")]
+fn listen(connector: crate::spy::platform::proc_connector::ProcConnector, stop: Arc<AtomicBool>) {
+    use crate::spy::platform::proc_connector::ProcEvent;
+
+    let mut system = System::new();
+    let mut untracked = snapshot(&mut system, &mut HashMap::new());
+    let mut focused = focused_names();
+    let initial = tracked(&untracked, &focused);
+    untracked.retain(|pid, _| !initial.contains_key(pid));
+    TRACKER.lock().unwrap().sync(&initial, now_millis());
+    let mut checked = now_millis();
+
+    let uid = unsafe { libc::getuid() };
+    while !stop.load(Ordering::SeqCst) {
+        let now = now_millis();
+        if now - checked >= POLL_SECS as i64 * 1000 {
+            checked = now;
+            let latest = focused_names();
+            if latest != focused {
+                focused = latest;
+                let started = tracked(&untracked, &focused);
+                untracked.retain(|pid, _| !started.contains_key(pid));
+                let mut tracker = TRACKER.lock().unwrap();
+                let events: Vec<_> = started
+                    .iter()
+                    .flat_map(|(pid, name)| tracker.started(*pid, name, now))
+                    .collect();
+                drop(tracker);
+                send(events);
+            }
+        }
+
+        let batch = match connector.recv() {
+            Ok(batch) => batch,
+            Err(e) => {
+                event::emit(TrackerEvent::failed(
+                    event::TrackerEventKind::Error,
+                    "lifecycle",
+                    format!("netlink error: {}, fallback to polling", e),
+                ));
+                return poll(stop);
+            }
+        };
+        if batch.is_empty() {
+            continue;
+        }
+        let mut events = Vec::new();
+        for event in batch {
+            let now = now_millis();
+            match event {
+                ProcEvent::Exec(pid) => {
+                    // 只跟踪当前用户的进程
+                    let owned = std::fs::metadata(format!("/proc/{}", pid))
+                        .map(|m| std::os::unix::fs::MetadataExt::uid(&m) == uid)
+                        .unwrap_or(false);
+                    let exe = std::fs::read_link(format!("/proc/{}/exe", pid))
+                        .map(|p| p.to_string_lossy().into_owned())
+                        .unwrap_or_default();
+                    let app = resolve_app(pid, &exe).filter(|_| owned);
+                    let name = app.as_ref().and_then(|app| tracked_name(app, &focused));
+                    let mut tracker = TRACKER.lock().unwrap();
+                    untracked.remove(&pid);
+                    match name {
+                        Some(name) => events.extend(tracker.started(pid, &name, now)),
+                        // exec 成了不需要跟踪的程序
+                        None => {
+                            events.extend(tracker.exited(pid, now));
+                            if let Some(app) = app {
+                                untracked.insert(pid, app);
+                            }
+                        }
+                    }
+                }
+                ProcEvent::Exit(pid) => {
+                    untracked.remove(&pid);
+                    events.extend(TRACKER.lock().unwrap().exited(pid, now));
+                }
+            }
+        }
+        send(events);
+    }
+}
+
+/// 启动进程监听，返回使用的方式：`netlink` 或 `poll`
+pub fn start_watcher() -> anyhow::Result<String> {
+    let mut watcher = WATCHER.lock().unwrap();
+    if watcher.is_some() {
+        anyhow::bail!("lifecycle watcher already started");
+    }
+    let stop = Arc::new(AtomicBool::new(false));
+    *watcher = Some(stop.clone());
+
+    #[cfg(target_os = "linux")]
+    {
+        use crate::spy::platform::proc_connector::ProcConnector;
+        match ProcConnector::open(Duration::from_secs(1)) {
+            Ok(connector) => {
+                std::thread::spawn(move || listen(connector, stop));
+                return Ok("netlink".to_string());
+            }
+            Err(e) => event::emit(TrackerEvent::failed(
+                event::TrackerEventKind::Error,
+                "lifecycle",
+                format!("proc connector unavailable: {}, fallback to polling", e),
+            )),
+        }
+    }
+
+    std::thread::spawn(move || poll(stop));
+    Ok("poll".to_string())
+}
+
+pub fn stop_watcher() {
+    if let Some(stop) = WATCHER.lock().unwrap().take() {
+        stop.store(true, Ordering::SeqCst);
+    }
+}
+
+pub fn get_lifecycle_events(start: i64, end: i64) -> Vec<AppLifecycleEvent> {
+    TRACKER.lock().unwrap().events_between(start, end)
+}
+
+pub fn get_running_time(start: i64, end: i64) -> Vec<AppRunningTime> {
+    let sessions = crate::spy::session::sessions_between(start, end);
+    running_time(
+        &TRACKER.lock().unwrap(),
+        &sessions,
+        start,
+        end,
+        now_millis(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_launch_and_exit_per_app() {
+        let mut tracker = LifecycleTracker::default();
+        let launched = tracker.started(10, "chrome", 1_000);
+        assert_eq!(launched.len(), 1);
+        assert_eq!(launched[0].kind, LifecycleKind::Launched);
+        // 同一应用的子进程不产生事件
+        assert!(tracker.started(11, "chrome", 1_500).is_empty());
+        assert!(tracker.started(11, "chrome", 1_600).is_empty());
+        assert!(tracker.exited(10, 2_000).is_none());
+        let exited = tracker.exited(11, 3_000).unwrap();
+        assert_eq!((exited.kind, exited.pid), (LifecycleKind::Exited, 11));
+        assert!(tracker.exited(11, 3_000).is_none());
+
+        // exec 成另一个应用
+        tracker.started(20, "bash-wrapper", 4_000);
+        let events = tracker.started(20, "idea", 4_100);
+        let kinds: Vec<_> = events.iter().map(|e| (e.kind, e.name.as_str())).collect();
+        assert_eq!(
+            kinds,
+            [
+                (LifecycleKind::Exited, "bash-wrapper"),
+                (LifecycleKind::Launched, "idea")
+            ]
+        );
+        assert_eq!(tracker.events_between(0, 3_000).len(), 2);
+    }
+
+    #[test]
+    fn test_sync_snapshot() {
+        let mut tracker = LifecycleTracker::default();
+        let snapshot = HashMap::from([(1, "code".to_string()), (2, "code".to_string())]);
+        assert_eq!(tracker.sync(&snapshot, 0).len(), 1);
+        assert!(tracker.sync(&snapshot, 5_000).is_empty());
+        let snapshot = HashMap::from([(3, "spotify".to_string())]);
+        let events = tracker.sync(&snapshot, 10_000);
+        let kinds: Vec<_> = events.iter().map(|e| (e.kind, e.name.as_str())).collect();
+        assert_eq!(
+            kinds,
+            [
+                (LifecycleKind::Exited, "code"),
+                (LifecycleKind::Launched, "spotify")
+            ]
+        );
+
+        // 已在运行的进程在应用第一次到前台后才开始跟踪
+        let apps = HashMap::from([(
+            4,
+            ResolvedApp {
+                exe: "krita".to_string(),
+                desktop: None,
+            },
+        )]);
+        assert!(tracked(&apps, &HashSet::new()).is_empty());
+        let focused = HashSet::from(["krita".to_string()]);
+        assert_eq!(
+            tracked(&apps, &focused),
+            HashMap::from([(4, "krita".to_string())])
+        );
+    }
+
+    #[test]
+    fn test_running_time() {
+        let mut tracker = LifecycleTracker::default();
+        tracker.started(1, "code", 0);
+        tracker.exited(1, 60_000);
+        tracker.started(2, "spotify", 30_000);
+        let sessions = vec![Session {
+            id: 1,
+            name: "code".to_string(),
+            start: 10_000,
+            end: 40_000,
+            ..Default::default()
+        }];
+        let result = running_time(&tracker, &sessions, 0, 100_000, 90_000);
+        assert_eq!(
+            result,
+            [
+                AppRunningTime {
+                    name: "code".to_string(),
+                    running_seconds: 60,
+                    focused_seconds: 30,
+                },
+                AppRunningTime {
+                    name: "spotify".to_string(),
+                    running_seconds: 60,
+                    focused_seconds: 0,
+                },
+            ]
+        );
+    }
+}
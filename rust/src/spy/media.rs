@@ -0,0 +1,298 @@
+//! 媒体播放记录
+//!
+//! 后台播放的音乐、播客不在前台窗口中，采样统计不到。Linux 上通过会话总线的 MPRIS 接口
+//! 定时读取各播放器的播放状态和曲目，把播放区间作为与前台会话并行的一条活动轨迹保存。
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+
+/// 读取播放器状态的间隔
+#[cfg_attr(not(target_os = "linux"), allow(dead_code))]
+const POLL_INTERVAL: Duration = Duration::from_secs(5);
+
+/// 内存中最多保留的播放区间数量
+const MAX_INTERVALS: usize = 4096;
+
+/// 播放状态，与 MPRIS PlaybackStatus 一致
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PlaybackStatus {
+    Playing,
+    Paused,
+    Stopped,
+}
+
+impl PlaybackStatus {
+    pub fn parse(s: &str) -> PlaybackStatus {
+        match s {
+            "Playing" => PlaybackStatus::Playing,
+            "Paused" => PlaybackStatus::Paused,
+            _ => PlaybackStatus::Stopped,
+        }
+    }
+}
+
+/// 一个播放器当前的状态
+#[derive(Debug, Clone, PartialEq)]
+pub struct MediaState {
+    pub player: String, // 播放器名称，例如 Spotify、mpv
+    pub status: PlaybackStatus,
+    pub title: Option<String>,
+    pub artist: Option<String>, // 多位艺术家以 ", " 连接
+    pub album: Option<String>,
+    pub url: Option<String>,
+}
+
+/// 一段连续播放同一曲目的区间
+#[derive(Debug, Clone, PartialEq)]
+pub struct MediaActivity {
+    pub player: String,
+    pub title: Option<String>,
+    pub artist: Option<String>,
+    pub start: i64, // unix 毫秒
+    pub end: i64,
+}
+
+/// 按播放器统计的播放时间
+#[derive(Debug, Clone)]
+pub struct MediaTime {
+    pub player: String,
+    pub seconds: f64,
+}
+
+#[derive(Default)]
+pub struct MediaStore {
+    intervals: VecDeque<MediaActivity>,   // 已结束的区间
+    open: HashMap<String, MediaActivity>, // 各播放器正在播放的区间
+    current: Vec<MediaState>,             // 最近一次读取的状态
+}
+
+impl MediaStore {
+    /// 记录一次读取到的播放器状态：同一播放器继续播放同一曲目时延长区间，
+    /// 暂停、停止、换曲或播放器退出时在本次读取时结束
+    pub fn record(&mut self, states: Vec<MediaState>, now: i64) {
+        let mut open = HashMap::new();
+        for state in states
+            .iter()
+            .filter(|s| s.status == PlaybackStatus::Playing)
+        {
+            if open.contains_key(&state.player) {
+                continue;
+            }
+            let interval = match self.open.remove(&state.player) {
+                Some(mut interval) if interval.title == state.title => {
+                    interval.end = now;
+                    interval
+                }
+                previous => {
+                    if let Some(mut previous) = previous {
+                        previous.end = now;
+                        self.intervals.push_back(previous);
+                    }
+                    MediaActivity {
+                        player: state.player.clone(),
+                        title: state.title.clone(),
+                        artist: state.artist.clone(),
+                        start: now,
+                        end: now,
+                    }
+                }
+            };
+            open.insert(state.player.clone(), interval);
+        }
+        for (_, mut interval) in std::mem::replace(&mut self.open, open) {
+            interval.end = now;
+            self.intervals.push_back(interval);
+        }
+        while self.intervals.len() > MAX_INTERVALS {
+            self.intervals.pop_front();
+        }
+        self.current = states;
+    }
+
+    /// 与 [start, end] 有交集的播放区间，按开始时间排序并截取到范围内
+    pub fn between(&self, start: i64, end: i64) -> Vec<MediaActivity> {
+        let mut result: Vec<MediaActivity> = self
+            .intervals
+            .iter()
+            .chain(self.open.values())
+            .filter(|a| a.end > start && a.start < end)
+            .map(|a| MediaActivity {
+                start: a.start.max(start),
+                end: a.end.min(end),
+                ..a.clone()
+            })
+            .collect();
+        result.sort_by_key(|a| a.start);
+        result
+    }
+}
+
+/// 按播放器合计播放时间，降序
+pub fn media_time(activities: &[MediaActivity]) -> Vec<MediaTime> {
+    let mut totals: HashMap<String, i64> = HashMap::new();
+    for a in activities {
+        *totals.entry(a.player.clone()).or_default() += a.end - a.start;
+    }
+    let mut result: Vec<MediaTime> = totals
+        .into_iter()
+        .map(|(player, ms)| MediaTime {
+            player,
+            seconds: ms as f64 / 1000.0,
+        })
+        .collect();
+    result.sort_by(|a, b| b.seconds.total_cmp(&a.seconds));
+    result
+}
+
+pub static MEDIA: Lazy<Mutex<MediaStore>> = Lazy::new(|| Mutex::new(MediaStore::default()));
+
+static WATCHER: Lazy<Mutex<Option<Arc<AtomicBool>>>> = Lazy::new(|| Mutex::new(None));
+
+/// 在 MEDIA 锁内检查停止标志后再记录，停止之后旧线程迟到的采样不会覆盖重启后的新线程
+#[cfg(target_os = "linux")]
+fn record_if_running(stop: &AtomicBool, states: Vec<MediaState>, now: i64) -> bool {
+    let mut media = MEDIA.lock().unwrap();
+    if stop.load(Ordering::SeqCst) {
+        return false;
+    }
+    media.record(states, now);
+    true
+}
+
+#[cfg(target_os = "linux")]
+fn watch(connection: zbus::blocking::Connection, stop: Arc<AtomicBool>) {
+    while !stop.load(Ordering::SeqCst) {
+        match crate::spy::platform::mpris::players(&connection) {
+            Ok(states) => {
+                let now = chrono::Local::now().timestamp_millis();
+                if !record_if_running(&stop, states, now) {
+                    break;
+                }
+            }
+            Err(e) => crate::spy::event::emit(crate::spy::event::TrackerEvent::failed(
+                crate::spy::event::TrackerEventKind::Error,
+                "media",
+                e,
+            )),
+        }
+        std::thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// 连接会话总线并开始记录播放状态
+#[cfg(target_os = "linux")]
+pub fn start_watcher() -> anyhow::Result<()> {
+    let mut watcher = WATCHER.lock().unwrap();
+    if watcher.is_some() {
+        anyhow::bail!("media watcher already started");
+    }
+    let connection = zbus::blocking::Connection::session()?;
+    let stop = Arc::new(AtomicBool::new(false));
+    *watcher = Some(stop.clone());
+    std::thread::spawn(move || watch(connection, stop));
+    Ok(())
+}
+
+/// 其他平台暂不支持读取媒体播放状态
+#[cfg(not(target_os = "linux"))]
+pub fn start_watcher() -> anyhow::Result<()> {
+    anyhow::bail!("media watcher is only supported on Linux")
+}
+
+/// 停止记录并结束所有正在播放的区间；旧线程最多还会休眠一个轮询周期，但不再写入
+pub fn stop_watcher() {
+    if let Some(stop) = WATCHER.lock().unwrap().take() {
+        let mut media = MEDIA.lock().unwrap();
+        stop.store(true, Ordering::SeqCst);
+        media.record(Vec::new(), chrono::Local::now().timestamp_millis());
+    }
+}
+
+pub fn now_playing() -> Vec<MediaState> {
+    MEDIA.lock().unwrap().current.clone()
+}
+
+pub fn get_media_activity(start: i64, end: i64) -> Vec<MediaActivity> {
+    MEDIA.lock().unwrap().between(start, end)
+}
+
+pub fn get_media_time(start: i64, end: i64) -> Vec<MediaTime> {
+    media_time(&get_media_activity(start, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn state(player: &str, status: PlaybackStatus, title: &str) -> MediaState {
+        MediaState {
+            player: player.to_string(),
+            status,
+            title: Some(title.to_string()),
+            artist: None,
+            album: None,
+            url: None,
+        }
+    }
+
+    #[test]
+    fn test_record_intervals() {
+        use PlaybackStatus::*;
+
+        let mut store = MediaStore::default();
+        store.record(vec![state("Spotify", Playing, "a")], 0);
+        store.record(vec![state("Spotify", Playing, "a")], 60_000);
+        store.record(vec![state("Spotify", Playing, "b")], 120_000); // 换曲
+        store.record(vec![state("Spotify", Paused, "b")], 180_000);
+        store.record(
+            vec![
+                state("Spotify", Playing, "b"),
+                state("mpv", Playing, "talk"),
+            ],
+            240_000,
+        );
+        store.record(vec![], 300_000); // 播放器退出
+
+        let between = store.between(0, 1_000_000);
+        let activity: Vec<(&str, &str, i64, i64)> = between
+            .iter()
+            .map(|a| {
+                (
+                    a.player.as_str(),
+                    a.title.as_deref().unwrap(),
+                    a.start,
+                    a.end,
+                )
+            })
+            .collect();
+        assert_eq!(activity.len(), 4);
+        assert_eq!(activity[0], ("Spotify", "a", 0, 120_000));
+        assert_eq!(activity[1], ("Spotify", "b", 120_000, 180_000));
+
+        let time = media_time(&between);
+        assert_eq!(time[0].player, "Spotify");
+        assert_eq!(time[0].seconds, 240.0);
+        assert_eq!(time[1].seconds, 60.0);
+    }
+
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn test_stopped_watcher_does_not_record() {
+        use PlaybackStatus::*;
+
+        // 重启后新线程的区间不会被停止的旧线程结束
+        let old = AtomicBool::new(true);
+        let new = AtomicBool::new(false);
+        assert!(record_if_running(
+            &new,
+            vec![state("mpv", Playing, "talk")],
+            0
+        ));
+        assert!(!record_if_running(&old, vec![], 5_000));
+        assert_eq!(now_playing().len(), 1);
+    }
+}
@@ -0,0 +1,340 @@
+//! 会议和通话检测
+//!
+//! 综合多个信号判断是否在开会：打开的窗口标题（Zoom、Teams、浏览器中的 Meet 等）、
+//! 麦克风和摄像头占用（Linux）以及屏幕共享提示。只有会议窗口而没有在使用麦克风、摄像头或
+//! 共享屏幕时不算开会，只占用麦克风（语音输入）也不算；不能检测麦克风和摄像头的平台上
+//! 只有会议窗口也算开会。会议开始和结束时在追踪事件流上发出事件，
+//! 并在会议边界处切分前台会话，会议内的部分标记为会议时间。
+
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+/// 检测间隔
+const POLL_SECS: u64 = 10;
+
+/// 信号消失超过这个时长才结束会议，避免静音、切换设备时反复开始结束
+const END_GRACE_MS: i64 = 30_000;
+
+/// 内存中最多保留的会议数量
+const MAX_MEETINGS: usize = 1024;
+
+/// 会议窗口标题规则：(会议应用, 标题需同时包含的片段)，都是小写
+const MEETING_TITLES: &[(&str, &[&str])] = &[
+    ("Zoom", &["zoom meeting"]),
+    ("Zoom", &["zoom webinar"]),
+    ("Teams", &["microsoft teams", "meeting"]),
+    ("Teams", &["microsoft teams", "call"]),
+    ("Teams", &["microsoft teams", "会议"]),
+    ("Google Meet", &["google meet"]),
+    ("Google Meet", &["meet.google.com"]),
+    ("Webex", &["webex", "meeting"]),
+    ("Slack", &["slack", "huddle"]),
+    ("Jitsi", &["jitsi meet"]),
+    ("腾讯会议", &["腾讯会议"]),
+    ("飞书会议", &["飞书会议"]),
+    ("钉钉会议", &["钉钉会议"]),
+];
+
+/// 屏幕共享提示窗口或标题片段，小写
+const SCREEN_SHARE_TITLES: &[&str] = &[
+    "is sharing your screen",
+    "is sharing a window",
+    "you are screen sharing",
+    "you're sharing your screen",
+    "sharing your screen",
+    "正在共享屏幕",
+    "正在共享你的屏幕",
+];
+
+/// 一次检测得到的信号
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct MeetingSignals {
+    pub app: Option<String>,     // 标题匹配到的会议应用
+    pub microphone: Vec<String>, // 正在录音的应用
+    pub camera: Vec<String>,     // 打开摄像头的进程
+    pub screen_share: bool,
+    pub devices: bool, // 当前平台能否检测麦克风和摄像头
+}
+
+impl MeetingSignals {
+    pub fn is_meeting(&self) -> bool {
+        let microphone = !self.microphone.is_empty();
+        let camera = !self.camera.is_empty();
+        match &self.app {
+            // 没有设备信号时只能相信窗口标题
+            Some(_) => !self.devices || microphone || camera || self.screen_share,
+            None => microphone && camera,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MeetingEventKind {
+    Started,
+    Ended,
+}
+
+/// 会议开始或结束事件
+#[derive(Debug, Clone, PartialEq)]
+pub struct MeetingEvent {
+    pub kind: MeetingEventKind,
+    pub meeting_id: u64,
+    pub app: Option<String>,
+    pub timestamp: i64, // unix 毫秒，结束事件为最后一次检测到会议的时间
+}
+
+/// 一次会议
+#[derive(Debug, Clone, PartialEq)]
+pub struct Meeting {
+    pub id: u64,
+    pub app: Option<String>,
+    pub start: i64,
+    pub end: i64,
+    pub camera: bool,       // 期间是否开过摄像头
+    pub screen_share: bool, // 期间是否共享过屏幕
+    pub ongoing: bool,
+}
+
+#[derive(Default)]
+pub struct MeetingDetector {
+    next_id: u64,
+    meetings: VecDeque<Meeting>,
+}
+
+impl MeetingDetector {
+    fn current(&mut self) -> Option<&mut Meeting> {
+        self.meetings.back_mut().filter(|m| m.ongoing)
+    }
+
+    /// 根据一次检测的信号更新会议状态，返回开始或结束事件
+    pub fn update(&mut self, signals: &MeetingSignals, now: i64) -> Option<MeetingEvent> {
+        if signals.is_meeting() {
+            if let Some(meeting) = self.current() {
+                meeting.end = now;
+                meeting.camera |= !signals.camera.is_empty();
+                meeting.screen_share |= signals.screen_share;
+                if meeting.app.is_none() {
+                    meeting.app = signals.app.clone();
+                }
+                return None;
+            }
+            self.next_id += 1;
+            self.meetings.push_back(Meeting {
+                id: self.next_id,
+                app: signals.app.clone(),
+                start: now,
+                end: now,
+                camera: !signals.camera.is_empty(),
+                screen_share: signals.screen_share,
+                ongoing: true,
+            });
+            if self.meetings.len() > MAX_MEETINGS {
+                self.meetings.pop_front();
+            }
+            return Some(MeetingEvent {
+                kind: MeetingEventKind::Started,
+                meeting_id: self.next_id,
+                app: signals.app.clone(),
+                timestamp: now,
+            });
+        }
+
+        let meeting = self.current()?;
+        if now - meeting.end < END_GRACE_MS {
+            return None;
+        }
+        meeting.ongoing = false;
+        Some(MeetingEvent {
+            kind: MeetingEventKind::Ended,
+            meeting_id: meeting.id,
+            app: meeting.app.clone(),
+            timestamp: meeting.end,
+        })
+    }
+
+    pub fn between(&self, start: i64, end: i64) -> Vec<Meeting> {
+        self.meetings
+            .iter()
+            .filter(|m| m.end >= start && m.start <= end)
+            .cloned()
+            .collect()
+    }
+}
+
+/// 浏览器中 Google Meet 的标题：`Meet - abc-defg-hij - Google Chrome`，以会议代码区分
+/// 普通标题中的 "meet - "（例如 `Team meet - notes.md`）
+static MEET_TITLE: Lazy<Regex> =
+    Lazy::new(|| Regex::new(r"^meet [-–] [a-z]{3}-[a-z]{4}-[a-z]{3}\b").unwrap());
+
+/// 按窗口标题判断会议应用
+pub fn meeting_app(title: &str) -> Option<&'static str> {
+    let title = title.to_lowercase();
+    if MEET_TITLE.is_match(&title) {
+        return Some("Google Meet");
+    }
+    MEETING_TITLES
+        .iter()
+        .find(|(_, parts)| parts.iter().all(|p| title.contains(p)))
+        .map(|(app, _)| *app)
+}
+
+pub fn is_screen_share_title(title: &str) -> bool {
+    let title = title.to_lowercase();
+    SCREEN_SHARE_TITLES.iter().any(|p| title.contains(p))
+}
+
+/// 采集当前的会议信号：标题来自所有打开的窗口，因为开会时常切到其他窗口
+pub fn signals() -> MeetingSignals {
+    let titles = crate::spy::platform::window_titles();
+    let (microphone, camera) = devices();
+    MeetingSignals {
+        devices: cfg!(target_os = "linux"),
+        app: titles
+            .iter()
+            .find_map(|t| meeting_app(t))
+            .map(|a| a.to_string()),
+        microphone,
+        camera,
+        screen_share: titles.iter().any(|t| is_screen_share_title(t)),
+    }
+}
+
+/// 正在使用 (麦克风, 摄像头) 的应用
+#[cfg(target_os = "linux")]
+fn devices() -> (Vec<String>, Vec<String>) {
+    use crate::spy::platform::av_devices;
+    let camera = if av_devices::has_camera() {
+        av_devices::camera_users()
+    } else {
+        Vec::new()
+    };
+    (av_devices::microphone_users(), camera)
+}
+
+/// 其他平台暂不检测麦克风和摄像头，只依靠窗口标题，打开会议窗口就算开会
+#[cfg(not(target_os = "linux"))]
+fn devices() -> (Vec<String>, Vec<String>) {
+    (Vec::new(), Vec::new())
+}
+
+pub static DETECTOR: Lazy<Mutex<MeetingDetector>> =
+    Lazy::new(|| Mutex::new(MeetingDetector::default()));
+
+static WATCHER: Lazy<Mutex<Option<Arc<AtomicBool>>>> = Lazy::new(|| Mutex::new(None));
+
+fn detect(stop: Arc<AtomicBool>) {
+    while !stop.load(Ordering::SeqCst) {
+        let signals = signals();
+        let now = chrono::Local::now().timestamp_millis();
+        let mut detector = DETECTOR.lock().unwrap();
+        let event = detector.update(&signals, now);
+        // 会议进行中持续把会议开始之后的会话标记为会议时间，结束时在结束处切分
+        if let Some(meeting) = detector.meetings.back() {
+            if meeting.ongoing || event.is_some() {
+                crate::spy::session::SESSIONS.lock().unwrap().tag_meeting(
+                    meeting.id,
+                    meeting.start,
+                    meeting.end,
+                    meeting.ongoing,
+                );
+            }
+        }
+        drop(detector);
+        if let Some(event) = event {
+            crate::spy::event::emit(crate::spy::event::TrackerEvent::meeting(&event));
+        }
+        std::thread::sleep(Duration::from_secs(POLL_SECS));
+    }
+}
+
+pub fn start_detector() -> anyhow::Result<()> {
+    let mut watcher = WATCHER.lock().unwrap();
+    if watcher.is_some() {
+        anyhow::bail!("meeting detector already started");
+    }
+    let stop = Arc::new(AtomicBool::new(false));
+    *watcher = Some(stop.clone());
+    std::thread::spawn(move || detect(stop));
+    Ok(())
+}
+
+pub fn stop_detector() {
+    if let Some(stop) = WATCHER.lock().unwrap().take() {
+        stop.store(true, Ordering::SeqCst);
+    }
+}
+
+pub fn get_meetings(start: i64, end: i64) -> Vec<Meeting> {
+    DETECTOR.lock().unwrap().between(start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_title_signals() {
+        assert_eq!(meeting_app("Zoom Meeting"), Some("Zoom"));
+        assert_eq!(
+            meeting_app("Meeting with Design | Microsoft Teams"),
+            Some("Teams")
+        );
+        assert_eq!(
+            meeting_app("Meet - abc-defg-hij - Google Chrome"),
+            Some("Google Meet")
+        );
+        assert_eq!(
+            meeting_app("Team meet - notes.md - Visual Studio Code"),
+            None
+        );
+        assert_eq!(meeting_app("Meet - notes.md"), None);
+        assert_eq!(meeting_app("Chat | Microsoft Teams"), None);
+        assert_eq!(meeting_app("main.rs - spy - Visual Studio Code"), None);
+        assert!(is_screen_share_title(
+            "meet.google.com is sharing your screen."
+        ));
+    }
+
+    #[test]
+    fn test_meeting_start_and_end() {
+        let mut detector = MeetingDetector::default();
+        let idle = MeetingSignals {
+            app: Some("Zoom".to_string()),
+            devices: true,
+            ..Default::default()
+        };
+        let call = MeetingSignals {
+            microphone: vec!["zoom".to_string()],
+            ..idle.clone()
+        };
+        // 只有会议窗口不算开会，不能检测设备时才算
+        assert_eq!(detector.update(&idle, 0), None);
+        assert!(MeetingSignals {
+            devices: false,
+            ..idle.clone()
+        }
+        .is_meeting());
+
+        let started = detector.update(&call, 10_000).unwrap();
+        assert_eq!(started.kind, MeetingEventKind::Started);
+        assert_eq!(detector.update(&call, 20_000), None);
+        // 短暂静音不结束会议
+        assert_eq!(detector.update(&idle, 30_000), None);
+        assert_eq!(detector.update(&call, 40_000), None);
+
+        let ended = detector.update(&idle, 80_000).unwrap();
+        assert_eq!(ended.kind, MeetingEventKind::Ended);
+        assert_eq!(ended.timestamp, 40_000);
+        assert_eq!(detector.update(&idle, 90_000), None);
+
+        let meetings = detector.between(0, 100_000);
+        assert_eq!(meetings.len(), 1);
+        assert_eq!((meetings[0].start, meetings[0].end), (10_000, 40_000));
+        assert!(!meetings[0].ongoing);
+    }
+}
@@ -1,4 +1,26 @@
 pub mod api;
+pub mod bus;
+pub mod catalog;
+pub mod event;
+pub mod git;
+pub mod heartbeat;
+pub mod icon;
+pub mod input;
+pub mod issue;
+pub mod journal;
+pub mod lifecycle;
+pub mod media;
+pub mod meeting;
 pub mod model;
+pub mod monitor;
 pub mod platform;
+pub mod process;
+pub mod resource;
+pub mod runtime;
+pub mod session;
+pub mod shell;
+pub mod shell_protocol;
 mod tests;
+pub mod visibility;
+pub mod window_state;
+pub mod workspace;
@@ -1,10 +1,37 @@
-#[derive(Debug, Clone)]
+use serde::{Deserialize, Serialize};
+
+use crate::spy::process::ProcessInfo;
+use crate::spy::visibility::Rect;
+use crate::spy::window_state::WindowState;
+use crate::spy::workspace::Workspace;
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct Application {
-    pub icon: Option<String>,
-    pub name: String,  // 应用程序名称（从可执行文件路径提取，稳定不变）
-    pub title: String, // 窗口标题（动态变化）
-    pub path: String,  // 可执行文件完整路径
+    pub icon: Option<String>,             // 图标 id，通过 get_icon 获取图片
+    pub name: String,                     // 应用程序名称（从可执行文件路径提取，稳定不变）
+    pub title: String,                    // 窗口标题（动态变化）
+    pub path: String, // 应用路径：可执行文件完整路径，运行时程序为被运行的 jar、脚本等
     pub screen_shot_path: Option<String>, // 截图保存路径,默认为空
+    pub repo: Option<String>, // 当前工作所在 git 仓库根目录
+    pub branch: Option<String>, // 当前分支
+    pub app_id: Option<String>, // 平台应用标识：Linux 为 desktop-file id，macOS 为 Bundle ID
+    pub exe_path: String, // 进程可执行文件的原始路径
+    pub runtime: Option<String>, // 运行时名称（java、python、node、electron、dotnet）
+    pub package: Option<String>, // 打包格式（flatpak、snap、appimage）
+    pub version: Option<String>, // 打包应用的版本
+    pub process: Option<ProcessInfo>, // 进程信息：pid、父进程链、命令行、启动时间、用户
+    pub monitor: Option<String>, // 窗口所在显示器名称
+    pub workspace: Option<Workspace>, // 窗口所在虚拟桌面
+    pub state: Option<WindowState>, // 窗口状态：全屏、最大化、最小化、置顶
+    pub geometry: Option<Rect>, // 窗口位置和大小（虚拟屏幕坐标）
+}
+
+/// 打开的窗口及其所属应用
+#[derive(Debug, Clone)]
+pub struct WindowInfo {
+    pub window_id: u64, // 平台窗口标识：X11 窗口 id、Windows HWND；macOS 无法获取时为 0
+    pub focused: bool,  // 是否为当前前台窗口
+    pub app: Application,
 }
 
 #[cfg(target_os = "windows")]
@@ -0,0 +1,209 @@
+//! 显示器布局与截图范围
+//!
+//! 记录前台窗口所在的显示器，并按截图模式确定要截取的区域：窗口（跨显示器时分别截取各部分）、
+//! 窗口所在显示器，或把所有显示器拼成一张图。
+
+use std::sync::Mutex;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use image::RgbaImage;
+use once_cell::sync::Lazy;
+
+use crate::spy::visibility::Rect;
+
+/// 一个显示器在虚拟屏幕坐标系中的位置
+#[derive(Debug, Clone, PartialEq)]
+pub struct Monitor {
+    pub name: String, // 输出名称，例如 HDMI-1、\\.\DISPLAY1
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+    pub primary: bool,
+}
+
+impl Monitor {
+    pub fn rect(&self) -> Rect {
+        Rect {
+            x: self.x,
+            y: self.y,
+            width: self.width,
+            height: self.height,
+        }
+    }
+}
+
+/// 截图模式
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CaptureMode {
+    Window,         // 只截取前台窗口
+    FocusedMonitor, // 截取前台窗口所在的显示器
+    AllMonitors,    // 所有显示器拼成一张图
+}
+
+pub static CAPTURE_MODE: Lazy<Mutex<CaptureMode>> = Lazy::new(|| Mutex::new(CaptureMode::Window));
+
+pub fn set_capture_mode(mode: CaptureMode) {
+    *CAPTURE_MODE.lock().unwrap() = mode;
+}
+
+pub fn capture_mode() -> CaptureMode {
+    *CAPTURE_MODE.lock().unwrap()
+}
+
+/// 与窗口重叠面积最大的显示器
+pub fn monitor_for<'a>(window: &Rect, monitors: &'a [Monitor]) -> Option<&'a Monitor> {
+    monitors
+        .iter()
+        .filter_map(|m| Some((m, window.intersect(&m.rect())?.area())))
+        .max_by_key(|(_, area)| *area)
+        .map(|(m, _)| m)
+}
+
+/// 按截图模式返回 (画布范围, 需要从屏幕复制的区域)，区域都在画布内
+pub fn capture_regions(
+    mode: CaptureMode,
+    window: &Rect,
+    monitors: &[Monitor],
+) -> Option<(Rect, Vec<Rect>)> {
+    match mode {
+        CaptureMode::Window => {
+            // 只复制窗口落在显示器上的部分，显示器之间的空隙留空
+            let regions: Vec<Rect> = monitors
+                .iter()
+                .filter_map(|m| window.intersect(&m.rect()))
+                .collect();
+            (!regions.is_empty()).then_some((*window, regions))
+        }
+        CaptureMode::FocusedMonitor => {
+            let rect = monitor_for(window, monitors)?.rect();
+            Some((rect, vec![rect]))
+        }
+        CaptureMode::AllMonitors => {
+            let rects: Vec<Rect> = monitors.iter().map(|m| m.rect()).collect();
+            let x = rects.iter().map(|r| r.x).min()?;
+            let y = rects.iter().map(|r| r.y).min()?;
+            let right = rects.iter().map(|r| r.x + r.width).max()?;
+            let bottom = rects.iter().map(|r| r.y + r.height).max()?;
+            let canvas = Rect {
+                x,
+                y,
+                width: right - x,
+                height: bottom - y,
+            };
+            Some((canvas, rects))
+        }
+    }
+}
+
+/// 把各区域的截图复制到画布上，grab 截取屏幕上的一块区域
+pub fn composite(
+    canvas: &Rect,
+    regions: &[Rect],
+    mut grab: impl FnMut(&Rect) -> Option<RgbaImage>,
+) -> Option<RgbaImage> {
+    if canvas.width <= 0 || canvas.height <= 0 {
+        return None;
+    }
+    let mut image = RgbaImage::new(canvas.width as u32, canvas.height as u32);
+    let mut grabbed = false;
+    for region in regions {
+        let Some(part) = grab(region) else {
+            continue;
+        };
+        image::imageops::replace(
+            &mut image,
+            &part,
+            (region.x - canvas.x) as i64,
+            (region.y - canvas.y) as i64,
+        );
+        grabbed = true;
+    }
+    grabbed.then_some(image)
+}
+
+/// 保存截图到文件夹，文件名为标题加时间戳，返回完整路径
+pub fn save_capture(image: &RgbaImage, folder: &str, title: &str) -> anyhow::Result<String> {
+    std::fs::create_dir_all(folder)?;
+    let safe_title = title
+        .replace(['<', '>', ':', '"', '/', '\\', '|', '?', '*'], "_")
+        .trim()
+        .to_string();
+    let timestamp = SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .unwrap_or_default()
+        .as_secs();
+    let path = std::path::Path::new(folder).join(format!("{}_{}.png", safe_title, timestamp));
+    image.save(&path)?;
+    Ok(path.to_string_lossy().into_owned())
+}
+
+pub fn list_monitors() -> Vec<Monitor> {
+    crate::spy::platform::monitors()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn monitor(name: &str, x: i32, width: i32) -> Monitor {
+        Monitor {
+            name: name.to_string(),
+            x,
+            y: 0,
+            width,
+            height: 100,
+            primary: x == 0,
+        }
+    }
+
+    fn rect(x: i32, y: i32, width: i32, height: i32) -> Rect {
+        Rect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn test_regions_across_monitors() {
+        let monitors = [monitor("DP-1", 0, 200), monitor("HDMI-1", 200, 100)];
+        let window = rect(140, 10, 100, 50);
+
+        assert_eq!(monitor_for(&window, &monitors).unwrap().name, "DP-1");
+
+        let (canvas, regions) = capture_regions(CaptureMode::Window, &window, &monitors).unwrap();
+        assert_eq!(canvas, window);
+        assert_eq!(regions, [rect(140, 10, 60, 50), rect(200, 10, 40, 50)]);
+
+        let (canvas, _) = capture_regions(CaptureMode::FocusedMonitor, &window, &monitors).unwrap();
+        assert_eq!(canvas, rect(0, 0, 200, 100));
+
+        let (canvas, regions) =
+            capture_regions(CaptureMode::AllMonitors, &window, &monitors).unwrap();
+        assert_eq!(canvas, rect(0, 0, 300, 100));
+        assert_eq!(regions.len(), 2);
+
+        // 窗口完全在屏幕外
+        assert!(capture_regions(CaptureMode::Window, &rect(500, 0, 10, 10), &monitors).is_none());
+    }
+
+    #[test]
+    fn test_composite() {
+        let canvas = rect(0, 0, 4, 2);
+        let regions = [rect(0, 0, 2, 2), rect(2, 0, 2, 2)];
+        let image = composite(&canvas, &regions, |r| {
+            let shade = if r.x == 0 { 10 } else { 200 };
+            Some(RgbaImage::from_pixel(
+                r.width as u32,
+                r.height as u32,
+                image::Rgba([shade, shade, shade, 255]),
+            ))
+        })
+        .unwrap();
+        assert_eq!(image.get_pixel(1, 1)[0], 10);
+        assert_eq!(image.get_pixel(3, 0)[0], 200);
+        assert!(composite(&canvas, &regions, |_| None).is_none());
+    }
+}
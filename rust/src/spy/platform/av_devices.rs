@@ -0,0 +1,148 @@
+//! 摄像头和麦克风占用检测
+//!
+//! 摄像头：扫描 `/proc/*/fd`，找出打开了 `/dev/video*` 的进程。
+//! 麦克风：ALSA 录音 PCM 处于 RUNNING 状态（PipeWire / PulseAudio 录音时也会打开设备），
+//! 以及 `pactl list source-outputs` 中的录音流，后者可以得到录音的应用名称。
+
+use std::collections::BTreeSet;
+use std::path::Path;
+use std::process::Command;
+
+/// 不算作通话的录音流：音量控制的电平表等
+const IGNORED_CAPTURE_APPS: &[&str] = &[
+    "pavucontrol",
+    "PulseAudio Volume Control",
+    "gnome-control-center",
+];
+
+/// 打开了摄像头的进程名称
+pub fn camera_users() -> Vec<String> {
+    let mut names = BTreeSet::new();
+    let Ok(procs) = std::fs::read_dir("/proc") else {
+        return Vec::new();
+    };
+    for entry in procs.flatten() {
+        let file_name = entry.file_name();
+        let Some(pid) = file_name
+            .to_str()
+            .filter(|s| s.bytes().all(|b| b.is_ascii_digit()))
+        else {
+            continue;
+        };
+        // 其他用户的进程没有权限读取，直接跳过
+        let Ok(fds) = std::fs::read_dir(entry.path().join("fd")) else {
+            continue;
+        };
+        let uses_camera = fds.flatten().any(|fd| {
+            std::fs::read_link(fd.path())
+                .is_ok_and(|target| target.to_string_lossy().starts_with("/dev/video"))
+        });
+        if uses_camera {
+            if let Ok(comm) = std::fs::read_to_string(format!("/proc/{}/comm", pid)) {
+                names.insert(comm.trim().to_string());
+            }
+        }
+    }
+    names.into_iter().collect()
+}
+
+/// 是否有 ALSA 录音设备正在工作
+fn alsa_capture_running() -> bool {
+    let Ok(cards) = std::fs::read_dir("/proc/asound") else {
+        return false;
+    };
+    cards
+        .flatten()
+        .filter(|c| c.file_name().to_string_lossy().starts_with("card"))
+        .filter_map(|c| std::fs::read_dir(c.path()).ok())
+        .flatten()
+        .flatten()
+        // 录音 PCM 目录以 c 结尾，例如 pcm0c
+        .filter(|p| {
+            let name = p.file_name().to_string_lossy().into_owned();
+            name.starts_with("pcm") && name.ends_with('c')
+        })
+        .filter_map(|p| std::fs::read_dir(p.path()).ok())
+        .flatten()
+        .flatten()
+        .any(|sub| {
+            std::fs::read_to_string(sub.path().join("status"))
+                .is_ok_and(|s| s.contains("state: RUNNING"))
+        })
+}
+
+/// 从 `pactl list source-outputs` 的输出中取出录音应用名称
+pub fn parse_source_outputs(output: &str) -> Vec<String> {
+    let mut names = BTreeSet::new();
+    for block in output.split("Source Output #").skip(1) {
+        let property = |key: &str| {
+            block.lines().find_map(|line| {
+                let (k, v) = line.trim().split_once(" = ")?;
+                (k == key).then(|| v.trim_matches('"').to_string())
+            })
+        };
+        let Some(name) =
+            property("application.name").or_else(|| property("application.process.binary"))
+        else {
+            continue;
+        };
+        // 音量控制的电平表也会创建录音流
+        if property("media.name").is_some_and(|m| m.contains("Peak detect"))
+            || IGNORED_CAPTURE_APPS.contains(&name.as_str())
+        {
+            continue;
+        }
+        names.insert(name);
+    }
+    names.into_iter().collect()
+}
+
+/// 正在录音的应用名称；没有 pactl 但 ALSA 设备在录音时返回 "unknown"
+pub fn microphone_users() -> Vec<String> {
+    let apps = Command::new("pactl")
+        .args(["list", "source-outputs"])
+        .env("LC_ALL", "C")
+        .output()
+        .ok()
+        .filter(|o| o.status.success())
+        .map(|o| parse_source_outputs(&String::from_utf8_lossy(&o.stdout)));
+    match apps {
+        Some(apps) => apps,
+        None if alsa_capture_running() => vec!["unknown".to_string()],
+        None => Vec::new(),
+    }
+}
+
+/// 是否存在摄像头设备，没有摄像头时不必扫描进程
+pub fn has_camera() -> bool {
+    (0..8).any(|i| Path::new(&format!("/dev/video{}", i)).exists())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_source_outputs() {
+        let output = r#"Source Output #41
+	Driver: PipeWire
+	Source: 52
+	Properties:
+		media.name = "WebRTC VoiceEngine"
+		application.name = "Chromium"
+		application.process.binary = "chromium"
+
+Source Output #57
+	Driver: PipeWire
+	Properties:
+		media.name = "Peak detect"
+		application.name = "PulseAudio Volume Control"
+
+Source Output #60
+	Properties:
+		application.process.binary = "zoom"
+"#;
+        assert_eq!(parse_source_outputs(output), ["Chromium", "zoom"]);
+        assert!(parse_source_outputs("").is_empty());
+    }
+}
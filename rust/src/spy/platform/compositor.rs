@@ -0,0 +1,279 @@
+//! Wayland 合成器 IPC，获取当前工作区和前台窗口
+//!
+//! Wayland 没有 `_NET_CURRENT_DESKTOP` 这样的通用协议，支持 sway（i3 IPC，`SWAYSOCK`）
+//! 和 Hyprland（`HYPRLAND_INSTANCE_SIGNATURE`）。原生 Wayland 窗口在前台时 X11 读不到
+//! 激活窗口，由合成器给出前台窗口。
+
+use std::io::{Read, Write};
+use std::os::unix::net::UnixStream;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use serde_json::Value;
+
+use crate::spy::event::{self, TrackerEvent, TrackerEventKind};
+use crate::spy::visibility::Rect;
+
+const I3_MAGIC: &[u8] = b"i3-ipc";
+const I3_GET_WORKSPACES: u32 = 1;
+const I3_GET_TREE: u32 = 4;
+const IPC_TIMEOUT: Duration = Duration::from_millis(500);
+
+/// 合成器报告的前台窗口
+#[derive(Debug, Clone, PartialEq)]
+pub struct FocusedWindow {
+    pub title: String,
+    pub app_id: String, // Wayland app_id，XWayland 窗口为 WM_CLASS
+    pub pid: Option<u32>,
+    pub rect: Option<Rect>,
+    pub fullscreen: bool,
+}
+
+/// 通过合成器 IPC 获取当前工作区 (编号, 名称)，不在支持的合成器下时返回 None
+pub fn current_workspace() -> Option<(u32, String)> {
+    query(sway_workspace, |socket| {
+        hyprland_request(socket, "j/activeworkspace").map(|r| parse_hyprland_workspace(&r))
+    })
+}
+
+/// 通过合成器 IPC 获取前台窗口，不在支持的合成器下或没有前台窗口时返回 None
+pub fn focused_window() -> Option<FocusedWindow> {
+    query(
+        |socket| Ok(parse_sway_tree(&sway_request(socket, I3_GET_TREE)?)),
+        |socket| hyprland_request(socket, "j/activewindow").map(|r| parse_hyprland_window(&r)),
+    )
+}
+
+/// 按当前合成器选择 sway 或 Hyprland 的查询
+fn query<T>(
+    sway: impl FnOnce(&Path) -> anyhow::Result<Option<T>>,
+    hyprland: impl FnOnce(&Path) -> anyhow::Result<Option<T>>,
+) -> Option<T> {
+    if let Some(socket) = std::env::var_os("SWAYSOCK").or_else(|| std::env::var_os("I3SOCK")) {
+        return match sway(Path::new(&socket)) {
+            Ok(result) => result,
+            Err(e) => {
+                event::emit(TrackerEvent::failed(
+                    TrackerEventKind::Error,
+                    "compositor",
+                    format!("sway ipc error: {}", e),
+                ));
+                None
+            }
+        };
+    }
+    if let Some(socket) = hyprland_socket() {
+        return match hyprland(&socket) {
+            Ok(result) => result,
+            Err(e) => {
+                event::emit(TrackerEvent::failed(
+                    TrackerEventKind::Error,
+                    "compositor",
+                    format!("hyprland ipc error: {}", e),
+                ));
+                None
+            }
+        };
+    }
+    None
+}
+
+fn connect(socket: &Path) -> anyhow::Result<UnixStream> {
+    let stream = UnixStream::connect(socket)?;
+    stream.set_read_timeout(Some(IPC_TIMEOUT))?;
+    stream.set_write_timeout(Some(IPC_TIMEOUT))?;
+    Ok(stream)
+}
+
+/// i3 IPC：`i3-ipc` + 长度 + 类型（本机字节序）+ 负载
+fn sway_request(socket: &Path, message_type: u32) -> anyhow::Result<Value> {
+    let mut stream = connect(socket)?;
+    let mut request = I3_MAGIC.to_vec();
+    request.extend_from_slice(&0u32.to_ne_bytes());
+    request.extend_from_slice(&message_type.to_ne_bytes());
+    stream.write_all(&request)?;
+
+    let mut header = [0u8; 14];
+    stream.read_exact(&mut header)?;
+    if &header[..6] != I3_MAGIC {
+        anyhow::bail!("invalid i3 ipc reply");
+    }
+    let len = u32::from_ne_bytes(header[6..10].try_into()?) as usize;
+    let mut payload = vec![0u8; len];
+    stream.read_exact(&mut payload)?;
+    Ok(serde_json::from_slice(&payload)?)
+}
+
+pub fn sway_workspace(socket: &Path) -> anyhow::Result<Option<(u32, String)>> {
+    Ok(parse_sway_workspaces(&sway_request(
+        socket,
+        I3_GET_WORKSPACES,
+    )?))
+}
+
+/// 从 GET_WORKSPACES 的结果中找出 focused 的工作区；命名工作区的 num 为 -1
+pub fn parse_sway_workspaces(reply: &Value) -> Option<(u32, String)> {
+    let focused = reply
+        .as_array()?
+        .iter()
+        .find(|w| w["focused"].as_bool() == Some(true))?;
+    let name = focused["name"].as_str().unwrap_or_default().to_string();
+    let num = focused["num"].as_i64().filter(|n| *n >= 0).unwrap_or(0);
+    Some((num as u32, name))
+}
+
+/// Hyprland 的请求 socket：$XDG_RUNTIME_DIR/hypr/<签名>/.socket.sock，旧版本在 /tmp/hypr
+fn hyprland_socket() -> Option<PathBuf> {
+    let signature = std::env::var_os("HYPRLAND_INSTANCE_SIGNATURE")?;
+    let runtime = std::env::var_os("XDG_RUNTIME_DIR").map(|d| PathBuf::from(d).join("hypr"));
+    runtime
+        .into_iter()
+        .chain([PathBuf::from("/tmp/hypr")])
+        .map(|dir| dir.join(&signature).join(".socket.sock"))
+        .find(|p| p.exists())
+}
+
+/// 发送 `j/<命令>`，返回 JSON 格式的结果
+fn hyprland_request(socket: &Path, command: &str) -> anyhow::Result<Value> {
+    let mut stream = connect(socket)?;
+    stream.write_all(command.as_bytes())?;
+    let mut reply = Vec::new();
+    stream.read_to_end(&mut reply)?;
+    Ok(serde_json::from_slice(&reply)?)
+}
+
+/// 特殊工作区（scratchpad）的 id 为负数
+pub fn parse_hyprland_workspace(reply: &Value) -> Option<(u32, String)> {
+    let id = reply["id"].as_i64()?;
+    let name = reply["name"].as_str().unwrap_or_default().to_string();
+    Some((id.max(0) as u32, name))
+}
+
+/// 在 GET_TREE 的结果中递归查找 focused 的窗口（平铺和浮动窗口）
+pub fn parse_sway_tree(node: &Value) -> Option<FocusedWindow> {
+    let is_window = matches!(node["type"].as_str(), Some("con" | "floating_con"))
+        && (node["pid"].is_u64() || node["app_id"].is_string());
+    if is_window && node["focused"].as_bool() == Some(true) {
+        let app_id = node["app_id"]
+            .as_str()
+            .or(node["window_properties"]["class"].as_str())
+            .unwrap_or_default()
+            .to_string();
+        let rect = &node["rect"];
+        return Some(FocusedWindow {
+            title: node["name"].as_str().unwrap_or_default().to_string(),
+            app_id,
+            pid: node["pid"].as_u64().map(|p| p as u32),
+            rect: Some(Rect {
+                x: rect["x"].as_i64()? as i32,
+                y: rect["y"].as_i64()? as i32,
+                width: rect["width"].as_i64()? as i32,
+                height: rect["height"].as_i64()? as i32,
+            }),
+            fullscreen: node["fullscreen_mode"].as_u64().unwrap_or(0) != 0,
+        });
+    }
+    ["nodes", "floating_nodes"]
+        .iter()
+        .filter_map(|key| node[*key].as_array())
+        .flatten()
+        .find_map(parse_sway_tree)
+}
+
+/// activewindow 没有前台窗口时返回空对象；fullscreen 新版本为模式编号，旧版本为布尔值
+pub fn parse_hyprland_window(reply: &Value) -> Option<FocusedWindow> {
+    let app_id = reply["class"].as_str()?.to_string();
+    let point = |key: &str, i: usize| reply[key][i].as_i64().map(|v| v as i32);
+    let rect = (|| {
+        Some(Rect {
+            x: point("at", 0)?,
+            y: point("at", 1)?,
+            width: point("size", 0)?,
+            height: point("size", 1)?,
+        })
+    })();
+    let fullscreen = &reply["fullscreen"];
+    Some(FocusedWindow {
+        title: reply["title"].as_str().unwrap_or_default().to_string(),
+        app_id,
+        pid: reply["pid"].as_u64().map(|p| p as u32),
+        rect,
+        fullscreen: fullscreen.as_bool().unwrap_or(false) || fullscreen.as_u64().unwrap_or(0) != 0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::os::unix::net::UnixListener;
+
+    #[test]
+    fn test_sway_workspace_over_ipc() {
+        let socket = std::env::temp_dir().join(format!("spy-sway-{}.sock", std::process::id()));
+        let _ = std::fs::remove_file(&socket);
+        let listener = UnixListener::bind(&socket).unwrap();
+        let server = std::thread::spawn(move || {
+            let (mut stream, _) = listener.accept().unwrap();
+            let mut header = [0u8; 14];
+            stream.read_exact(&mut header).unwrap();
+            assert_eq!(&header[..6], I3_MAGIC);
+            assert_eq!(u32::from_ne_bytes(header[10..14].try_into().unwrap()), 1);
+
+            let payload = br#"[{"num":1,"name":"1: mail","focused":false},{"num":-1,"name":"proj-spy","focused":true}]"#;
+            let mut reply = I3_MAGIC.to_vec();
+            reply.extend_from_slice(&(payload.len() as u32).to_ne_bytes());
+            reply.extend_from_slice(&I3_GET_WORKSPACES.to_ne_bytes());
+            reply.extend_from_slice(payload);
+            stream.write_all(&reply).unwrap();
+        });
+
+        let workspace = sway_workspace(&socket).unwrap();
+        server.join().unwrap();
+        let _ = std::fs::remove_file(&socket);
+        assert_eq!(workspace, Some((0, "proj-spy".to_string())));
+    }
+
+    #[test]
+    fn test_parse_hyprland_workspace() {
+        let reply = serde_json::json!({"id": 3, "name": "3", "monitor": "DP-1", "windows": 2});
+        assert_eq!(parse_hyprland_workspace(&reply), Some((3, "3".to_string())));
+        let special = serde_json::json!({"id": -98, "name": "special:scratch"});
+        assert_eq!(
+            parse_hyprland_workspace(&special),
+            Some((0, "special:scratch".to_string()))
+        );
+        assert_eq!(parse_hyprland_workspace(&serde_json::json!({})), None);
+    }
+
+    #[test]
+    fn test_parse_focused_window() {
+        let tree = serde_json::json!({
+            "type": "root", "focused": false, "nodes": [{
+                "type": "output", "name": "DP-1", "nodes": [{
+                    "type": "workspace", "name": "proj-spy", "nodes": [
+                        {"type": "con", "focused": false, "pid": 10, "app_id": "foot", "name": "shell",
+                         "rect": {"x": 0, "y": 0, "width": 960, "height": 1080}},
+                    ],
+                    "floating_nodes": [
+                        {"type": "floating_con", "focused": true, "pid": 11, "app_id": null,
+                         "window_properties": {"class": "Steam"}, "name": "Steam",
+                         "fullscreen_mode": 1,
+                         "rect": {"x": 100, "y": 50, "width": 800, "height": 600}},
+                    ],
+                }],
+            }],
+        });
+        let window = parse_sway_tree(&tree).unwrap();
+        assert_eq!(window.app_id, "Steam");
+        assert_eq!(window.pid, Some(11));
+        assert!(window.fullscreen);
+        assert_eq!(window.rect.unwrap().width, 800);
+
+        let reply = serde_json::json!({"class": "firefox", "title": "Inbox", "pid": 42,
+            "at": [0, 0], "size": [1920, 1080], "fullscreen": 0});
+        let window = parse_hyprland_window(&reply).unwrap();
+        assert_eq!((window.app_id.as_str(), window.pid), ("firefox", Some(42)));
+        assert!(!window.fullscreen);
+        assert_eq!(parse_hyprland_window(&serde_json::json!({})), None);
+    }
+}
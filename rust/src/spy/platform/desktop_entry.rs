@@ -0,0 +1,363 @@
+//! Freedesktop `.desktop` 文件解析，用于在 Linux 上确定应用的显示名称、
+//! desktop-file id 和图标名称。
+
+use std::collections::{HashMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+
+/// 一个 `.desktop` 文件中与应用识别相关的字段
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct DesktopEntry {
+    pub id: String,                       // desktop-file id（不含 .desktop 后缀）
+    pub name: String,                     // Name=
+    pub icon: Option<String>,             // Icon=，图标名称或绝对路径
+    pub exec: Option<String>,             // Exec= 中的程序名（不含路径和参数）
+    pub startup_wm_class: Option<String>, // StartupWMClass=
+    pub categories: Vec<String>,          // Categories=
+    pub no_display: bool,
+    pub path: PathBuf, // 文件位置
+}
+
+/// 已解析的 desktop 文件，首次使用时扫描
+static ENTRIES: Lazy<Mutex<Option<Vec<DesktopEntry>>>> = Lazy::new(|| Mutex::new(None));
+
+/// 窗口到 desktop 文件的匹配结果缓存，键为 (WM_CLASS instance, WM_CLASS class, 可执行文件名)
+type MatchKey = (String, String, String);
+static MATCHES: Lazy<Mutex<HashMap<MatchKey, Option<DesktopEntry>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// 按 XDG 规范列出 applications 目录，优先级从高到低
+pub fn application_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    match std::env::var_os("XDG_DATA_HOME") {
+        Some(home) if !home.is_empty() => dirs.push(PathBuf::from(home)),
+        _ => {
+            if let Some(home) = std::env::var_os("HOME") {
+                dirs.push(PathBuf::from(home).join(".local/share"));
+            }
+        }
+    }
+    let data_dirs = std::env::var("XDG_DATA_DIRS")
+        .ok()
+        .filter(|d| !d.is_empty())
+        .unwrap_or_else(|| "/usr/local/share:/usr/share".to_string());
+    dirs.extend(data_dirs.split(':').map(PathBuf::from));
+
+    // Flatpak 与 Snap 导出的 desktop 文件，某些会话没有把它们加入 XDG_DATA_DIRS
+    if let Some(home) = std::env::var_os("HOME") {
+        dirs.push(PathBuf::from(home).join(".local/share/flatpak/exports/share"));
+    }
+    dirs.push(PathBuf::from("/var/lib/flatpak/exports/share"));
+    dirs.push(PathBuf::from("/var/lib/snapd/desktop"));
+
+    let mut result: Vec<PathBuf> = Vec::new();
+    for dir in dirs {
+        let dir = dir.join("applications");
+        if !result.contains(&dir) {
+            result.push(dir);
+        }
+    }
+    result
+}
+
+/// 扫描所有 applications 目录，同一 id 只保留优先级最高的文件；
+/// 高优先级的文件被隐藏（Hidden=true）或无效时，低优先级的同 id 文件也不再使用
+pub fn scan(dirs: &[PathBuf]) -> Vec<DesktopEntry> {
+    let mut entries: Vec<DesktopEntry> = Vec::new();
+    let mut seen: HashSet<String> = HashSet::new();
+    for dir in dirs {
+        let mut files = Vec::new();
+        collect_files(dir, dir, &mut files);
+        for (id, path) in files {
+            if !seen.insert(id.clone()) {
+                continue;
+            }
+            let Ok(content) = std::fs::read_to_string(&path) else {
+                continue;
+            };
+            if let Some(entry) = parse(&id, &path, &content) {
+                entries.push(entry);
+            }
+        }
+    }
+    entries
+}
+
+/// 递归收集 .desktop 文件，子目录中的文件 id 以 `-` 连接目录名
+fn collect_files(base: &Path, dir: &Path, files: &mut Vec<(String, PathBuf)>) {
+    let Ok(read_dir) = std::fs::read_dir(dir) else {
+        return;
+    };
+    for entry in read_dir.flatten() {
+        let path = entry.path();
+        if path.is_dir() {
+            collect_files(base, &path, files);
+        } else if path.extension().is_some_and(|e| e == "desktop") {
+            let Ok(relative) = path.strip_prefix(base) else {
+                continue;
+            };
+            let id = relative
+                .with_extension("")
+                .to_string_lossy()
+                .replace('/', "-");
+            files.push((id, path));
+        }
+    }
+}
+
+/// 解析 `[Desktop Entry]` 段，隐藏或非应用类型的条目返回 None
+pub fn parse(id: &str, path: &Path, content: &str) -> Option<DesktopEntry> {
+    let mut in_entry = false;
+    let mut fields: HashMap<&str, &str> = HashMap::new();
+    for line in content.lines() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            in_entry = line == "[Desktop Entry]";
+            continue;
+        }
+        if !in_entry || line.starts_with('#') {
+            continue;
+        }
+        if let Some((key, value)) = line.split_once('=') {
+            fields.entry(key.trim()).or_insert(value.trim());
+        }
+    }
+
+    if fields.get("Type").is_some_and(|t| *t != "Application")
+        || fields.get("Hidden").is_some_and(|h| *h == "true")
+    {
+        return None;
+    }
+    let non_empty = |key: &str| {
+        fields
+            .get(key)
+            .filter(|v| !v.is_empty())
+            .map(|v| v.to_string())
+    };
+
+    Some(DesktopEntry {
+        id: id.to_string(),
+        name: non_empty("Name").unwrap_or_else(|| id.to_string()),
+        icon: non_empty("Icon"),
+        exec: fields.get("Exec").and_then(|e| exec_program(e)),
+        startup_wm_class: non_empty("StartupWMClass"),
+        categories: fields
+            .get("Categories")
+            .map(|c| {
+                c.split(';')
+                    .filter(|c| !c.is_empty())
+                    .map(|c| c.to_string())
+                    .collect()
+            })
+            .unwrap_or_default(),
+        no_display: fields.get("NoDisplay").is_some_and(|v| *v == "true"),
+        path: path.to_path_buf(),
+    })
+}
+
+/// 从 Exec= 中提取程序名，跳过 `env VAR=value` 前缀
+pub fn exec_program(exec: &str) -> Option<String> {
+    let mut words = split_exec(exec).into_iter();
+    let mut program = words.next()?;
+    if program == "env" || program.ends_with("/env") {
+        program = words.find(|w| !w.contains('=') && !w.starts_with('-'))?;
+    }
+    Path::new(&program)
+        .file_name()
+        .map(|f| f.to_string_lossy().into_owned())
+}
+
+/// 按 desktop 规范的引号规则拆分 Exec=
+fn split_exec(exec: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut current = String::new();
+    let mut quoted = false;
+    let mut chars = exec.chars();
+    while let Some(c) = chars.next() {
+        match c {
+            '"' => quoted = !quoted,
+            '\\' if quoted => {
+                if let Some(next) = chars.next() {
+                    current.push(next);
+                }
+            }
+            c if c.is_whitespace() && !quoted => {
+                if !current.is_empty() {
+                    words.push(std::mem::take(&mut current));
+                }
+            }
+            c => current.push(c),
+        }
+    }
+    if !current.is_empty() {
+        words.push(current);
+    }
+    words
+}
+
+/// 为窗口寻找对应的 desktop 文件
+///
+/// 匹配顺序：StartupWMClass 与 WM_CLASS、desktop id 与 WM_CLASS、Exec 程序名与可执行文件名。
+pub fn find_match<'a>(
+    entries: &'a [DesktopEntry],
+    instance: &str,
+    class: &str,
+    exe: &str,
+) -> Option<&'a DesktopEntry> {
+    let eq = |a: &str, b: &str| !a.is_empty() && a.eq_ignore_ascii_case(b);
+    // 同等匹配下优先选择会显示在菜单中的条目
+    let best = |pred: &dyn Fn(&DesktopEntry) -> bool| {
+        entries
+            .iter()
+            .filter(|e| pred(e))
+            .min_by_key(|e| e.no_display)
+    };
+
+    best(&|e| {
+        e.startup_wm_class
+            .as_deref()
+            .is_some_and(|c| eq(c, class) || eq(c, instance))
+    })
+    .or_else(|| {
+        best(&|e| {
+            // reverse-DNS 形式的 id 也比较最后一段，例如 org.gnome.Nautilus 与 nautilus
+            let short = e.id.rsplit('.').next().unwrap_or(&e.id);
+            eq(&e.id, class) || eq(&e.id, instance) || eq(short, class) || eq(short, instance)
+        })
+    })
+    .or_else(|| best(&|e| e.exec.as_deref().is_some_and(|p| eq(p, exe))))
+}
+
+/// 按 WM_CLASS 和可执行文件名查找 desktop 文件，结果会被缓存
+pub fn lookup(instance: &str, class: &str, exe: &str) -> Option<DesktopEntry> {
+    let key = (instance.to_string(), class.to_string(), exe.to_string());
+    if let Some(cached) = MATCHES.lock().unwrap().get(&key) {
+        return cached.clone();
+    }
+
+    let mut entries = ENTRIES.lock().unwrap();
+    let entries = entries.get_or_insert_with(|| scan(&application_dirs()));
+    let found = find_match(entries, instance, class, exe).cloned();
+    MATCHES.lock().unwrap().insert(key, found.clone());
+    found
+}
+
+/// 按 desktop-file id 查找
+pub fn by_id(id: &str) -> Option<DesktopEntry> {
+    let id = id.strip_suffix(".desktop").unwrap_or(id);
+    let mut entries = ENTRIES.lock().unwrap();
+    let entries = entries.get_or_insert_with(|| scan(&application_dirs()));
+    entries.iter().find(|e| e.id == id).cloned()
+}
+
+/// 所有已解析的 desktop 文件
+pub fn entries() -> Vec<DesktopEntry> {
+    let mut entries = ENTRIES.lock().unwrap();
+    entries
+        .get_or_insert_with(|| scan(&application_dirs()))
+        .clone()
+}
+
+/// 丢弃缓存，下次查找时重新扫描（安装或卸载应用后调用）
+pub fn refresh() {
+    *ENTRIES.lock().unwrap() = None;
+    MATCHES.lock().unwrap().clear();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entry(id: &str, content: &str) -> DesktopEntry {
+        parse(id, Path::new("/dev/null"), content).unwrap()
+    }
+
+    #[test]
+    fn test_parse_entry() {
+        let e = entry(
+            "google-chrome",
+            "[Desktop Entry]\nName=Google Chrome\nExec=/usr/bin/google-chrome-stable %U\n\
+             Icon=google-chrome\nType=Application\nCategories=Network;WebBrowser;\n\n[Desktop Action new-window]\nName=New Window\n",
+        );
+        assert_eq!(e.name, "Google Chrome");
+        assert_eq!(e.exec.as_deref(), Some("google-chrome-stable"));
+        assert_eq!(e.icon.as_deref(), Some("google-chrome"));
+        assert_eq!(e.categories, ["Network", "WebBrowser"]);
+
+        let e = entry(
+            "code",
+            "[Desktop Entry]\nName=Code\nExec=env BAMF_DESKTOP_FILE_HINT=x \"/opt/my code/code\" --new",
+        );
+        assert_eq!(e.exec.as_deref(), Some("code"));
+
+        assert!(parse("x", Path::new("x"), "[Desktop Entry]\nName=X\nHidden=true").is_none());
+        assert!(parse("x", Path::new("x"), "[Desktop Entry]\nName=X\nType=Link").is_none());
+    }
+
+    #[test]
+    fn test_find_match_order() {
+        let entries =
+            vec![
+            entry(
+                "jetbrains-idea",
+                "[Desktop Entry]\nName=IntelliJ IDEA\nExec=idea.sh\nStartupWMClass=jetbrains-idea",
+            ),
+            entry("org.gnome.Nautilus", "[Desktop Entry]\nName=Files\nExec=nautilus"),
+            entry("vim", "[Desktop Entry]\nName=Vim\nExec=vim %F\nNoDisplay=true"),
+            entry("gvim", "[Desktop Entry]\nName=GVim\nExec=vim -g %F"),
+        ];
+        let name = |instance, class, exe| {
+            find_match(&entries, instance, class, exe).map(|e| e.name.as_str())
+        };
+
+        assert_eq!(
+            name("jetbrains-idea", "jetbrains-idea", "java"),
+            Some("IntelliJ IDEA")
+        );
+        assert_eq!(
+            name("org.gnome.Nautilus", "Org.gnome.Nautilus", "nautilus"),
+            Some("Files")
+        );
+        assert_eq!(name("", "", "vim"), Some("GVim"));
+        assert_eq!(name("foo", "Foo", "foo"), None);
+    }
+
+    #[test]
+    fn test_scan_ids_and_precedence() {
+        let base = std::env::temp_dir().join(format!("spy-desktop-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&base);
+        let user = base.join("user/applications");
+        let system = base.join("system/applications");
+        std::fs::create_dir_all(user.join("kde4")).unwrap();
+        std::fs::create_dir_all(&system).unwrap();
+        std::fs::write(user.join("kde4/kate.desktop"), "[Desktop Entry]\nName=Kate").unwrap();
+        std::fs::write(user.join("app.desktop"), "[Desktop Entry]\nName=User App").unwrap();
+        std::fs::write(
+            system.join("app.desktop"),
+            "[Desktop Entry]\nName=System App",
+        )
+        .unwrap();
+        // 用户目录中的 Hidden=true 屏蔽同 id 的系统文件
+        std::fs::write(
+            user.join("hidden.desktop"),
+            "[Desktop Entry]\nName=Hidden\nHidden=true",
+        )
+        .unwrap();
+        std::fs::write(
+            system.join("hidden.desktop"),
+            "[Desktop Entry]\nName=System Hidden",
+        )
+        .unwrap();
+
+        let mut entries = scan(&[user, system]);
+        entries.sort_by(|a, b| a.id.cmp(&b.id));
+        let ids: Vec<(&str, &str)> = entries
+            .iter()
+            .map(|e| (e.id.as_str(), e.name.as_str()))
+            .collect();
+        assert_eq!(ids, vec![("app", "User App"), ("kde4-kate", "Kate")]);
+        let _ = std::fs::remove_dir_all(&base);
+    }
+}
@@ -0,0 +1,405 @@
+//! Linux 应用图标：解析 `_NET_WM_ICON` 像素数据，或按 freedesktop 图标主题规范
+//! 查找图标文件（PNG/SVG），统一输出为指定尺寸的 PNG。
+
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+
+use image::{imageops::FilterType, DynamicImage, ImageBuffer, Rgba};
+use once_cell::sync::Lazy;
+
+/// 所有主题最终都回退到 hicolor
+const FALLBACK_THEME: &str = "hicolor";
+/// 查找顺序中的文件扩展名
+const EXTENSIONS: &[&str] = &["png", "svg"];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum DirKind {
+    Fixed,
+    Scalable,
+    Threshold,
+}
+
+/// index.theme 中的一个图标目录
+#[derive(Debug, Clone)]
+struct ThemeDir {
+    path: String,
+    size: u32,
+    scale: u32,
+    min: u32,
+    max: u32,
+    threshold: u32,
+    kind: DirKind,
+}
+
+impl ThemeDir {
+    fn matches(&self, size: u32) -> bool {
+        self.distance(size) == 0
+    }
+
+    /// 与请求尺寸的距离，0 表示精确匹配
+    fn distance(&self, size: u32) -> u32 {
+        let (min, max) = match self.kind {
+            DirKind::Fixed => (self.size, self.size),
+            DirKind::Scalable => (self.min, self.max),
+            DirKind::Threshold => (
+                self.size.saturating_sub(self.threshold),
+                self.size + self.threshold,
+            ),
+        };
+        let (min, max) = (min * self.scale, max * self.scale);
+        if size < min {
+            min - size
+        } else {
+            size.saturating_sub(max)
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+struct Theme {
+    inherits: Vec<String>,
+    dirs: Vec<ThemeDir>,
+}
+
+/// 已加载的主题，None 表示不存在
+static THEMES: Lazy<Mutex<HashMap<String, Option<Theme>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// 图标主题的搜索根目录：~/.icons、$XDG_DATA_HOME/icons、$XDG_DATA_DIRS/icons
+pub fn icon_base_dirs() -> Vec<PathBuf> {
+    let mut dirs = Vec::new();
+    if let Some(home) = std::env::var_os("HOME") {
+        dirs.push(PathBuf::from(home).join(".icons"));
+    }
+    dirs.extend(
+        super::desktop_entry::application_dirs()
+            .into_iter()
+            .filter_map(|d| d.parent().map(|p| p.join("icons"))),
+    );
+    dirs
+}
+
+/// 当前图标主题：读取 GTK 与 KDE 的配置文件，不调用 gsettings
+pub fn current_theme() -> Option<String> {
+    let config = std::env::var_os("XDG_CONFIG_HOME")
+        .map(PathBuf::from)
+        .or_else(|| std::env::var_os("HOME").map(|h| PathBuf::from(h).join(".config")))?;
+
+    let candidates = [
+        (config.join("gtk-4.0/settings.ini"), "gtk-icon-theme-name"),
+        (config.join("gtk-3.0/settings.ini"), "gtk-icon-theme-name"),
+        (config.join("kdeglobals"), "Theme"),
+    ];
+    candidates.iter().find_map(|(path, key)| {
+        let content = std::fs::read_to_string(path).ok()?;
+        content.lines().find_map(|line| {
+            let (k, v) = line.split_once('=')?;
+            let v = v.trim().trim_matches('"');
+            (k.trim() == *key && !v.is_empty()).then(|| v.to_string())
+        })
+    })
+}
+
+fn parse_theme(content: &str) -> Theme {
+    let mut sections: HashMap<&str, HashMap<&str, &str>> = HashMap::new();
+    let mut current = "";
+    for line in content.lines() {
+        let line = line.trim();
+        if let Some(name) = line.strip_prefix('[').and_then(|l| l.strip_suffix(']')) {
+            current = name;
+        } else if let Some((k, v)) = line.split_once('=') {
+            sections
+                .entry(current)
+                .or_default()
+                .insert(k.trim(), v.trim());
+        }
+    }
+
+    let main = sections.get("Icon Theme").cloned().unwrap_or_default();
+    let list = |key: &str| -> Vec<String> {
+        main.get(key)
+            .map(|v| {
+                v.split(',')
+                    .map(|s| s.trim().to_string())
+                    .filter(|s| !s.is_empty())
+                    .collect()
+            })
+            .unwrap_or_default()
+    };
+    let mut dir_names = list("Directories");
+    dir_names.extend(list("ScaledDirectories"));
+
+    let dirs = dir_names
+        .into_iter()
+        .filter_map(|path| {
+            let section = sections.get(path.as_str())?;
+            let num = |key: &str| section.get(key).and_then(|v| v.parse::<u32>().ok());
+            let size = num("Size")?;
+            Some(ThemeDir {
+                size,
+                scale: num("Scale").unwrap_or(1).max(1),
+                min: num("MinSize").unwrap_or(size),
+                max: num("MaxSize").unwrap_or(size),
+                threshold: num("Threshold").unwrap_or(2),
+                kind: match section.get("Type").copied() {
+                    Some("Fixed") => DirKind::Fixed,
+                    Some("Scalable") => DirKind::Scalable,
+                    _ => DirKind::Threshold,
+                },
+                path,
+            })
+        })
+        .collect();
+
+    Theme {
+        inherits: list("Inherits"),
+        dirs,
+    }
+}
+
+fn load_theme(name: &str, bases: &[PathBuf]) -> Option<Theme> {
+    let mut themes = THEMES.lock().unwrap();
+    themes
+        .entry(format!("{}\0{:?}", name, bases))
+        .or_insert_with(|| {
+            bases.iter().find_map(|base| {
+                let content = std::fs::read_to_string(base.join(name).join("index.theme")).ok()?;
+                Some(parse_theme(&content))
+            })
+        })
+        .clone()
+}
+
+/// 按规范在主题链中查找图标文件：先找尺寸精确匹配的，其次尺寸最接近的
+pub fn find_icon(name: &str, size: u32, theme: Option<&str>, bases: &[PathBuf]) -> Option<PathBuf> {
+    // 主题继承链：当前主题 → Inherits → hicolor
+    let mut chain: Vec<String> = theme.map(|t| vec![t.to_string()]).unwrap_or_default();
+    let mut i = 0;
+    while i < chain.len() {
+        if let Some(theme) = load_theme(&chain[i], bases) {
+            for parent in theme.inherits {
+                if !chain.contains(&parent) {
+                    chain.push(parent);
+                }
+            }
+        }
+        i += 1;
+    }
+    if !chain.iter().any(|t| t == FALLBACK_THEME) {
+        chain.push(FALLBACK_THEME.to_string());
+    }
+
+    for theme_name in &chain {
+        let Some(theme) = load_theme(theme_name, bases) else {
+            continue;
+        };
+        let mut closest: Option<(u32, PathBuf)> = None;
+        for dir in &theme.dirs {
+            for base in bases {
+                for ext in EXTENSIONS {
+                    let file = base
+                        .join(theme_name)
+                        .join(&dir.path)
+                        .join(format!("{}.{}", name, ext));
+                    if !file.is_file() {
+                        continue;
+                    }
+                    if dir.matches(size) {
+                        return Some(file);
+                    }
+                    let distance = dir.distance(size);
+                    if closest.as_ref().is_none_or(|(d, _)| distance < *d) {
+                        closest = Some((distance, file));
+                    }
+                }
+            }
+        }
+        if let Some((_, file)) = closest {
+            return Some(file);
+        }
+    }
+
+    // 不属于任何主题的图标
+    let pixmaps = bases
+        .iter()
+        .filter_map(|b| b.parent().map(|p| p.join("pixmaps")));
+    pixmaps.into_iter().find_map(|dir| {
+        EXTENSIONS
+            .iter()
+            .map(|ext| dir.join(format!("{}.{}", name, ext)))
+            .find(|f| f.is_file())
+    })
+}
+
+/// 按图标名称（或 Icon= 中的绝对路径）返回指定尺寸的 PNG
+pub fn icon_png(icon: &str, size: u32) -> Option<Vec<u8>> {
+    let path = if Path::new(icon).is_absolute() {
+        PathBuf::from(icon)
+    } else {
+        find_icon(icon, size, current_theme().as_deref(), &icon_base_dirs())?
+    };
+    render_file(&path, size)
+}
+
+/// 把图标文件渲染为 size×size 的 PNG，SVG 使用 resvg 光栅化
+pub fn render_file(path: &Path, size: u32) -> Option<Vec<u8>> {
+    let data = std::fs::read(path).ok()?;
+    let image = if path.extension().is_some_and(|e| e == "svg" || e == "svgz") {
+        rasterize_svg(&data, size)?
+    } else {
+        image::load_from_memory(&data).ok()?
+    };
+    encode_png(&image, size)
+}
+
+fn rasterize_svg(data: &[u8], size: u32) -> Option<DynamicImage> {
+    use resvg::{tiny_skia, usvg};
+
+    let tree = usvg::Tree::from_data(data, &usvg::Options::default()).ok()?;
+    let mut pixmap = tiny_skia::Pixmap::new(size, size)?;
+    let svg_size = tree.size();
+    let scale = (size as f32 / svg_size.width()).min(size as f32 / svg_size.height());
+    // 保持比例居中
+    let dx = (size as f32 - svg_size.width() * scale) / 2.0;
+    let dy = (size as f32 - svg_size.height() * scale) / 2.0;
+    resvg::render(
+        &tree,
+        tiny_skia::Transform::from_row(scale, 0.0, 0.0, scale, dx, dy),
+        &mut pixmap.as_mut(),
+    );
+
+    // tiny-skia 使用预乘 alpha，转回普通 RGBA
+    let rgba: Vec<u8> = pixmap
+        .pixels()
+        .iter()
+        .flat_map(|p| {
+            let c = p.demultiply();
+            [c.red(), c.green(), c.blue(), c.alpha()]
+        })
+        .collect();
+    let buffer: ImageBuffer<Rgba<u8>, _> = ImageBuffer::from_raw(size, size, rgba)?;
+    Some(DynamicImage::ImageRgba8(buffer))
+}
+
+/// 从 `_NET_WM_ICON` 的多个尺寸中选取最合适的一个并输出 PNG
+///
+/// 优先选择不小于目标尺寸的最小图标，避免放大模糊。
+pub fn argb_icon_png(icons: &[(u32, u32, Vec<u32>)], size: u32) -> Option<Vec<u8>> {
+    let (width, height, pixels) = icons.iter().min_by_key(|(w, h, _)| {
+        let side = (*w).max(*h);
+        if side >= size {
+            (0, side - size)
+        } else {
+            (1, size - side)
+        }
+    })?;
+
+    let rgba: Vec<u8> = pixels
+        .iter()
+        .flat_map(|argb| {
+            let [a, r, g, b] = argb.to_be_bytes();
+            [r, g, b, a]
+        })
+        .collect();
+    let buffer: ImageBuffer<Rgba<u8>, _> = ImageBuffer::from_raw(*width, *height, rgba)?;
+    encode_png(&DynamicImage::ImageRgba8(buffer), size)
+}
+
+fn encode_png(image: &DynamicImage, size: u32) -> Option<Vec<u8>> {
+    let image = if image.width() == size && image.height() == size {
+        image.clone()
+    } else {
+        image.resize_exact(size, size, FilterType::Lanczos3)
+    };
+    let mut buf = Cursor::new(Vec::new());
+    image.write_to(&mut buf, image::ImageFormat::Png).ok()?;
+    Some(buf.into_inner())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(png: &[u8]) -> image::RgbaImage {
+        image::load_from_memory(png).unwrap().to_rgba8()
+    }
+
+    fn write_png(path: &Path, size: u32) {
+        std::fs::create_dir_all(path.parent().unwrap()).unwrap();
+        ImageBuffer::from_pixel(size, size, Rgba([255u8, 0, 0, 255]))
+            .save(path)
+            .unwrap();
+    }
+
+    #[test]
+    fn test_find_icon_in_theme_chain() {
+        let base = std::env::temp_dir().join(format!("spy-icons-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&base);
+        let icons = base.join("icons");
+
+        std::fs::create_dir_all(icons.join("Custom")).unwrap();
+        std::fs::write(
+            icons.join("Custom/index.theme"),
+            "[Icon Theme]\nName=Custom\nInherits=hicolor\nDirectories=48x48/apps\n\n\
+             [48x48/apps]\nSize=48\nType=Fixed\n",
+        )
+        .unwrap();
+        std::fs::create_dir_all(icons.join("hicolor")).unwrap();
+        std::fs::write(
+            icons.join("hicolor/index.theme"),
+            "[Icon Theme]\nName=Hicolor\nDirectories=16x16/apps,32x32/apps,scalable/apps\n\n\
+             [16x16/apps]\nSize=16\nType=Threshold\n\n[32x32/apps]\nSize=32\nType=Threshold\n\n\
+             [scalable/apps]\nSize=128\nMinSize=8\nMaxSize=512\nType=Scalable\n",
+        )
+        .unwrap();
+        write_png(&icons.join("Custom/48x48/apps/editor.png"), 48);
+        write_png(&icons.join("hicolor/16x16/apps/editor.png"), 16);
+        write_png(&icons.join("hicolor/32x32/apps/viewer.png"), 32);
+        write_png(&base.join("pixmaps/legacy.png"), 24);
+
+        let bases = vec![icons.clone()];
+        // 当前主题中只有 48px，仍优先于 hicolor 中的图标
+        assert_eq!(
+            find_icon("editor", 16, Some("Custom"), &bases),
+            Some(icons.join("Custom/48x48/apps/editor.png"))
+        );
+        assert_eq!(
+            find_icon("viewer", 32, Some("Custom"), &bases),
+            Some(icons.join("hicolor/32x32/apps/viewer.png"))
+        );
+        assert_eq!(
+            find_icon("legacy", 32, None, &bases),
+            Some(base.join("pixmaps/legacy.png"))
+        );
+        assert_eq!(find_icon("missing", 32, Some("Custom"), &bases), None);
+
+        let png = render_file(&icons.join("Custom/48x48/apps/editor.png"), 32).unwrap();
+        assert_eq!(decode(&png).dimensions(), (32, 32));
+        let _ = std::fs::remove_dir_all(&base);
+    }
+
+    #[test]
+    fn test_rasterize_svg() {
+        let svg = br##"<svg xmlns="http://www.w3.org/2000/svg" width="16" height="16">
+            <rect width="16" height="16" fill="#0000ff"/></svg>"##;
+        let image = rasterize_svg(svg, 64).unwrap().to_rgba8();
+        assert_eq!(image.dimensions(), (64, 64));
+        assert_eq!(image.get_pixel(32, 32).0, [0, 0, 255, 255]);
+    }
+
+    #[test]
+    fn test_argb_icon_picks_best_size() {
+        let icon = |size: u32, argb: u32| (size, size, vec![argb; (size * size) as usize]);
+        let icons = vec![
+            icon(16, 0xff00ff00),
+            icon(48, 0x80ff0000),
+            icon(128, 0xff0000ff),
+        ];
+        let png = argb_icon_png(&icons, 32).unwrap();
+        let image = decode(&png);
+        assert_eq!(image.dimensions(), (32, 32));
+        assert_eq!(image.get_pixel(0, 0).0, [255, 0, 0, 128]);
+    }
+}
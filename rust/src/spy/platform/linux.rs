@@ -1,8 +1,367 @@
+use std::path::PathBuf;
+
+use crate::spy::event::{self, TrackerEvent, TrackerEventKind};
 use crate::spy::model::Application;
 use crate::spy::model::ApplicationProvider;
+use crate::spy::model::WindowInfo;
+use crate::spy::monitor::{self, Monitor};
+use crate::spy::platform::{compositor, desktop_entry, icon_theme, packaging, X11};
+use crate::spy::visibility::Rect;
+use crate::spy::window_state;
+use crate::spy::workspace::{self, Workspace};
+
+/// 缓存的原始图标尺寸，界面按需缩放
+const ICON_SIZE: u32 = 128;
 
 impl ApplicationProvider for Application {
-    fn from_process<T>(p: T) -> Option<Application> {
-        todo!()
+    fn from_process<T>(_p: T) -> Option<Application> {
+        // Linux 实现获取当前激活窗口
+        Self::get_active_window_application()
+    }
+}
+
+impl Application {
+    /// 通过 EWMH 获取当前激活窗口对应的应用；原生 Wayland 窗口在前台时 X11 没有激活窗口，
+    /// 改由合成器 IPC 获取
+    pub fn get_active_window_application() -> Option<Application> {
+        X11::with(|x| {
+            let Some(window) = x.active_window()? else {
+                return Ok(None);
+            };
+            Ok(Some(Self::from_window(x, window, true)?))
+        })
+        .flatten()
+        .or_else(Self::from_compositor)
+    }
+
+    /// 从合成器报告的前台窗口创建 Application 实例，不截图
+    fn from_compositor() -> Option<Application> {
+        let window = compositor::focused_window()?;
+        let app = Self::from_pid(
+            window.pid,
+            &window.app_id,
+            &window.app_id,
+            window.title.clone(),
+            || None,
+        );
+        let workspace = compositor::current_workspace()
+            .map(|(index, name)| workspace::workspace(index, Some(&name)));
+        Some(Application {
+            workspace,
+            state: Some(window_state::WindowState {
+                fullscreen: window.fullscreen,
+                ..Default::default()
+            }),
+            geometry: window.rect,
+            ..app
+        })
+    }
+
+    /// 从 X11 窗口创建 Application 实例，active 为 false 时不截图
+    fn from_window(x: &X11, window: u32, active: bool) -> anyhow::Result<Application> {
+        // 获取窗口标题（动态变化）
+        let title = x
+            .window_title(window)?
+            .unwrap_or_else(|| String::from("Unknown Window"));
+
+        // 获取进程ID
+        let pid = x.window_pid(window)?;
+        let (instance, class) = x.window_class(window)?.unwrap_or_default();
+        let app = Self::from_pid(pid, &instance, &class, title, || {
+            Self::window_icon_png(x, window)
+        });
+
+        // 窗口所在显示器
+        let rect = x.window_rect(window).ok();
+        let monitors = x.monitors().unwrap_or_default();
+        let monitor = rect
+            .as_ref()
+            .and_then(|r| monitor::monitor_for(r, &monitors))
+            .map(|m| m.name.clone());
+
+        // 窗口状态，未标记全屏但覆盖整个显示器的窗口（无边框游戏等）也算全屏
+        let state = x.state(window).ok().map(|mut state| {
+            state.fullscreen |= !state.maximized
+                && rect.is_some_and(|r| window_state::covers_monitor(&r, &monitors));
+            state
+        });
+
+        let workspace = Self::workspace(x, window, active);
+
+        // 检查截图功能
+        let mut screen_shot_path = None;
+        if active && super::is_screenshot_target(&app.name, app.app_id.as_deref()) {
+            if let Some(rect) = rect {
+                screen_shot_path = Self::capture(x, &rect, &monitors, &app.title);
+            }
+        }
+
+        Ok(Application {
+            screen_shot_path,
+            monitor,
+            workspace,
+            state,
+            geometry: rect,
+            ..app
+        })
+    }
+
+    /// 按进程和窗口类识别应用：打包格式、运行时、desktop 文件、图标和仓库，
+    /// 不包含窗口位置、状态和工作区；window_icon 提取窗口自带的图标
+    fn from_pid(
+        pid: Option<u32>,
+        instance: &str,
+        class: &str,
+        title: String,
+        window_icon: impl FnOnce() -> Option<Vec<u8>>,
+    ) -> Application {
+        // 获取进程的可执行文件路径
+        let exe_path = pid
+            .and_then(|pid| std::fs::read_link(format!("/proc/{}/exe", pid)).ok())
+            .map(|p| p.to_string_lossy().into_owned())
+            .unwrap_or_default();
+
+        // Flatpak、Snap、AppImage 使用稳定的应用 id 和路径
+        let package = pid.and_then(|pid| {
+            let (_, environ) = crate::spy::runtime::process_command(pid)?;
+            packaging::detect(pid, &exe_path, &environ)
+        });
+
+        // java、python 等运行时从命令行中找出真正的应用
+        let runtime = crate::spy::runtime::resolve(&exe_path, pid);
+        let path = runtime
+            .as_ref()
+            .and_then(|r| r.target.clone())
+            .or_else(|| package.as_ref().and_then(|p| p.path.clone()))
+            .unwrap_or_else(|| exe_path.clone());
+
+        // 通过启动器提示的 desktop-file id，或 WM_CLASS 和可执行文件名匹配 desktop 文件，
+        // 得到显示名称和 desktop-file id
+        let exe = match &runtime {
+            Some(r) => r.name.clone(),
+            None => std::path::Path::new(&exe_path)
+                .file_name()
+                .map(|f| f.to_string_lossy().into_owned())
+                .unwrap_or_default(),
+        };
+        let entry = package
+            .as_ref()
+            .and_then(|p| p.desktop_id.as_deref())
+            .and_then(desktop_entry::by_id)
+            .or_else(|| desktop_entry::lookup(instance, class, &exe));
+
+        // 没有 desktop 文件时退回打包名称、运行时应用名或可执行文件名、WM_CLASS、标题
+        let name = match (&entry, &package) {
+            (Some(entry), _) => entry.name.clone(),
+            (None, Some(package)) => package.id.clone(),
+            (None, None) if !exe.is_empty() => exe.clone(),
+            (None, None) if !class.is_empty() => class.to_string(),
+            (None, None) => title.clone(),
+        };
+        let app_id = entry
+            .as_ref()
+            .map(|e| e.id.clone())
+            .or_else(|| package.as_ref().map(|p| p.id.clone()));
+
+        // 同一应用只提取一次图标，按 desktop-file id、可执行文件路径、名称缓存
+        let icon_key = match (&app_id, path.is_empty()) {
+            (Some(id), _) => id.clone(),
+            (None, false) => path.clone(),
+            (None, true) => name.clone(),
+        };
+        let icon = crate::spy::icon::icon_for(&icon_key, || {
+            window_icon()
+                .or_else(|| icon_theme::icon_png(entry.as_ref()?.icon.as_deref()?, ICON_SIZE))
+        });
+
+        // 进程当前工作目录，用于仓库归属
+        let cwd = pid.and_then(process_cwd);
+        let git = crate::spy::git::attribute(&name, &exe_path, &title, cwd.as_deref());
+
+        Application {
+            icon,
+            name,
+            title,
+            path,
+            repo: git.as_ref().map(|g| g.root.to_string_lossy().into_owned()),
+            branch: git.and_then(|g| g.branch),
+            app_id,
+            exe_path,
+            runtime: runtime.map(|r| r.runtime),
+            package: package.as_ref().map(|p| p.kind.as_str().to_string()),
+            version: package.and_then(|p| p.version),
+            process: pid.and_then(crate::spy::process::process_info),
+            ..Default::default()
+        }
     }
+
+    /// 窗口所在工作区：前台窗口优先使用合成器 IPC，其余窗口读取 _NET_WM_DESKTOP
+    fn workspace(x: &X11, window: u32, active: bool) -> Option<Workspace> {
+        if active {
+            if let Some((index, name)) = compositor::current_workspace() {
+                return Some(workspace::workspace(index, Some(&name)));
+            }
+        }
+        let current = x.current_desktop().ok().flatten();
+        let index = if active {
+            current
+        } else {
+            // 0xFFFFFFFF 表示窗口在所有桌面显示
+            x.window_desktop(window)
+                .ok()
+                .flatten()
+                .filter(|d| *d != u32::MAX)
+                .or(current)
+        }?;
+        let names = x.desktop_names().unwrap_or_default();
+        Some(workspace::workspace(
+            index,
+            names.get(index as usize).map(|n| n.as_str()),
+        ))
+    }
+
+    /// 按截图模式截取窗口、所在显示器或所有显示器，返回保存路径
+    fn capture(x: &X11, window: &Rect, monitors: &[Monitor], title: &str) -> Option<String> {
+        let (canvas, regions) =
+            monitor::capture_regions(monitor::capture_mode(), window, monitors)?;
+        // 单个区域截取失败时发出带原因的事件，其余区域照常拼接
+        let mut failed = false;
+        let Some(image) = monitor::composite(&canvas, &regions, |r| match x.capture(r) {
+            Ok(image) => Some(image),
+            Err(e) => {
+                failed = true;
+                event::emit(TrackerEvent::failed(
+                    TrackerEventKind::ScreenshotFailed,
+                    "x11",
+                    e,
+                ));
+                None
+            }
+        }) else {
+            if !failed {
+                event::emit(TrackerEvent::failed(
+                    TrackerEventKind::ScreenshotFailed,
+                    "x11",
+                    "capture failed",
+                ));
+            }
+            return None;
+        };
+        let save_folder = crate::api::spy_api::SCREENSHOT_SAVE_FOLDER
+            .lock()
+            .unwrap()
+            .clone();
+        match monitor::save_capture(&image, &save_folder, title) {
+            Ok(path) => Some(path),
+            Err(e) => {
+                event::emit(TrackerEvent::failed(
+                    TrackerEventKind::ScreenshotFailed,
+                    "x11",
+                    e,
+                ));
+                None
+            }
+        }
+    }
+
+    /// 窗口自带的图标（_NET_WM_ICON），没有时由调用方退回 desktop 文件中 Icon= 指定的主题图标
+    fn window_icon_png(x: &X11, window: u32) -> Option<Vec<u8>> {
+        x.window_icons(window)
+            .ok()
+            .and_then(|icons| icon_theme::argb_icon_png(&icons, ICON_SIZE))
+    }
+}
+
+/// 通过 _NET_CLIENT_LIST 列出所有打开的窗口
+pub fn list_windows() -> Vec<WindowInfo> {
+    X11::with(|x| {
+        let active = x.active_window()?;
+        let mut windows = Vec::new();
+        for window in x.client_list()? {
+            // 枚举期间关闭的窗口会读取失败，跳过即可
+            match Application::from_window(x, window, false) {
+                Ok(app) => windows.push(WindowInfo {
+                    window_id: window as u64,
+                    focused: active == Some(window),
+                    app,
+                }),
+                Err(e) => println!("[x11] window {} error: {}", window, e),
+            }
+        }
+        Ok(windows)
+    })
+    .unwrap_or_default()
+}
+
+/// 所有打开窗口的标题，只读取标题，开销比 list_windows 小
+pub fn window_titles() -> Vec<String> {
+    X11::with(|x| {
+        let mut titles = Vec::new();
+        for window in x.client_list()? {
+            if let Ok(Some(title)) = x.window_title(window) {
+                titles.push(title);
+            }
+        }
+        Ok(titles)
+    })
+    .unwrap_or_default()
+}
+
+/// 当前桌面上可见的窗口：已映射、未最小化，且未被上层窗口遮挡的面积足够大
+pub fn visible_windows() -> Vec<WindowInfo> {
+    use crate::spy::visibility::{is_visible, visible_ratios};
+
+    X11::with(|x| {
+        let active = x.active_window()?;
+        let desktop = x.current_desktop()?;
+        let mut candidates = Vec::new();
+        for window in x.stacking_list()? {
+            // 枚举期间关闭的窗口会读取失败，跳过即可
+            let Ok(true) = x.is_viewable(window) else {
+                continue;
+            };
+            let hidden = x
+                .window_state(window)
+                .is_ok_and(|s| s.contains(&x.atoms._NET_WM_STATE_HIDDEN));
+            let other_desktop = match (desktop, x.window_desktop(window).ok().flatten()) {
+                (Some(current), Some(d)) => d != current && d != u32::MAX,
+                _ => false,
+            };
+            if hidden || other_desktop {
+                continue;
+            }
+            if let Ok(rect) = x.window_rect(window) {
+                candidates.push((window, rect));
+            }
+        }
+
+        let rects: Vec<_> = candidates.iter().map(|(_, r)| *r).collect();
+        let screens: Vec<_> = x.monitors()?.iter().map(|m| m.rect()).collect();
+        let ratios = visible_ratios(&rects, &screens);
+        let mut windows = Vec::new();
+        for ((window, _), ratio) in candidates.into_iter().zip(ratios) {
+            if !is_visible(ratio) {
+                continue;
+            }
+            if let Ok(app) = Application::from_window(x, window, false) {
+                windows.push(WindowInfo {
+                    window_id: window as u64,
+                    focused: active == Some(window),
+                    app,
+                });
+            }
+        }
+        Ok(windows)
+    })
+    .unwrap_or_default()
+}
+
+/// 显示器布局
+pub fn monitors() -> Vec<Monitor> {
+    X11::with(|x| x.monitors()).unwrap_or_default()
+}
+
+/// 读取 /proc/<pid>/cwd
+pub fn process_cwd(pid: u32) -> Option<PathBuf> {
+    std::fs::read_link(format!("/proc/{}/cwd", pid)).ok()
 }
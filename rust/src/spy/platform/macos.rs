@@ -6,6 +6,7 @@ use std::ffi::CStr;
 
 use crate::spy::model::Application;
 use crate::spy::model::ApplicationProvider;
+use crate::spy::model::WindowInfo;
 
 /// macOS 平台的 ApplicationProvider 实现
 impl ApplicationProvider for Application {
@@ -72,14 +73,38 @@ impl Application {
             }
         }
 
-        // 获取应用图标
-        let icon = if bundle_url != nil {
-            let path_nsstring: id = msg_send![bundle_url, path];
+        // 获取可执行文件路径
+        let mut exe_path = String::new();
+        let executable_url: id = msg_send![app, executableURL];
+        if executable_url != nil {
+            let path_nsstring: id = msg_send![executable_url, path];
             if path_nsstring != nil {
-                Self::get_app_icon_base64(path_nsstring)
-            } else {
-                None
+                exe_path = nsstring_to_rust(path_nsstring);
             }
+        }
+
+        // 没有 Bundle 的 java、python 等运行时程序，从命令行中找出真正的应用
+        let pid: i32 = msg_send![app, processIdentifier];
+        let runtime = if bundle_id_str.is_empty() {
+            crate::spy::runtime::resolve(&exe_path, u32::try_from(pid).ok())
+        } else {
+            None
+        };
+        let (name, path) = match &runtime {
+            Some(r) => (r.name.clone(), r.target.clone().unwrap_or(path)),
+            None => (name, path),
+        };
+
+        // 获取应用图标，同一应用只提取一次
+        let icon = if bundle_url != nil {
+            crate::spy::icon::icon_for(&path, || {
+                let path_nsstring: id = msg_send![bundle_url, path];
+                if path_nsstring != nil {
+                    Self::get_app_icon_png(path_nsstring)
+                } else {
+                    None
+                }
+            })
         } else {
             None
         };
@@ -90,19 +115,36 @@ impl Application {
         // 检查截图功能
         let mut screen_shot_path = None;
         {
-            if super::is_screenshot_app(name.clone()) {
+            if super::is_screenshot_target(&name, Some(&bundle_id_str)) {
                 // macOS 上的截图实现可以后续添加
                 // 暂时留空，因为需要额外的权限和实现
                 println!("macOS 截图功能待实现");
             }
         }
 
+        // 从窗口标题中的路径解析 git 仓库
+        let git = crate::spy::git::attribute(&name, &exe_path, &title, None);
+
         Some(Application {
             icon,
             name,
             title,
             path,
             screen_shot_path,
+            repo: git.as_ref().map(|g| g.root.to_string_lossy().into_owned()),
+            branch: git.and_then(|g| g.branch),
+            app_id: Some(bundle_id_str).filter(|id| !id.is_empty()),
+            exe_path,
+            runtime: runtime.map(|r| r.runtime),
+            package: None,
+            version: None,
+            process: u32::try_from(pid)
+                .ok()
+                .and_then(crate::spy::process::process_info),
+            monitor: None,
+            workspace: None,
+            state: None,
+            geometry: None,
         })
     }
 
@@ -122,8 +164,8 @@ impl Application {
         fallback_name.to_string()
     }
 
-    /// 获取应用图标并转换为 Base64
-    unsafe fn get_app_icon_base64(path_nsstring: id) -> Option<String> {
+    /// 获取应用图标并转换为 PNG
+    unsafe fn get_app_icon_png(path_nsstring: id) -> Option<Vec<u8>> {
         if path_nsstring == nil {
             return None;
         }
@@ -141,11 +183,11 @@ impl Application {
         }
 
         // 转换 NSImage 为 Base64
-        Self::nsimage_to_base64(icon)
+        Self::nsimage_to_png(icon)
     }
 
-    /// 将 NSImage 转换为 Base64 字符串
-    unsafe fn nsimage_to_base64(image: id) -> Option<String> {
+    /// 将 NSImage 转换为 PNG 数据
+    unsafe fn nsimage_to_png(image: id) -> Option<Vec<u8>> {
         if image == nil {
             return None;
         }
@@ -185,38 +227,58 @@ impl Application {
         }
 
         // 转换为 Vec<u8>
-        let data = std::slice::from_raw_parts(bytes, length).to_vec();
-
-        // 转换为 Base64
-        use base64::{engine::general_purpose, Engine as _};
-        Some(general_purpose::STANDARD.encode(data))
+        Some(std::slice::from_raw_parts(bytes, length).to_vec())
     }
+}
 
-    /// 获取运行中的所有应用（用于调试和扩展功能）
-    #[allow(dead_code)]
-    pub unsafe fn get_running_applications() -> Vec<Application> {
-        let mut apps = Vec::new();
-
+/// 列出 Dock 中显示的普通应用；窗口 id 需要屏幕录制权限才能获取，这里以应用为单位，window_id 为 0
+pub fn list_windows() -> Vec<WindowInfo> {
+    let mut windows = Vec::new();
+    unsafe {
         let workspace: id = msg_send![class!(NSWorkspace), sharedWorkspace];
         if workspace == nil {
-            return apps;
+            return windows;
         }
 
         let running_apps: id = msg_send![workspace, runningApplications];
         if running_apps == nil {
-            return apps;
+            return windows;
         }
 
         let count: usize = msg_send![running_apps, count];
         for i in 0..count {
             let app: id = msg_send![running_apps, objectAtIndex: i];
-            if let Some(application) = Self::create_application_from_nsrunning_app(app) {
-                apps.push(application);
+            // NSApplicationActivationPolicyRegular = 0，跳过后台服务和菜单栏工具
+            let policy: i64 = msg_send![app, activationPolicy];
+            if policy != 0 {
+                continue;
+            }
+            let active: objc::runtime::BOOL = msg_send![app, isActive];
+            if let Some(application) = Application::create_application_from_nsrunning_app(app) {
+                windows.push(WindowInfo {
+                    window_id: 0,
+                    focused: active == objc::runtime::YES,
+                    app: application,
+                });
             }
         }
-
-        apps
     }
+    windows
+}
+
+/// 窗口位置和叠放顺序需要屏幕录制权限，macOS 上暂不统计可见时间
+pub fn visible_windows() -> Vec<WindowInfo> {
+    Vec::new()
+}
+
+/// macOS 上只能拿到各应用前台窗口的标题
+pub fn window_titles() -> Vec<String> {
+    list_windows().into_iter().map(|w| w.app.title).collect()
+}
+
+/// macOS 上暂未获取显示器布局
+pub fn monitors() -> Vec<crate::spy::monitor::Monitor> {
+    Vec::new()
 }
 
 /// 工具函数: NSString -> Rust String
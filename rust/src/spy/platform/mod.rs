@@ -2,6 +2,8 @@ use std::collections::HashSet;
 use std::sync::Mutex;
 
 use once_cell::sync::Lazy;
+
+use crate::spy::model::Application;
 #[cfg(target_os = "windows")]
 mod window_capture;
 #[cfg(target_os = "windows")]
@@ -18,10 +20,31 @@ mod macos;
 #[cfg(target_os = "macos")]
 pub use macos::*;
 
+#[cfg(target_os = "linux")]
+pub mod av_devices;
+#[cfg(target_os = "linux")]
+pub mod compositor;
+#[cfg(target_os = "linux")]
+pub mod desktop_entry;
+#[cfg(target_os = "linux")]
+pub mod icon_theme;
 #[cfg(target_os = "linux")]
 mod linux;
 #[cfg(target_os = "linux")]
+pub mod mpris;
+#[cfg(target_os = "linux")]
+pub mod packaging;
+#[cfg(target_os = "linux")]
+pub mod proc_connector;
+#[cfg(target_os = "linux")]
+mod x11;
+#[cfg(target_os = "linux")]
+pub mod xinput;
+#[cfg(target_os = "linux")]
+#[allow(unused_imports)]
 pub use linux::*;
+#[cfg(target_os = "linux")]
+pub use x11::*;
 
 pub static SCREENSHOT_APPS_ON: Lazy<Mutex<HashSet<String>>> =
     Lazy::new(|| Mutex::new(HashSet::new()));
@@ -47,3 +70,31 @@ pub fn is_screenshot_app(v: String) -> bool {
     let apps = SCREENSHOT_APPS_ON.lock().unwrap();
     apps.contains(&v)
 }
+
+/// 应用名称或平台应用标识任一在截图列表中即需要截图
+pub fn is_screenshot_target(name: &str, app_id: Option<&str>) -> bool {
+    let apps = SCREENSHOT_APPS_ON.lock().unwrap();
+    apps.contains(name) || app_id.is_some_and(|id| apps.contains(id))
+}
+
+/// 正在运行的应用：按平台应用标识、路径或名称合并打开的窗口，焦点窗口所属的记录优先
+pub fn list_running_applications() -> Vec<Application> {
+    let mut windows = list_windows();
+    windows.sort_by_key(|w| !w.focused);
+    let mut seen = HashSet::new();
+    windows
+        .into_iter()
+        .filter(|w| {
+            let app = &w.app;
+            let key = app.app_id.clone().unwrap_or_else(|| {
+                if app.path.is_empty() {
+                    app.name.clone()
+                } else {
+                    app.path.clone()
+                }
+            });
+            seen.insert(key)
+        })
+        .map(|w| w.app)
+        .collect()
+}
@@ -0,0 +1,170 @@
+//! 通过 D-Bus 读取 MPRIS 播放器状态
+//!
+//! 每个播放器在会话总线上注册 `org.mpris.MediaPlayer2.<名称>`，在 `/org/mpris/MediaPlayer2`
+//! 上提供 `org.mpris.MediaPlayer2`（Identity）和 `org.mpris.MediaPlayer2.Player`
+//! （PlaybackStatus、Metadata）接口。
+
+use std::collections::HashMap;
+
+use zbus::blocking::fdo::DBusProxy;
+use zbus::blocking::proxy::Builder;
+use zbus::blocking::{Connection, Proxy};
+use zbus::proxy::CacheProperties;
+use zbus::zvariant::OwnedValue;
+
+use crate::spy::event::{self, TrackerEvent, TrackerEventKind};
+use crate::spy::media::{MediaState, PlaybackStatus};
+
+const BUS_PREFIX: &str = "org.mpris.MediaPlayer2.";
+const OBJECT_PATH: &str = "/org/mpris/MediaPlayer2";
+
+fn proxy<'a>(
+    connection: &Connection,
+    name: &'a str,
+    interface: &'a str,
+) -> zbus::Result<Proxy<'a>> {
+    Builder::new(connection)
+        .destination(name)?
+        .path(OBJECT_PATH)?
+        .interface(interface)?
+        .cache_properties(CacheProperties::No)
+        .build()
+}
+
+/// 读取总线上所有 MPRIS 播放器的状态，单个播放器读取失败时跳过
+pub fn players(connection: &Connection) -> anyhow::Result<Vec<MediaState>> {
+    let names = DBusProxy::new(connection)?.list_names()?;
+    let mut players = Vec::new();
+    for name in names.iter().filter(|n| n.starts_with(BUS_PREFIX)) {
+        match player(connection, name) {
+            Ok(state) => players.push(state),
+            Err(e) => event::emit(TrackerEvent::failed(
+                TrackerEventKind::Error,
+                "mpris",
+                format!("{}: {}", name, e),
+            )),
+        }
+    }
+    Ok(players)
+}
+
+fn player(connection: &Connection, name: &str) -> anyhow::Result<MediaState> {
+    let player = proxy(connection, name, "org.mpris.MediaPlayer2.Player")?;
+    let status: String = player.get_property("PlaybackStatus")?;
+    let metadata: HashMap<String, OwnedValue> = player.get_property("Metadata").unwrap_or_default();
+
+    // Identity 是可读名称，没有时使用总线名，例如 org.mpris.MediaPlayer2.vlc.instance123 → vlc
+    let identity = proxy(connection, name, "org.mpris.MediaPlayer2")?
+        .get_property::<String>("Identity")
+        .ok()
+        .filter(|i| !i.is_empty());
+    let player = identity.unwrap_or_else(|| {
+        let suffix = name.trim_start_matches(BUS_PREFIX);
+        suffix
+            .split(".instance")
+            .next()
+            .unwrap_or(suffix)
+            .to_string()
+    });
+
+    Ok(MediaState {
+        player,
+        status: PlaybackStatus::parse(&status),
+        title: string(&metadata, "xesam:title"),
+        artist: metadata
+            .get("xesam:artist")
+            .and_then(|v| Vec::<String>::try_from(v.try_clone().ok()?).ok())
+            .filter(|a| !a.is_empty())
+            .map(|a| a.join(", ")),
+        album: string(&metadata, "xesam:album"),
+        url: string(&metadata, "xesam:url"),
+    })
+}
+
+fn string(metadata: &HashMap<String, OwnedValue>, key: &str) -> Option<String> {
+    metadata
+        .get(key)
+        .and_then(|v| String::try_from(v.try_clone().ok()?).ok())
+        .filter(|s| !s.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::{BufRead, BufReader};
+    use std::process::{Command, Stdio};
+    use zbus::zvariant::Value;
+
+    struct MockPlayer;
+
+    #[zbus::interface(name = "org.mpris.MediaPlayer2")]
+    impl MockPlayer {
+        #[zbus(property)]
+        fn identity(&self) -> String {
+            "Mock Player".to_string()
+        }
+    }
+
+    struct MockPlayback;
+
+    #[zbus::interface(name = "org.mpris.MediaPlayer2.Player")]
+    impl MockPlayback {
+        #[zbus(property)]
+        fn playback_status(&self) -> String {
+            "Playing".to_string()
+        }
+
+        #[zbus(property)]
+        fn metadata(&self) -> HashMap<String, OwnedValue> {
+            HashMap::from([
+                (
+                    "xesam:title".to_string(),
+                    OwnedValue::try_from(Value::from("Episode 12")).unwrap(),
+                ),
+                (
+                    "xesam:artist".to_string(),
+                    OwnedValue::try_from(Value::from(vec!["Host A", "Host B"])).unwrap(),
+                ),
+            ])
+        }
+    }
+
+    #[test]
+    #[ignore = "需要 dbus-daemon，使用 cargo test -- --ignored 运行"]
+    fn test_players_on_private_bus() {
+        // 在私有总线上测试，不影响桌面会话
+        let mut daemon = Command::new("dbus-daemon")
+            .args(["--session", "--nofork", "--print-address"])
+            .stdout(Stdio::piped())
+            .spawn()
+            .expect("dbus-daemon not found");
+        let mut address = String::new();
+        BufReader::new(daemon.stdout.take().unwrap())
+            .read_line(&mut address)
+            .unwrap();
+
+        let result = (|| -> anyhow::Result<Vec<MediaState>> {
+            let _player = zbus::blocking::connection::Builder::address(address.trim())?
+                .name("org.mpris.MediaPlayer2.mock.instance42")?
+                .serve_at(OBJECT_PATH, MockPlayer)?
+                .serve_at(OBJECT_PATH, MockPlayback)?
+                .build()?;
+            let client = zbus::blocking::connection::Builder::address(address.trim())?.build()?;
+            players(&client)
+        })();
+        let _ = daemon.kill();
+        let _ = daemon.wait();
+
+        assert_eq!(
+            result.unwrap(),
+            [MediaState {
+                player: "Mock Player".to_string(),
+                status: PlaybackStatus::Playing,
+                title: Some("Episode 12".to_string()),
+                artist: Some("Host A, Host B".to_string()),
+                album: None,
+                url: None,
+            }]
+        );
+    }
+}
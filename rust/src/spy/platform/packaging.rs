@@ -0,0 +1,334 @@
+//! Flatpak、Snap、AppImage 打包格式识别
+//!
+//! 这些应用的 `/proc/<pid>/exe` 指向 `/app/...`、`/snap/<name>/<revision>/...`
+//! 或每次启动都会变化的 AppImage 挂载目录，需要换成稳定的应用 id 和路径。
+//! `SNAP_NAME`、`APPIMAGE` 等环境变量会被从这些应用启动的子进程继承，
+//! 只有可执行文件确实在包内时才采信。
+
+use std::path::Path;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PackageKind {
+    Flatpak,
+    Snap,
+    AppImage,
+}
+
+impl PackageKind {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            PackageKind::Flatpak => "flatpak",
+            PackageKind::Snap => "snap",
+            PackageKind::AppImage => "appimage",
+        }
+    }
+}
+
+/// 识别出的打包信息
+#[derive(Debug, Clone, PartialEq)]
+pub struct PackageInfo {
+    pub kind: PackageKind,
+    pub id: String,                 // Flatpak 应用 id、Snap 名称或 AppImage 名称
+    pub version: Option<String>,    // 应用版本，Flatpak 取不到时为分支名
+    pub path: Option<String>,       // 跨启动稳定的路径
+    pub desktop_id: Option<String>, // 启动器提示的 desktop-file id
+}
+
+/// 识别进程的打包格式，依次检查 Flatpak、Snap、AppImage
+pub fn detect(pid: u32, exe_path: &str, environ: &[(String, String)]) -> Option<PackageInfo> {
+    let cgroup = std::fs::read_to_string(format!("/proc/{}/cgroup", pid)).unwrap_or_default();
+    detect_in(
+        Path::new(&format!("/proc/{}/root", pid)),
+        &cgroup,
+        exe_path,
+        environ,
+    )
+}
+
+/// 按进程的根目录（`/proc/<pid>/root`）和 cgroup 内容识别打包格式
+pub fn detect_in(
+    root: &Path,
+    cgroup: &str,
+    exe_path: &str,
+    environ: &[(String, String)],
+) -> Option<PackageInfo> {
+    let env = |key: &str| {
+        environ
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.clone())
+            .filter(|v| !v.is_empty())
+    };
+    // 启动器通过环境变量告知 desktop 文件位置
+    let desktop_id = env("BAMF_DESKTOP_FILE_HINT")
+        .or_else(|| env("GIO_LAUNCHED_DESKTOP_FILE"))
+        .and_then(|p| {
+            Path::new(&p)
+                .file_stem()
+                .map(|s| s.to_string_lossy().into_owned())
+        });
+
+    if let Some(info) = flatpak(root, cgroup) {
+        return Some(PackageInfo {
+            desktop_id: desktop_id.or_else(|| Some(info.id.clone())),
+            ..info
+        });
+    }
+    // snap 应用运行在 snap.<name>.* scope 中；不在时（例如 cgroup 不可读）要求可执行文件在 /snap/<name>/ 下
+    let snap = snap_name_from_cgroup(cgroup).or_else(|| {
+        env("SNAP_NAME").filter(|name| exe_path.starts_with(&format!("/snap/{}/", name)))
+    });
+    if let Some(name) = snap {
+        return Some(PackageInfo {
+            kind: PackageKind::Snap,
+            version: env("SNAP_VERSION").filter(|_| env("SNAP_NAME").as_ref() == Some(&name)),
+            path: snap_stable_path(exe_path, &name),
+            id: name,
+            desktop_id,
+        });
+    }
+    // AppImage 运行时把镜像挂载到 $APPDIR，包内的程序才在这个目录下
+    let in_appdir = env("APPDIR").is_some_and(|dir| {
+        Path::new(exe_path).starts_with(&dir) && Path::new(exe_path) != Path::new(&dir)
+    });
+    if let Some(image) = env("APPIMAGE").filter(|_| in_appdir) {
+        let stem = Path::new(&image)
+            .file_stem()
+            .map(|s| s.to_string_lossy().into_owned())
+            .unwrap_or_else(|| image.clone());
+        let (id, version) = appimage_name_version(&stem);
+        return Some(PackageInfo {
+            kind: PackageKind::AppImage,
+            id,
+            version,
+            path: Some(image),
+            desktop_id,
+        });
+    }
+    None
+}
+
+fn flatpak(root: &Path, cgroup: &str) -> Option<PackageInfo> {
+    // 其他用户的进程无法读取 root，只能从 cgroup 中取得 id
+    let (id, branch) = match std::fs::read_to_string(root.join(".flatpak-info")) {
+        Ok(content) => parse_flatpak_info(&content)?,
+        Err(_) => (flatpak_id_from_cgroup(cgroup)?, None),
+    };
+    let version = ["metainfo", "appdata"]
+        .iter()
+        .flat_map(|dir| {
+            [
+                root.join(format!("app/share/{}/{}.metainfo.xml", dir, id)),
+                root.join(format!("app/share/{}/{}.appdata.xml", dir, id)),
+            ]
+        })
+        .find_map(|path| release_version(&std::fs::read_to_string(path).ok()?))
+        .or(branch);
+
+    // 沙箱内的 /app/... 路径本身是稳定的
+    Some(PackageInfo {
+        kind: PackageKind::Flatpak,
+        path: None,
+        id,
+        version,
+        desktop_id: None,
+    })
+}
+
+/// 解析 `.flatpak-info`，返回 (应用 id, 分支)
+pub fn parse_flatpak_info(content: &str) -> Option<(String, Option<String>)> {
+    let mut section = "";
+    let mut id = None;
+    let mut branch = None;
+    for line in content.lines() {
+        let line = line.trim();
+        if line.starts_with('[') {
+            section = line;
+            continue;
+        }
+        let Some((key, value)) = line.split_once('=') else {
+            continue;
+        };
+        match (section, key.trim()) {
+            ("[Application]", "name") => id = Some(value.trim().to_string()),
+            ("[Instance]", "branch") => branch = Some(value.trim().to_string()),
+            _ => {}
+        }
+    }
+    Some((id?, branch))
+}
+
+/// `app-flatpak-org.gnome.Builder-12345.scope` → `org.gnome.Builder`
+pub fn flatpak_id_from_cgroup(cgroup: &str) -> Option<String> {
+    cgroup.lines().find_map(|line| {
+        let unit = line.rsplit('/').next()?;
+        let rest = unit.strip_prefix("app-flatpak-")?.strip_suffix(".scope")?;
+        let (id, _) = rest.rsplit_once('-')?;
+        Some(id.to_string())
+    })
+}
+
+/// `snap.firefox.firefox-<uuid>.scope` → `firefox`
+pub fn snap_name_from_cgroup(cgroup: &str) -> Option<String> {
+    cgroup.lines().find_map(|line| {
+        let unit = line.rsplit('/').next()?;
+        let rest = unit.strip_prefix("snap.")?;
+        let (name, _) = rest.split_once('.')?;
+        Some(name.to_string())
+    })
+}
+
+/// `/snap/firefox/4173/usr/lib/firefox/firefox` → `/snap/firefox/current/usr/lib/firefox/firefox`
+pub fn snap_stable_path(exe_path: &str, name: &str) -> Option<String> {
+    let rest = exe_path.strip_prefix(&format!("/snap/{}/", name))?;
+    let (_, inner) = rest.split_once('/')?;
+    Some(format!("/snap/{}/current/{}", name, inner))
+}
+
+/// `Obsidian-1.5.3-x86_64` → (`Obsidian`, `1.5.3`)
+pub fn appimage_name_version(stem: &str) -> (String, Option<String>) {
+    let parts: Vec<&str> = stem.split(['-', '_']).collect();
+    match parts
+        .iter()
+        .position(|p| p.starts_with(|c: char| c.is_ascii_digit()) && p.contains('.'))
+    {
+        Some(i) if i > 0 => (parts[..i].join("-"), Some(parts[i].to_string())),
+        _ => {
+            // 去掉架构后缀
+            let name = stem
+                .trim_end_matches("-x86_64")
+                .trim_end_matches("-aarch64")
+                .trim_end_matches(".x86_64");
+            (name.to_string(), None)
+        }
+    }
+}
+
+/// AppStream 元数据中第一个 `<release version="...">`
+fn release_version(xml: &str) -> Option<String> {
+    let release = &xml[xml.find("<release ")?..];
+    let release = &release[..release.find('>')?];
+    let value = &release[release.find("version=")? + "version=".len()..];
+    let quote = value.chars().next()?;
+    let value = &value[quote.len_utf8()..];
+    Some(value[..value.find(quote)?].to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_flatpak() {
+        let info = "[Application]\nname=org.gnome.Builder\nruntime=runtime/org.gnome.Platform/x86_64/45\n\n\
+                    [Instance]\ninstance-id=123\nbranch=stable\napp-commit=abc\n";
+        assert_eq!(
+            parse_flatpak_info(info),
+            Some(("org.gnome.Builder".to_string(), Some("stable".to_string())))
+        );
+
+        let cgroup = "0::/user.slice/user-1000.slice/user@1000.service/app.slice/app-flatpak-org.gnome.Builder-12345.scope\n";
+        assert_eq!(
+            flatpak_id_from_cgroup(cgroup).as_deref(),
+            Some("org.gnome.Builder")
+        );
+
+        let xml = r#"<component><releases><release version="45.0" date="2023-09-15"/><release version="44.2"/></releases></component>"#;
+        assert_eq!(release_version(xml).as_deref(), Some("45.0"));
+        // 引号是多字节字符时不能按字节跳过
+        assert_eq!(
+            release_version("<release version=”45.1”>").as_deref(),
+            Some("45.1")
+        );
+
+        // 用临时目录代替 /proc/<pid>/root，不依赖运行测试的环境
+        let root = std::env::temp_dir().join(format!("spy-flatpak-{}", std::process::id()));
+        let metainfo = root.join("app/share/metainfo");
+        std::fs::create_dir_all(&metainfo).unwrap();
+        std::fs::write(root.join(".flatpak-info"), info).unwrap();
+        std::fs::write(metainfo.join("org.gnome.Builder.metainfo.xml"), xml).unwrap();
+        let detected = detect_in(&root, cgroup, "/app/bin/gnome-builder", &[]).unwrap();
+        let _ = std::fs::remove_dir_all(&root);
+        assert_eq!(detected.kind, PackageKind::Flatpak);
+        assert_eq!(detected.id, "org.gnome.Builder");
+        assert_eq!(detected.version.as_deref(), Some("45.0"));
+        assert_eq!(detected.desktop_id.as_deref(), Some("org.gnome.Builder"));
+    }
+
+    #[test]
+    fn test_snap() {
+        let cgroup = "0::/user.slice/user-1000.slice/user@1000.service/app.slice/snap.firefox.firefox-1f2e.scope\n";
+        assert_eq!(snap_name_from_cgroup(cgroup).as_deref(), Some("firefox"));
+        assert_eq!(snap_name_from_cgroup("0::/init.scope"), None);
+        assert_eq!(
+            snap_stable_path("/snap/firefox/4173/usr/lib/firefox/firefox", "firefox").as_deref(),
+            Some("/snap/firefox/current/usr/lib/firefox/firefox")
+        );
+
+        // 从 snap 应用的终端启动的程序继承了 SNAP_NAME
+        let environ = vec![
+            ("SNAP_NAME".to_string(), "code".to_string()),
+            ("SNAP_VERSION".to_string(), "1.85".to_string()),
+        ];
+        let root = std::env::temp_dir().join(format!("spy-snap-{}", std::process::id()));
+        let session = "0::/user.slice/user-1000.slice/session-2.scope\n";
+        assert_eq!(detect_in(&root, session, "/usr/bin/git", &environ), None);
+        let info = detect_in(
+            &root,
+            session,
+            "/snap/code/180/usr/share/code/code",
+            &environ,
+        )
+        .unwrap();
+        assert_eq!(info.id, "code");
+        assert_eq!(info.version.as_deref(), Some("1.85"));
+        // cgroup 中的 scope 优先，继承的版本号不属于这个 snap
+        let info = detect_in(
+            &root,
+            cgroup,
+            "/snap/firefox/4173/usr/lib/firefox/firefox",
+            &environ,
+        )
+        .unwrap();
+        assert_eq!((info.id.as_str(), info.version), ("firefox", None));
+    }
+
+    #[test]
+    fn test_appimage() {
+        assert_eq!(
+            appimage_name_version("Obsidian-1.5.3-x86_64"),
+            ("Obsidian".to_string(), Some("1.5.3".to_string()))
+        );
+        assert_eq!(
+            appimage_name_version("balena-etcher_1.18.11_x64"),
+            ("balena-etcher".to_string(), Some("1.18.11".to_string()))
+        );
+        assert_eq!(
+            appimage_name_version("Joplin-x86_64"),
+            ("Joplin".to_string(), None)
+        );
+
+        let environ = vec![
+            (
+                "APPIMAGE".to_string(),
+                "/home/u/Apps/Obsidian-1.5.3.AppImage".to_string(),
+            ),
+            ("APPDIR".to_string(), "/tmp/.mount_ObsidXY".to_string()),
+        ];
+        let root = std::env::temp_dir().join(format!("spy-appimage-{}", std::process::id()));
+        let session = "0::/user.slice/user-1000.slice/session-2.scope\n";
+        // 从 AppImage 中启动的外部程序继承了 APPIMAGE 和 APPDIR
+        assert_eq!(detect_in(&root, session, "/usr/bin/git", &environ), None);
+        assert_eq!(
+            detect_in(&root, session, "/tmp/.mount_ObsidXYZ/obsidian", &environ),
+            None
+        );
+        let info = detect_in(&root, session, "/tmp/.mount_ObsidXY/obsidian", &environ).unwrap();
+        assert_eq!(info.kind, PackageKind::AppImage);
+        assert_eq!(info.id, "Obsidian");
+        assert_eq!(
+            info.path.as_deref(),
+            Some("/home/u/Apps/Obsidian-1.5.3.AppImage")
+        );
+    }
+}
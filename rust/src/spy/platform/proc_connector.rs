@@ -0,0 +1,204 @@
+//! Linux netlink proc connector，实时接收进程 exec / exit 通知
+//!
+//! 订阅需要 CAP_NET_ADMIN，没有权限时 `ProcConnector::open` 返回错误，调用方改用 `/proc` 轮询。
+
+use std::io;
+use std::mem::size_of;
+use std::time::Duration;
+
+const NETLINK_CONNECTOR: libc::c_int = 11;
+const CN_IDX_PROC: u32 = 1;
+const CN_VAL_PROC: u32 = 1;
+const PROC_CN_MCAST_LISTEN: u32 = 1;
+
+const PROC_EVENT_EXEC: u32 = 0x0000_0002;
+const PROC_EVENT_EXIT: u32 = 0x8000_0000;
+
+const NLMSG_HDR_LEN: usize = 16;
+const CN_MSG_LEN: usize = 20;
+const PROC_EVENT_HDR_LEN: usize = 16;
+
+/// 进程事件，只报告进程（线程组 leader），不报告线程
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum ProcEvent {
+    Exec(u32),
+    Exit(u32),
+}
+
+pub struct ProcConnector {
+    fd: libc::c_int,
+}
+
+impl ProcConnector {
+    /// 打开 netlink socket 并订阅进程事件，timeout 为每次 recv 的最长等待时间
+    pub fn open(timeout: Duration) -> io::Result<ProcConnector> {
+        unsafe {
+            let fd = libc::socket(
+                libc::AF_NETLINK,
+                libc::SOCK_DGRAM | libc::SOCK_CLOEXEC,
+                NETLINK_CONNECTOR,
+            );
+            if fd < 0 {
+                return Err(io::Error::last_os_error());
+            }
+            let connector = ProcConnector { fd };
+
+            let mut addr: libc::sockaddr_nl = std::mem::zeroed();
+            addr.nl_family = libc::AF_NETLINK as libc::sa_family_t;
+            addr.nl_pid = 0; // 由内核分配
+            addr.nl_groups = CN_IDX_PROC;
+            if libc::bind(
+                fd,
+                &addr as *const libc::sockaddr_nl as *const libc::sockaddr,
+                size_of::<libc::sockaddr_nl>() as libc::socklen_t,
+            ) < 0
+            {
+                return Err(io::Error::last_os_error());
+            }
+
+            let tv = libc::timeval {
+                tv_sec: timeout.as_secs() as libc::time_t,
+                tv_usec: timeout.subsec_micros() as libc::suseconds_t,
+            };
+            libc::setsockopt(
+                fd,
+                libc::SOL_SOCKET,
+                libc::SO_RCVTIMEO,
+                &tv as *const libc::timeval as *const libc::c_void,
+                size_of::<libc::timeval>() as libc::socklen_t,
+            );
+
+            connector.send_listen()?;
+            Ok(connector)
+        }
+    }
+
+    /// 发送 PROC_CN_MCAST_LISTEN 开始接收事件
+    fn send_listen(&self) -> io::Result<()> {
+        let total = NLMSG_HDR_LEN + CN_MSG_LEN + 4;
+        let mut buf = Vec::with_capacity(total);
+        // nlmsghdr
+        buf.extend_from_slice(&(total as u32).to_ne_bytes());
+        buf.extend_from_slice(&(libc::NLMSG_DONE as u16).to_ne_bytes());
+        buf.extend_from_slice(&0u16.to_ne_bytes());
+        buf.extend_from_slice(&0u32.to_ne_bytes());
+        buf.extend_from_slice(&std::process::id().to_ne_bytes());
+        // cn_msg
+        buf.extend_from_slice(&CN_IDX_PROC.to_ne_bytes());
+        buf.extend_from_slice(&CN_VAL_PROC.to_ne_bytes());
+        buf.extend_from_slice(&0u32.to_ne_bytes());
+        buf.extend_from_slice(&0u32.to_ne_bytes());
+        buf.extend_from_slice(&4u16.to_ne_bytes());
+        buf.extend_from_slice(&0u16.to_ne_bytes());
+        buf.extend_from_slice(&PROC_CN_MCAST_LISTEN.to_ne_bytes());
+
+        let sent =
+            unsafe { libc::send(self.fd, buf.as_ptr() as *const libc::c_void, buf.len(), 0) };
+        if sent < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+
+    /// 接收一批事件，超时返回空列表
+    pub fn recv(&self) -> io::Result<Vec<ProcEvent>> {
+        let mut buf = [0u8; 4096];
+        let len =
+            unsafe { libc::recv(self.fd, buf.as_mut_ptr() as *mut libc::c_void, buf.len(), 0) };
+        if len < 0 {
+            let e = io::Error::last_os_error();
+            return match e.kind() {
+                io::ErrorKind::WouldBlock
+                | io::ErrorKind::TimedOut
+                | io::ErrorKind::Interrupted => Ok(Vec::new()),
+                _ => Err(e),
+            };
+        }
+        Ok(parse_messages(&buf[..len as usize]))
+    }
+}
+
+impl Drop for ProcConnector {
+    fn drop(&mut self) {
+        unsafe {
+            libc::close(self.fd);
+        }
+    }
+}
+
+fn u32_at(buf: &[u8], offset: usize) -> Option<u32> {
+    Some(u32::from_ne_bytes(
+        buf.get(offset..offset + 4)?.try_into().ok()?,
+    ))
+}
+
+/// 解析一个数据报中的所有 netlink 消息
+pub fn parse_messages(buf: &[u8]) -> Vec<ProcEvent> {
+    let mut events = Vec::new();
+    let mut offset = 0;
+    while let Some(len) = u32_at(buf, offset) {
+        let len = len as usize;
+        if len < NLMSG_HDR_LEN || offset + len > buf.len() {
+            break;
+        }
+        // NLMSG_NOOP、NLMSG_DONE 等只有消息头，没有 cn_msg，跳过
+        if let Some(event) = buf.get(offset + NLMSG_HDR_LEN + CN_MSG_LEN..offset + len) {
+            if let Some(e) = parse_event(event) {
+                events.push(e);
+            }
+        }
+        // netlink 消息按 4 字节对齐
+        offset += (len + 3) & !3;
+    }
+    events
+}
+
+/// 解析 proc_event：what、cpu、timestamp_ns 之后是 (pid, tgid)
+fn parse_event(event: &[u8]) -> Option<ProcEvent> {
+    let what = u32_at(event, 0)?;
+    let pid = u32_at(event, PROC_EVENT_HDR_LEN)?;
+    let tgid = u32_at(event, PROC_EVENT_HDR_LEN + 4)?;
+    if pid != tgid {
+        return None;
+    }
+    match what {
+        PROC_EVENT_EXEC => Some(ProcEvent::Exec(tgid)),
+        PROC_EVENT_EXIT => Some(ProcEvent::Exit(tgid)),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn message(what: u32, pid: u32, tgid: u32) -> Vec<u8> {
+        let len = NLMSG_HDR_LEN + CN_MSG_LEN + PROC_EVENT_HDR_LEN + 8;
+        let mut buf = vec![0u8; len];
+        buf[0..4].copy_from_slice(&(len as u32).to_ne_bytes());
+        let event = NLMSG_HDR_LEN + CN_MSG_LEN;
+        buf[event..event + 4].copy_from_slice(&what.to_ne_bytes());
+        buf[event + 16..event + 20].copy_from_slice(&pid.to_ne_bytes());
+        buf[event + 20..event + 24].copy_from_slice(&tgid.to_ne_bytes());
+        buf
+    }
+
+    #[test]
+    fn test_parse_messages() {
+        let mut buf = message(PROC_EVENT_EXEC, 42, 42);
+        buf.extend(message(PROC_EVENT_EXIT, 43, 42)); // 线程退出
+        buf.extend(message(PROC_EVENT_EXIT, 42, 42));
+        buf.extend(message(1, 44, 44)); // fork
+        assert_eq!(
+            parse_messages(&buf),
+            [ProcEvent::Exec(42), ProcEvent::Exit(42)]
+        );
+        assert!(parse_messages(&buf[..10]).is_empty());
+
+        // 只有 16 字节消息头的消息不会越界，后面的消息照常解析
+        let mut buf = vec![0u8; NLMSG_HDR_LEN];
+        buf[0..4].copy_from_slice(&(NLMSG_HDR_LEN as u32).to_ne_bytes());
+        buf.extend(message(PROC_EVENT_EXIT, 42, 42));
+        assert_eq!(parse_messages(&buf), [ProcEvent::Exit(42)]);
+    }
+}
@@ -2,22 +2,25 @@ use std::ffi::OsString;
 use std::fs::File;
 use std::io::Write;
 use std::os::windows::ffi::OsStringExt;
-use std::time::{SystemTime, UNIX_EPOCH};
 
-use image::{ImageBuffer, Rgba};
+use image::RgbaImage;
 use windows::Win32::Foundation::{HWND, RECT};
 use windows::Win32::Graphics::Gdi::{
-    BitBlt, CreateCompatibleBitmap, CreateCompatibleDC, CreateSolidBrush, DeleteDC, DeleteObject,
-    FillRect, GetBitmapBits, GetDC, GetObjectW, GetWindowDC, ReleaseDC, SelectObject, BITMAP,
-    BITMAPFILEHEADER, BITMAPINFOHEADER, BI_RGB, HBITMAP, SRCCOPY,
+    BitBlt, CreateCompatibleBitmap, CreateCompatibleDC, DeleteDC, DeleteObject, GetBitmapBits,
+    GetDC, GetObjectW, ReleaseDC, SelectObject, BITMAP, BITMAPFILEHEADER, BITMAPINFOHEADER, BI_RGB,
+    HBITMAP, SRCCOPY,
 };
 use windows::Win32::UI::WindowsAndMessaging::{GetWindowRect, GetWindowTextW};
 
+use crate::spy::event::{self, TrackerEvent, TrackerEventKind};
+use crate::spy::monitor;
+use crate::spy::visibility::Rect;
+
 /// Windows窗口截图工具
 pub struct WindowCapture;
 
 impl WindowCapture {
-    /// 对指定窗口进行截图并保存到指定文件夹
+    /// 按截图模式截取窗口、窗口所在显示器或所有显示器，并保存到指定文件夹
     ///
     /// # 参数
     /// - `hwnd`: 要截图的窗口句柄
@@ -36,39 +39,52 @@ impl WindowCapture {
             // 获取窗口标题用于文件命名
             let title = Self::get_window_title(hwnd)?;
 
-            // 清理文件名中的非法字符
-            let safe_title = Self::sanitize_filename(&title);
-
             // 获取窗口矩形
             let mut rect = RECT::default();
             if GetWindowRect(hwnd, &mut rect).is_err() {
                 return Err("无法获取窗口矩形".to_string());
             }
-
-            let width = rect.right - rect.left;
-            let height = rect.bottom - rect.top;
-
-            if width <= 0 || height <= 0 {
+            let window = Rect {
+                x: rect.left,
+                y: rect.top,
+                width: rect.right - rect.left,
+                height: rect.bottom - rect.top,
+            };
+            if window.width <= 0 || window.height <= 0 {
                 return Err("窗口尺寸无效".to_string());
             }
 
-            // 创建位图并进行截图
-            let hbitmap = Self::create_window_bitmap(hwnd, &rect, width, height)?;
-
-            // 确保文件夹存在
-            if let Err(e) = std::fs::create_dir_all(folder_path) {
-                return Err(format!("无法创建文件夹: {}", e));
+            // 跨显示器的窗口按显示器分别复制，显示器之间的空隙留空
+            let monitors = super::monitors();
+            let (canvas, regions) =
+                monitor::capture_regions(monitor::capture_mode(), &window, &monitors)
+                    .ok_or("窗口不在任何显示器上")?;
+            let mut errors = Vec::new();
+            let image = monitor::composite(&canvas, &regions, |r| match Self::grab_screen(r) {
+                Ok(image) => Some(image),
+                Err(e) => {
+                    errors.push(e);
+                    None
+                }
+            });
+            // 全部失败时由调用方发出事件，部分区域失败时在这里发出，其余区域照常拼接
+            let Some(image) = image else {
+                return Err(if errors.is_empty() {
+                    "截图失败".to_string()
+                } else {
+                    errors.join("; ")
+                });
+            };
+            for e in errors {
+                event::emit(TrackerEvent::failed(
+                    TrackerEventKind::ScreenshotFailed,
+                    "windows",
+                    format!("截取屏幕区域失败: {}", e),
+                ));
             }
 
-            // 生成文件名（带时间戳避免重复）
-            let filename = Self::generate_filename(&safe_title);
-            let file_path = std::path::Path::new(folder_path).join(&filename);
-            let full_path = file_path.to_string_lossy().to_string();
-
-            // 保存为PNG
-            Self::save_bitmap_to_png(hbitmap, width, height, &full_path)?;
-
-            Ok(full_path)
+            monitor::save_capture(&image, folder_path, &title)
+                .map_err(|e| format!("保存PNG文件失败: {}", e))
         }
     }
 
@@ -86,78 +102,37 @@ impl WindowCapture {
         }
     }
 
-    /// 清理文件名中的非法字符
-    fn sanitize_filename(filename: &str) -> String {
-        filename
-            .replace(['<', '>', ':', '"', '/', '\\', '|', '?', '*'], "_")
-            .trim()
-            .to_string()
-    }
-
-    /// 生成带时间戳的文件名
-    fn generate_filename(safe_title: &str) -> String {
-        let timestamp = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .as_secs();
-
-        format!("{}_{}.png", safe_title, timestamp)
-    }
-
-    /// 创建窗口的位图
-    unsafe fn create_window_bitmap(
-        hwnd: HWND,
-        rect: &RECT,
-        width: i32,
-        height: i32,
-    ) -> Result<HBITMAP, String> {
-        // 创建设备上下文
-        let hdc_window = GetWindowDC(Some(hwnd));
-        let hdc_mem = CreateCompatibleDC(Some(hdc_window));
-        let hbitmap = CreateCompatibleBitmap(hdc_window, width, height);
-        let old_bitmap = SelectObject(hdc_mem, hbitmap.into());
-
-        // 先填充白色背景，避免黑色
-        let white_brush = CreateSolidBrush(windows::Win32::Foundation::COLORREF(0x00FFFFFF));
-        let rect_fill = RECT {
-            left: 0,
-            top: 0,
-            right: width,
-            bottom: height,
-        };
-        FillRect(hdc_mem, &rect_fill, white_brush);
-        let _ = DeleteObject(white_brush.into());
-
-        // 使用屏幕DC进行截图
+    /// 从屏幕 DC 复制虚拟屏幕坐标系中的一块区域
+    unsafe fn grab_screen(rect: &Rect) -> Result<RgbaImage, String> {
         let hdc_screen = GetDC(None);
-        let screen_x = rect.left;
-        let screen_y = rect.top;
+        let hdc_mem = CreateCompatibleDC(Some(hdc_screen));
+        let hbitmap = CreateCompatibleBitmap(hdc_screen, rect.width, rect.height);
+        let old_bitmap = SelectObject(hdc_mem, hbitmap.into());
 
         let blt_result = BitBlt(
             hdc_mem,
             0,
             0,
-            width,
-            height,
+            rect.width,
+            rect.height,
             Some(hdc_screen),
-            screen_x,
-            screen_y,
+            rect.x,
+            rect.y,
             SRCCOPY,
         );
 
-        ReleaseDC(None, hdc_screen);
-
         // 清理资源
         SelectObject(hdc_mem, old_bitmap);
-        ReleaseDC(Some(hwnd), hdc_window);
+        ReleaseDC(None, hdc_screen);
         let _ = DeleteDC(hdc_mem);
 
-        if blt_result.is_err() {
-            let _ = DeleteObject(hbitmap.into());
+        let result = if blt_result.is_err() {
             Err("BitBlt操作失败".to_string())
         } else {
-            Ok(hbitmap)
-        }
+            Self::bitmap_to_image(hbitmap, rect.width, rect.height)
+        };
+        let _ = DeleteObject(hbitmap.into());
+        result
     }
 
     /// 将位图保存为BMP文件
@@ -245,13 +220,12 @@ impl WindowCapture {
         Ok(())
     }
 
-    /// 将位图保存为PNG文件
-    unsafe fn save_bitmap_to_png(
+    /// 将位图转换为 RGBA 图像
+    unsafe fn bitmap_to_image(
         hbitmap: HBITMAP,
         width: i32,
         height: i32,
-        path: &str,
-    ) -> Result<(), String> {
+    ) -> Result<RgbaImage, String> {
         use std::ffi::c_void;
 
         let mut bmp: BITMAP = std::mem::zeroed();
@@ -276,40 +250,23 @@ impl WindowCapture {
             return Err("获取位图数据失败".to_string());
         }
 
-        // 转换BGRA到RGBA格式
+        // 转换BGRA到RGBA格式，屏幕位图的 alpha 通道无意义
         let mut rgba_buffer = Vec::with_capacity((width * height * 4) as usize);
-
         for y in 0..height {
             for x in 0..width {
-                let idx = ((y * width + x) * 4) as usize;
+                let idx = (y * bmp.bmWidthBytes + x * 4) as usize;
                 if idx + 3 < buffer.len() {
-                    let b = buffer[idx];
-                    let g = buffer[idx + 1];
-                    let r = buffer[idx + 2];
-                    let a = buffer[idx + 3];
-
-                    // BGRA -> RGBA
-                    rgba_buffer.push(r);
-                    rgba_buffer.push(g);
-                    rgba_buffer.push(b);
-                    rgba_buffer.push(a);
+                    rgba_buffer.extend_from_slice(&[
+                        buffer[idx + 2],
+                        buffer[idx + 1],
+                        buffer[idx],
+                        255,
+                    ]);
                 }
             }
         }
 
-        // 创建图像缓冲区
-        let img_buffer =
-            ImageBuffer::<Rgba<u8>, Vec<u8>>::from_raw(width as u32, height as u32, rgba_buffer)
-                .ok_or("创建图像缓冲区失败")?;
-
-        // 保存为PNG
-        img_buffer
-            .save(path)
-            .map_err(|e| format!("保存PNG文件失败: {}", e))?;
-
-        // 清理位图资源
-        let _ = DeleteObject(hbitmap.into());
-
-        Ok(())
+        RgbaImage::from_raw(width as u32, height as u32, rgba_buffer)
+            .ok_or_else(|| "创建图像缓冲区失败".to_string())
     }
 }
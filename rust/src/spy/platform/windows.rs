@@ -1,30 +1,40 @@
 use std::ffi::OsString;
 use std::os::windows::ffi::OsStringExt;
-use windows::Win32::Foundation::{HWND, LPARAM, WPARAM};
+use windows::core::BOOL;
+use windows::Win32::Foundation::{HWND, LPARAM, RECT, WPARAM};
+use windows::Win32::Graphics::Dwm::{DwmGetWindowAttribute, DWMWA_CLOAKED};
 use windows::Win32::Graphics::Gdi::{
-    CreateCompatibleDC, CreateDIBSection, DeleteDC, DeleteObject, GetDC, ReleaseDC, SelectObject,
-    BITMAPINFO, BITMAPINFOHEADER, BI_RGB, DIB_RGB_COLORS,
+    CreateCompatibleDC, CreateDIBSection, DeleteDC, DeleteObject, EnumDisplayMonitors, GetDC,
+    GetMonitorInfoW, ReleaseDC, SelectObject, BITMAPINFO, BITMAPINFOHEADER, BI_RGB, DIB_RGB_COLORS,
+    HDC, HMONITOR, MONITORINFOEXW,
 };
 use windows::Win32::System::ProcessStatus::K32GetModuleFileNameExW;
 use windows::Win32::System::Threading::{OpenProcess, PROCESS_QUERY_INFORMATION, PROCESS_VM_READ};
 use windows::Win32::UI::WindowsAndMessaging::{
-    DrawIconEx, GetClassLongPtrW, GetIconInfo, GetWindowTextW, GetWindowThreadProcessId,
-    SendMessageW, DI_NORMAL, GCLP_HICON, HICON, ICONINFO, ICON_BIG, WM_GETICON,
+    DrawIconEx, EnumWindows, GetClassLongPtrW, GetForegroundWindow, GetIconInfo, GetWindow,
+    GetWindowLongW, GetWindowRect, GetWindowTextLengthW, GetWindowTextW, GetWindowThreadProcessId,
+    IsIconic, IsWindowVisible, IsZoomed, SendMessageW, DI_NORMAL, GCLP_HICON, GWL_EXSTYLE,
+    GW_OWNER, HICON, ICONINFO, ICON_BIG, MONITORINFOF_PRIMARY, WM_GETICON, WS_EX_TOOLWINDOW,
+    WS_EX_TOPMOST,
 };
 
 use crate::spy::model::Application;
 use crate::spy::model::ApplicationProvider;
+use crate::spy::model::WindowInfo;
+use crate::spy::monitor::Monitor;
 use crate::spy::platform::WindowCapture;
+use crate::spy::visibility::Rect;
+use crate::spy::window_state::{self, WindowState};
 
 impl ApplicationProvider for Application {
     fn from_process(hwnd: HWND) -> Option<Application> {
-        Self::from_hwnd(hwnd)
+        Self::from_hwnd(hwnd, true)
     }
 }
 
 impl Application {
-    /// 从 HWND 创建 Application 实例
-    pub fn from_hwnd(hwnd: HWND) -> Option<Application> {
+    /// 从 HWND 创建 Application 实例，capture 为 false 时不截图
+    pub fn from_hwnd(hwnd: HWND, capture: bool) -> Option<Application> {
         unsafe {
             // 检查窗口句柄是否有效
             if hwnd.0 == std::ptr::null_mut() {
@@ -47,7 +57,7 @@ impl Application {
             GetWindowThreadProcessId(hwnd, Some(&mut pid));
 
             // 获取进程的可执行文件路径
-            let path = if let Ok(proc_handle) =
+            let exe_path = if let Ok(proc_handle) =
                 OpenProcess(PROCESS_QUERY_INFORMATION | PROCESS_VM_READ, false, pid)
             {
                 let mut path_buf = [0u16; 260];
@@ -63,8 +73,17 @@ impl Application {
                 String::new()
             };
 
+            // java、python 等运行时从命令行中找出真正的应用
+            let runtime = crate::spy::runtime::resolve(&exe_path, Some(pid));
+            let path = runtime
+                .as_ref()
+                .and_then(|r| r.target.clone())
+                .unwrap_or_else(|| exe_path.clone());
+
             // 从路径提取稳定的应用名称（不包含扩展名）
-            let name = if !path.is_empty() {
+            let name = if let Some(r) = &runtime {
+                r.name.clone()
+            } else if !path.is_empty() {
                 if let Some(file_stem) = std::path::Path::new(&path).file_stem() {
                     file_stem.to_string_lossy().into_owned()
                 } else {
@@ -76,14 +95,15 @@ impl Application {
                 Self::extract_app_name_from_title(&title)
             };
 
-            // 获取应用图标并转换为base64
-            let icon = Self::get_window_icon_base64(hwnd);
+            // 获取应用图标，同一可执行文件只提取一次
+            let icon_key = if path.is_empty() { &name } else { &path };
+            let icon = crate::spy::icon::icon_for(icon_key, || Self::get_window_icon_png(hwnd));
 
             // 只要有标题或路径中的任意一个，就创建Application
             if !title.is_empty() || !path.is_empty() {
                 let mut screen_shot_path = None;
                 {
-                    if super::is_screenshot_app(name.clone()) {
+                    if capture && super::is_screenshot_app(name.clone()) {
                         // save screenshot
                         let save_folder;
                         {
@@ -97,19 +117,62 @@ impl Application {
                             Ok(p) => {
                                 screen_shot_path = Some(p);
                             }
-                            Err(_e) => {
-                                println!("Save screenshot file error: {}", _e);
+                            Err(e) => {
+                                crate::spy::event::emit(crate::spy::event::TrackerEvent::failed(
+                                    crate::spy::event::TrackerEventKind::ScreenshotFailed,
+                                    "windows",
+                                    e,
+                                ));
                             }
                         }
                     }
                 }
 
+                // 窗口所在显示器
+                let mut rect = RECT::default();
+                let geometry = GetWindowRect(hwnd, &mut rect).is_ok().then(|| Rect {
+                    x: rect.left,
+                    y: rect.top,
+                    width: rect.right - rect.left,
+                    height: rect.bottom - rect.top,
+                });
+                let monitors = monitors();
+                let monitor = geometry
+                    .as_ref()
+                    .and_then(|r| crate::spy::monitor::monitor_for(r, &monitors))
+                    .map(|m| m.name.clone());
+
+                // 窗口状态：Windows 没有全屏标记，未最大化且覆盖整个显示器时视为全屏
+                let maximized = IsZoomed(hwnd).as_bool();
+                let state = WindowState {
+                    fullscreen: !maximized
+                        && geometry.is_some_and(|r| window_state::covers_monitor(&r, &monitors)),
+                    maximized,
+                    minimized: IsIconic(hwnd).as_bool(),
+                    above: GetWindowLongW(hwnd, GWL_EXSTYLE) as u32 & WS_EX_TOPMOST.0 != 0,
+                };
+
+                // 从窗口标题中的路径解析 git 仓库
+                let git = crate::spy::git::attribute(&name, &exe_path, &title, None);
+
                 Some(Application {
                     icon,
                     name,
                     title,
                     path,
                     screen_shot_path,
+                    repo: git.as_ref().map(|g| g.root.to_string_lossy().into_owned()),
+                    branch: git.and_then(|g| g.branch),
+                    app_id: None,
+                    exe_path,
+                    runtime: runtime.map(|r| r.runtime),
+                    package: None,
+                    version: None,
+                    process: crate::spy::process::process_info(pid),
+                    monitor,
+                    workspace: None,
+                    state: Some(state),
+                    geometry,
                 })
             } else {
                 None
@@ -117,17 +180,13 @@ impl Application {
         }
     }
 
-    #[allow(deprecated)]
-    /// 获取窗口图标并转换为base64字符串
-    unsafe fn get_window_icon_base64(hwnd: HWND) -> Option<String> {
+    /// 获取窗口图标并转换为PNG
+    unsafe fn get_window_icon_png(hwnd: HWND) -> Option<Vec<u8>> {
         // 尝试获取窗口图标
         let hicon = Self::get_window_icon(hwnd)?;
 
         // 将图标转换为位图数据
-        let bitmap_data = Self::icon_to_bitmap_data(hicon)?;
-
-        // 转换为base64
-        Some(base64::encode(bitmap_data))
+        Self::icon_to_bitmap_data(hicon)
     }
 
     /// 获取窗口图标句柄
@@ -349,3 +408,156 @@ impl Application {
         }
     }
 }
+
+/// 通过 EnumWindows 列出任务栏中可见的顶层窗口，按 Z 序从上到下
+unsafe fn top_level_windows() -> Vec<HWND> {
+    unsafe extern "system" fn collect(hwnd: HWND, lparam: LPARAM) -> BOOL {
+        let handles = &mut *(lparam.0 as *mut Vec<HWND>);
+        // 跳过不可见、无标题、被其他窗口拥有的窗口和工具窗口
+        let owned = GetWindow(hwnd, GW_OWNER).is_ok_and(|owner| !owner.is_invalid());
+        let tool = GetWindowLongW(hwnd, GWL_EXSTYLE) as u32 & WS_EX_TOOLWINDOW.0 != 0;
+        if IsWindowVisible(hwnd).as_bool() && GetWindowTextLengthW(hwnd) > 0 && !owned && !tool {
+            handles.push(hwnd);
+        }
+        BOOL(1)
+    }
+
+    let mut handles: Vec<HWND> = Vec::new();
+    if let Err(e) = EnumWindows(Some(collect), LPARAM(&mut handles as *mut _ as isize)) {
+        println!("EnumWindows error: {}", e);
+    }
+    handles
+}
+
+/// 所有顶层窗口的标题，只读取标题，开销比 list_windows 小
+pub fn window_titles() -> Vec<String> {
+    unsafe {
+        top_level_windows()
+            .into_iter()
+            .filter_map(|hwnd| {
+                let mut buf = [0u16; 512];
+                let len = GetWindowTextW(hwnd, &mut buf);
+                (len > 0).then(|| {
+                    OsString::from_wide(&buf[..len as usize])
+                        .to_string_lossy()
+                        .into_owned()
+                })
+            })
+            .collect()
+    }
+}
+
+fn window_info(hwnd: HWND, foreground: HWND) -> Option<WindowInfo> {
+    Some(WindowInfo {
+        window_id: hwnd.0 as usize as u64,
+        focused: hwnd == foreground,
+        app: Application::from_hwnd(hwnd, false)?,
+    })
+}
+
+pub fn list_windows() -> Vec<WindowInfo> {
+    unsafe {
+        let foreground = GetForegroundWindow();
+        top_level_windows()
+            .into_iter()
+            .filter_map(|hwnd| window_info(hwnd, foreground))
+            .collect()
+    }
+}
+
+/// 被 DWM 隐藏的窗口：其他虚拟桌面上的窗口、挂起的 UWP 应用等，IsWindowVisible 仍然返回 true
+fn is_cloaked(hwnd: HWND) -> bool {
+    let mut cloaked = 0u32;
+    unsafe {
+        DwmGetWindowAttribute(
+            hwnd,
+            DWMWA_CLOAKED,
+            &mut cloaked as *mut u32 as *mut _,
+            std::mem::size_of::<u32>() as u32,
+        )
+        .is_ok()
+            && cloaked != 0
+    }
+}
+
+/// 未最小化、未被 DWM 隐藏且未被上层窗口遮挡的面积足够大的窗口
+pub fn visible_windows() -> Vec<WindowInfo> {
+    use crate::spy::visibility::{is_visible, visible_ratios};
+
+    unsafe {
+        let mut candidates = Vec::new();
+        // EnumWindows 按从上到下的顺序，visible_ratios 需要从下到上
+        for hwnd in top_level_windows().into_iter().rev() {
+            if IsIconic(hwnd).as_bool() || is_cloaked(hwnd) {
+                continue;
+            }
+            let mut rect = RECT::default();
+            if GetWindowRect(hwnd, &mut rect).is_err() {
+                continue;
+            }
+            candidates.push((
+                hwnd,
+                Rect {
+                    x: rect.left,
+                    y: rect.top,
+                    width: rect.right - rect.left,
+                    height: rect.bottom - rect.top,
+                },
+            ));
+        }
+
+        // 按各个显示器计算，虚拟屏幕的外接矩形会把显示器之间的空白也算进去
+        let rects: Vec<Rect> = candidates.iter().map(|(_, r)| *r).collect();
+        let screens: Vec<Rect> = monitors().iter().map(|m| m.rect()).collect();
+        let ratios = visible_ratios(&rects, &screens);
+        let foreground = GetForegroundWindow();
+        candidates
+            .into_iter()
+            .zip(ratios)
+            .filter(|(_, ratio)| is_visible(*ratio))
+            .filter_map(|((hwnd, _), _)| window_info(hwnd, foreground))
+            .collect()
+    }
+}
+
+/// 通过 EnumDisplayMonitors 获取显示器布局
+pub fn monitors() -> Vec<Monitor> {
+    unsafe extern "system" fn collect(
+        hmonitor: HMONITOR,
+        _hdc: HDC,
+        _rect: *mut RECT,
+        lparam: LPARAM,
+    ) -> BOOL {
+        let monitors = &mut *(lparam.0 as *mut Vec<Monitor>);
+        let mut info = MONITORINFOEXW::default();
+        info.monitorInfo.cbSize = std::mem::size_of::<MONITORINFOEXW>() as u32;
+        if GetMonitorInfoW(hmonitor, &mut info.monitorInfo as *mut _).as_bool() {
+            let rect = info.monitorInfo.rcMonitor;
+            let len = info
+                .szDevice
+                .iter()
+                .position(|c| *c == 0)
+                .unwrap_or(info.szDevice.len());
+            monitors.push(Monitor {
+                name: String::from_utf16_lossy(&info.szDevice[..len]),
+                x: rect.left,
+                y: rect.top,
+                width: rect.right - rect.left,
+                height: rect.bottom - rect.top,
+                primary: info.monitorInfo.dwFlags & MONITORINFOF_PRIMARY != 0,
+            });
+        }
+        BOOL(1)
+    }
+
+    let mut monitors: Vec<Monitor> = Vec::new();
+    unsafe {
+        let _ = EnumDisplayMonitors(
+            None,
+            None,
+            Some(collect),
+            LPARAM(&mut monitors as *mut _ as isize),
+        );
+    }
+    monitors
+}
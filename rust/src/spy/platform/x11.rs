@@ -0,0 +1,361 @@
+use std::cell::RefCell;
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use x11rb::connection::Connection;
+use x11rb::protocol::randr::{ConnectionExt as _, NotifyMask};
+use x11rb::protocol::xproto::{
+    Atom, AtomEnum, ConnectionExt, ImageFormat, ImageOrder, MapState, Window,
+};
+use x11rb::protocol::Event;
+use x11rb::rust_connection::RustConnection;
+
+use crate::spy::monitor::Monitor;
+use crate::spy::visibility::Rect;
+use crate::spy::window_state::WindowState;
+
+x11rb::atom_manager! {
+    pub Atoms: AtomsCookie {
+        _NET_ACTIVE_WINDOW,
+        _NET_CLIENT_LIST,
+        _NET_CLIENT_LIST_STACKING,
+        _NET_CURRENT_DESKTOP,
+        _NET_DESKTOP_NAMES,
+        _NET_WM_DESKTOP,
+        _NET_WM_ICON,
+        _NET_WM_NAME,
+        _NET_WM_PID,
+        _NET_WM_STATE,
+        _NET_WM_STATE_ABOVE,
+        _NET_WM_STATE_FULLSCREEN,
+        _NET_WM_STATE_HIDDEN,
+        _NET_WM_STATE_MAXIMIZED_HORZ,
+        _NET_WM_STATE_MAXIMIZED_VERT,
+        UTF8_STRING,
+    }
+}
+
+/// 与 X server 的连接以及常用 atom
+pub struct X11 {
+    pub conn: RustConnection,
+    pub root: Window,
+    pub atoms: Atoms,
+    monitors: RefCell<Option<Vec<Monitor>>>, // 显示器布局缓存，收到 RandR 变化通知后清空
+}
+
+/// 全局共享的 X11 连接，首次使用时建立，出错后下次重连
+static X11_CONN: Lazy<Mutex<Option<X11>>> = Lazy::new(|| Mutex::new(None));
+
+impl X11 {
+    pub fn connect() -> anyhow::Result<X11> {
+        let (conn, screen_num) = x11rb::connect(None)?;
+        let root = conn.setup().roots[screen_num].root;
+        let atoms = Atoms::new(&conn)?.reply()?;
+        // 订阅显示器变化，不支持 RandR 时每次都退回到根窗口大小
+        let mask = NotifyMask::SCREEN_CHANGE | NotifyMask::CRTC_CHANGE | NotifyMask::OUTPUT_CHANGE;
+        if let Err(e) = conn
+            .randr_select_input(root, mask)
+            .map_err(anyhow::Error::from)
+            .and_then(|c| Ok(c.check()?))
+        {
+            println!("[x11] randr select input error: {}", e);
+        }
+        Ok(X11 {
+            conn,
+            root,
+            atoms,
+            monitors: RefCell::new(None),
+        })
+    }
+
+    /// 在共享连接上执行操作，失败时丢弃连接以便下次重连
+    pub fn with<R>(f: impl FnOnce(&X11) -> anyhow::Result<R>) -> Option<R> {
+        let mut guard = X11_CONN.lock().unwrap();
+        if guard.is_none() {
+            match X11::connect() {
+                Ok(x) => *guard = Some(x),
+                Err(e) => {
+                    println!("[x11] connect error: {}", e);
+                    return None;
+                }
+            }
+        }
+        match f(guard.as_ref().unwrap()) {
+            Ok(r) => Some(r),
+            Err(e) => {
+                println!("[x11] request error: {}", e);
+                *guard = None;
+                None
+            }
+        }
+    }
+
+    fn property(
+        &self,
+        window: Window,
+        property: Atom,
+        ty: impl Into<Atom>,
+    ) -> anyhow::Result<x11rb::protocol::xproto::GetPropertyReply> {
+        Ok(self
+            .conn
+            .get_property(false, window, property, ty, 0, u32::MAX / 4)?
+            .reply()?)
+    }
+
+    pub fn cardinal(&self, window: Window, property: Atom) -> anyhow::Result<Option<u32>> {
+        let reply = self.property(window, property, AtomEnum::CARDINAL)?;
+        Ok(reply.value32().and_then(|mut v| v.next()))
+    }
+
+    pub fn string(
+        &self,
+        window: Window,
+        property: Atom,
+        ty: impl Into<Atom>,
+    ) -> anyhow::Result<Option<String>> {
+        let reply = self.property(window, property, ty)?;
+        if reply.value.is_empty() {
+            return Ok(None);
+        }
+        Ok(Some(String::from_utf8_lossy(&reply.value).into_owned()))
+    }
+
+    /// 当前激活窗口（_NET_ACTIVE_WINDOW）
+    pub fn active_window(&self) -> anyhow::Result<Option<Window>> {
+        let reply = self.property(self.root, self.atoms._NET_ACTIVE_WINDOW, AtomEnum::WINDOW)?;
+        Ok(reply
+            .value32()
+            .and_then(|mut v| v.next())
+            .filter(|w| *w != 0))
+    }
+
+    /// 窗口管理器管理的所有顶层窗口（_NET_CLIENT_LIST），按映射顺序
+    pub fn client_list(&self) -> anyhow::Result<Vec<Window>> {
+        let reply = self.property(self.root, self.atoms._NET_CLIENT_LIST, AtomEnum::WINDOW)?;
+        Ok(reply.value32().map(|v| v.collect()).unwrap_or_default())
+    }
+
+    /// 按叠放顺序从下到上排列的顶层窗口（_NET_CLIENT_LIST_STACKING）
+    pub fn stacking_list(&self) -> anyhow::Result<Vec<Window>> {
+        let reply = self.property(
+            self.root,
+            self.atoms._NET_CLIENT_LIST_STACKING,
+            AtomEnum::WINDOW,
+        )?;
+        Ok(reply.value32().map(|v| v.collect()).unwrap_or_default())
+    }
+
+    /// 窗口在根窗口坐标系中的位置和大小
+    pub fn window_rect(&self, window: Window) -> anyhow::Result<Rect> {
+        let geometry = self.conn.get_geometry(window)?.reply()?;
+        let origin = self
+            .conn
+            .translate_coordinates(window, self.root, 0, 0)?
+            .reply()?;
+        Ok(Rect {
+            x: origin.dst_x as i32,
+            y: origin.dst_y as i32,
+            width: geometry.width as i32,
+            height: geometry.height as i32,
+        })
+    }
+
+    /// 窗口是否已映射且所有祖先都已映射
+    pub fn is_viewable(&self, window: Window) -> anyhow::Result<bool> {
+        let attributes = self.conn.get_window_attributes(window)?.reply()?;
+        Ok(attributes.map_state == MapState::VIEWABLE)
+    }
+
+    /// 窗口状态（_NET_WM_STATE）中的 atom
+    pub fn window_state(&self, window: Window) -> anyhow::Result<Vec<Atom>> {
+        let reply = self.property(window, self.atoms._NET_WM_STATE, AtomEnum::ATOM)?;
+        Ok(reply.value32().map(|v| v.collect()).unwrap_or_default())
+    }
+
+    /// 由 _NET_WM_STATE 得到全屏、最大化、最小化、置顶状态
+    pub fn state(&self, window: Window) -> anyhow::Result<WindowState> {
+        let state = self.window_state(window)?;
+        let has = |atom| state.contains(&atom);
+        Ok(WindowState {
+            fullscreen: has(self.atoms._NET_WM_STATE_FULLSCREEN),
+            maximized: has(self.atoms._NET_WM_STATE_MAXIMIZED_HORZ)
+                && has(self.atoms._NET_WM_STATE_MAXIMIZED_VERT),
+            minimized: has(self.atoms._NET_WM_STATE_HIDDEN),
+            above: has(self.atoms._NET_WM_STATE_ABOVE),
+        })
+    }
+
+    /// 当前虚拟桌面序号（_NET_CURRENT_DESKTOP）
+    pub fn current_desktop(&self) -> anyhow::Result<Option<u32>> {
+        self.cardinal(self.root, self.atoms._NET_CURRENT_DESKTOP)
+    }
+
+    /// 虚拟桌面名称（_NET_DESKTOP_NAMES），以 NUL 分隔的 UTF-8 字符串，可能少于桌面数量
+    pub fn desktop_names(&self) -> anyhow::Result<Vec<String>> {
+        let reply = self.property(
+            self.root,
+            self.atoms._NET_DESKTOP_NAMES,
+            self.atoms.UTF8_STRING,
+        )?;
+        Ok(reply
+            .value
+            .split(|b| *b == 0)
+            .map(|n| String::from_utf8_lossy(n).into_owned())
+            .collect())
+    }
+
+    /// 窗口所在虚拟桌面（_NET_WM_DESKTOP），0xFFFFFFFF 表示在所有桌面显示
+    pub fn window_desktop(&self, window: Window) -> anyhow::Result<Option<u32>> {
+        self.cardinal(window, self.atoms._NET_WM_DESKTOP)
+    }
+
+    /// 根窗口大小，作为整个屏幕区域
+    pub fn root_rect(&self) -> anyhow::Result<Rect> {
+        let geometry = self.conn.get_geometry(self.root)?.reply()?;
+        Ok(Rect {
+            x: 0,
+            y: 0,
+            width: geometry.width as i32,
+            height: geometry.height as i32,
+        })
+    }
+
+    /// 显示器布局，只在连接建立后和收到 RandR 变化通知后重新查询
+    pub fn monitors(&self) -> anyhow::Result<Vec<Monitor>> {
+        while let Some(event) = self.conn.poll_for_event()? {
+            if matches!(
+                event,
+                Event::RandrScreenChangeNotify(_) | Event::RandrNotify(_)
+            ) {
+                self.monitors.replace(None);
+            }
+        }
+        if let Some(monitors) = self.monitors.borrow().as_ref() {
+            return Ok(monitors.clone());
+        }
+        let monitors = self.query_monitors()?;
+        self.monitors.replace(Some(monitors.clone()));
+        Ok(monitors)
+    }
+
+    /// 通过 XRandR 获取显示器布局，不支持 RandR 时把整个根窗口当作一个显示器
+    fn query_monitors(&self) -> anyhow::Result<Vec<Monitor>> {
+        let reply = match self.conn.randr_get_monitors(self.root, true)?.reply() {
+            Ok(reply) => reply,
+            Err(e) => {
+                println!("[x11] randr error: {}", e);
+                let root = self.root_rect()?;
+                return Ok(vec![Monitor {
+                    name: "default".to_string(),
+                    x: root.x,
+                    y: root.y,
+                    width: root.width,
+                    height: root.height,
+                    primary: true,
+                }]);
+            }
+        };
+        let mut monitors = Vec::new();
+        for m in reply.monitors {
+            let name = self.conn.get_atom_name(m.name)?.reply()?.name;
+            monitors.push(Monitor {
+                name: String::from_utf8_lossy(&name).into_owned(),
+                x: m.x as i32,
+                y: m.y as i32,
+                width: m.width as i32,
+                height: m.height as i32,
+                primary: m.primary,
+            });
+        }
+        Ok(monitors)
+    }
+
+    /// 从根窗口截取一块区域，只支持 24/32 位色深
+    pub fn capture(&self, rect: &Rect) -> anyhow::Result<image::RgbaImage> {
+        let reply = self
+            .conn
+            .get_image(
+                ImageFormat::Z_PIXMAP,
+                self.root,
+                rect.x as i16,
+                rect.y as i16,
+                rect.width as u16,
+                rect.height as u16,
+                u32::MAX,
+            )?
+            .reply()?;
+        let setup = self.conn.setup();
+        let bpp = setup
+            .pixmap_formats
+            .iter()
+            .find(|f| f.depth == reply.depth)
+            .map(|f| f.bits_per_pixel)
+            .unwrap_or(0);
+        if bpp != 32 {
+            anyhow::bail!(
+                "unsupported pixmap format: depth {} bpp {}",
+                reply.depth,
+                bpp
+            );
+        }
+        let lsb = setup.image_byte_order == ImageOrder::LSB_FIRST;
+        let pixels = reply
+            .data
+            .chunks_exact(4)
+            .flat_map(|p| {
+                if lsb {
+                    [p[2], p[1], p[0], 255]
+                } else {
+                    [p[1], p[2], p[3], 255]
+                }
+            })
+            .collect();
+        image::RgbaImage::from_raw(rect.width as u32, rect.height as u32, pixels)
+            .ok_or_else(|| anyhow::anyhow!("image size mismatch"))
+    }
+
+    /// 窗口标题，优先 _NET_WM_NAME（UTF-8），否则 WM_NAME
+    pub fn window_title(&self, window: Window) -> anyhow::Result<Option<String>> {
+        if let Some(title) = self.string(window, self.atoms._NET_WM_NAME, self.atoms.UTF8_STRING)? {
+            return Ok(Some(title));
+        }
+        self.string(window, AtomEnum::WM_NAME.into(), AtomEnum::STRING)
+    }
+
+    /// 窗口所属进程 ID（_NET_WM_PID）
+    pub fn window_pid(&self, window: Window) -> anyhow::Result<Option<u32>> {
+        self.cardinal(window, self.atoms._NET_WM_PID)
+    }
+
+    /// 窗口图标（_NET_WM_ICON），每个元素为 (宽, 高, ARGB 像素)
+    pub fn window_icons(&self, window: Window) -> anyhow::Result<Vec<(u32, u32, Vec<u32>)>> {
+        let reply = self.property(window, self.atoms._NET_WM_ICON, AtomEnum::CARDINAL)?;
+        let Some(values) = reply.value32() else {
+            return Ok(Vec::new());
+        };
+        let values: Vec<u32> = values.collect();
+
+        let mut icons = Vec::new();
+        let mut rest = values.as_slice();
+        while let [width, height, data @ ..] = rest {
+            let len = (*width as usize).saturating_mul(*height as usize);
+            if len == 0 || data.len() < len {
+                break;
+            }
+            icons.push((*width, *height, data[..len].to_vec()));
+            rest = &data[len..];
+        }
+        Ok(icons)
+    }
+
+    /// WM_CLASS，返回 (instance, class)
+    pub fn window_class(&self, window: Window) -> anyhow::Result<Option<(String, String)>> {
+        let Some(value) = self.string(window, AtomEnum::WM_CLASS.into(), AtomEnum::STRING)? else {
+            return Ok(None);
+        };
+        let mut parts = value.split('\0');
+        let instance = parts.next().unwrap_or_default().to_string();
+        let class = parts.next().unwrap_or_default().to_string();
+        Ok(Some((instance, class)))
+    }
+}
@@ -0,0 +1,186 @@
+//! XInput2 raw 事件监听
+//!
+//! 在根窗口上订阅 RawKeyPress / RawButtonPress / RawMotion，raw 事件发给所有订阅者，
+//! 不会抢占其他程序的输入。按键事件只计数，不读取键码。
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+
+use x11rb::connection::Connection;
+use x11rb::protocol::xinput::{self, ConnectionExt as _, XIEventMask};
+use x11rb::protocol::xproto::{ConnectionExt as _, Window};
+use x11rb::protocol::Event;
+use x11rb::rust_connection::RustConnection;
+
+use crate::spy::input::InputStore;
+
+/// 没有事件时的等待间隔
+const IDLE_WAIT: Duration = Duration::from_millis(50);
+
+/// 鼠标移动时读取指针位置的最短间隔，避免每个移动事件都往返一次 X server
+const MOTION_INTERVAL: Duration = Duration::from_millis(100);
+
+/// 滚轮在核心协议中是 4~7 号按钮
+const SCROLL_BUTTONS: std::ops::RangeInclusive<u32> = 4..=7;
+
+pub struct RawInputListener {
+    conn: RustConnection,
+    root: Window,
+}
+
+impl RawInputListener {
+    /// 连接 display（None 为 $DISPLAY）并订阅 raw 事件，X server 不支持 XInput 2 时返回错误
+    pub fn open(display: Option<&str>) -> anyhow::Result<RawInputListener> {
+        let (conn, screen_num) = x11rb::connect(display)?;
+        let root = conn.setup().roots[screen_num].root;
+        let version = conn.xinput_xi_query_version(2, 0)?.reply()?;
+        if version.major_version < 2 {
+            anyhow::bail!(
+                "XInput {}.{} does not support raw events",
+                version.major_version,
+                version.minor_version
+            );
+        }
+        conn.xinput_xi_select_events(
+            root,
+            &[xinput::EventMask {
+                deviceid: xinput::Device::ALL_MASTER.into(),
+                mask: vec![
+                    XIEventMask::RAW_KEY_PRESS
+                        | XIEventMask::RAW_BUTTON_PRESS
+                        | XIEventMask::RAW_MOTION,
+                ],
+            }],
+        )?
+        .check()?;
+        Ok(RawInputListener { conn, root })
+    }
+
+    fn pointer(&self) -> anyhow::Result<(i32, i32)> {
+        let reply = self.conn.query_pointer(self.root)?.reply()?;
+        Ok((reply.root_x as i32, reply.root_y as i32))
+    }
+
+    /// 接收事件并计入 store，直到 stop 被设置或连接断开
+    pub fn run(&self, stop: &AtomicBool, store: &Mutex<InputStore>) -> anyhow::Result<()> {
+        let mut position = self.pointer().ok();
+        let mut last_motion = Instant::now();
+        let mut moved = false;
+        while !stop.load(Ordering::SeqCst) {
+            let Some(event) = self.conn.poll_for_event()? else {
+                // 补上最后一段还没统计的移动
+                if moved && last_motion.elapsed() >= MOTION_INTERVAL {
+                    self.track_motion(&mut position, store)?;
+                    last_motion = Instant::now();
+                    moved = false;
+                }
+                // 每分钟都要有一个区间，没有输入的分钟才能算作空闲
+                store.lock().unwrap().tick(now_millis());
+                std::thread::sleep(IDLE_WAIT);
+                continue;
+            };
+            match event {
+                Event::XinputRawKeyPress(_) => store.lock().unwrap().key(now_millis()),
+                Event::XinputRawButtonPress(e) if SCROLL_BUTTONS.contains(&e.detail) => {
+                    store.lock().unwrap().scroll(now_millis())
+                }
+                Event::XinputRawButtonPress(_) => store.lock().unwrap().click(now_millis()),
+                Event::XinputRawMotion(_) => {
+                    moved = true;
+                    if last_motion.elapsed() >= MOTION_INTERVAL {
+                        self.track_motion(&mut position, store)?;
+                        last_motion = Instant::now();
+                        moved = false;
+                    }
+                }
+                _ => {}
+            }
+        }
+        Ok(())
+    }
+
+    /// 读取指针位置，把与上次位置的距离计入移动距离
+    fn track_motion(
+        &self,
+        position: &mut Option<(i32, i32)>,
+        store: &Mutex<InputStore>,
+    ) -> anyhow::Result<()> {
+        let current = self.pointer()?;
+        if let Some((x, y)) = position.replace(current) {
+            let distance =
+                (((current.0 - x) as f64).powi(2) + ((current.1 - y) as f64).powi(2)).sqrt();
+            store
+                .lock()
+                .unwrap()
+                .moved(distance.round() as u32, now_millis());
+        }
+        Ok(())
+    }
+}
+
+fn now_millis() -> i64 {
+    chrono::Local::now().timestamp_millis()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::process::{Command, Stdio};
+    use std::sync::Arc;
+
+    #[test]
+    #[ignore = "需要 Xvfb 和 xdotool，使用 cargo test -- --ignored 运行"]
+    fn test_raw_events_under_xvfb() {
+        let display = format!(":{}", 90 + std::process::id() % 100);
+        let mut xvfb = Command::new("Xvfb")
+            .args([display.as_str(), "-nolisten", "tcp"])
+            .stderr(Stdio::null())
+            .spawn()
+            .expect("Xvfb not found");
+        let listener = (0..50).find_map(|_| {
+            std::thread::sleep(Duration::from_millis(100));
+            RawInputListener::open(Some(&display)).ok()
+        });
+        let Some(listener) = listener else {
+            let _ = xvfb.kill();
+            panic!("cannot connect to Xvfb");
+        };
+
+        let store = Arc::new(Mutex::new(InputStore::default()));
+        let stop = Arc::new(AtomicBool::new(false));
+        let handle = {
+            let (store, stop) = (store.clone(), stop.clone());
+            std::thread::spawn(move || listener.run(&stop, &store))
+        };
+        let xdotool = Command::new("xdotool")
+            .env("DISPLAY", &display)
+            .args([
+                "mousemove",
+                "10",
+                "10",
+                "sleep",
+                "0.2",
+                "mousemove",
+                "310",
+                "410",
+            ])
+            .args(["sleep", "0.2", "type", "--delay", "20", "hello"])
+            .args(["click", "1", "click", "4", "sleep", "0.3"])
+            .status();
+        stop.store(true, Ordering::SeqCst);
+        let _ = handle.join();
+        let _ = xvfb.kill();
+        let _ = xvfb.wait();
+        if xdotool.is_err() {
+            println!("xdotool not found, skip");
+            return;
+        }
+
+        let counts = store.lock().unwrap().counts_between(0, i64::MAX);
+        assert_eq!(counts.keystrokes, 5);
+        assert_eq!(counts.clicks, 1);
+        assert_eq!(counts.scrolls, 1);
+        assert!(counts.mouse_distance >= 400);
+    }
+}
@@ -0,0 +1,102 @@
+//! 进程信息
+//!
+//! 通过 sysinfo 读取前台窗口所属进程的 pid、父进程链、命令行、启动时间和用户，
+//! 用于区分同一应用的多个实例，以及排查应用识别错误。
+
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind, Users};
+
+/// 父进程链的最大深度，防止异常数据导致死循环
+const MAX_PARENTS: usize = 32;
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ProcessInfo {
+    pub pid: u32,
+    pub parents: Vec<ParentProcess>, // 父进程链，从直接父进程到 init
+    pub cmdline: Vec<String>,        // 命令行参数，第一个为程序本身
+    pub start_time: u64,             // 进程启动时间（Unix 秒）
+    pub user: Option<String>,        // 进程所属用户名
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct ParentProcess {
+    pub pid: u32,
+    pub name: String,
+}
+
+pub static SYSTEM: Lazy<Mutex<System>> = Lazy::new(|| Mutex::new(System::new()));
+
+static USERS: Lazy<Mutex<Users>> = Lazy::new(|| Mutex::new(Users::new_with_refreshed_list()));
+
+/// 读取进程信息，进程已退出时返回 None
+pub fn process_info(pid: u32) -> Option<ProcessInfo> {
+    let mut sys = SYSTEM.lock().unwrap();
+    let kind = ProcessRefreshKind::nothing()
+        .with_cmd(UpdateKind::Always)
+        .with_user(UpdateKind::Always);
+    sys.refresh_processes_specifics(ProcessesToUpdate::Some(&[Pid::from_u32(pid)]), true, kind);
+    let process = sys.process(Pid::from_u32(pid))?;
+    let cmdline = process
+        .cmd()
+        .iter()
+        .map(|s| s.to_string_lossy().into_owned())
+        .collect();
+    let start_time = process.start_time();
+    let user = process.user_id().and_then(user_name);
+
+    let mut parents = Vec::new();
+    let mut parent = process.parent();
+    while let Some(ppid) = parent {
+        if parents.len() >= MAX_PARENTS || ppid.as_u32() == pid {
+            break;
+        }
+        sys.refresh_processes_specifics(
+            ProcessesToUpdate::Some(&[ppid]),
+            true,
+            ProcessRefreshKind::nothing(),
+        );
+        let Some(p) = sys.process(ppid) else {
+            break;
+        };
+        parents.push(ParentProcess {
+            pid: ppid.as_u32(),
+            name: p.name().to_string_lossy().into_owned(),
+        });
+        parent = p.parent();
+    }
+
+    Some(ProcessInfo {
+        pid,
+        parents,
+        cmdline,
+        start_time,
+        user,
+    })
+}
+
+/// 按 uid 查找用户名，找不到时刷新一次用户列表（可能是新建的用户）
+fn user_name(uid: &sysinfo::Uid) -> Option<String> {
+    let mut users = USERS.lock().unwrap();
+    if users.get_user_by_id(uid).is_none() {
+        users.refresh();
+    }
+    users.get_user_by_id(uid).map(|u| u.name().to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_current_process() {
+        let info = process_info(std::process::id()).unwrap();
+        assert_eq!(info.pid, std::process::id());
+        assert!(!info.cmdline.is_empty());
+        assert!(info.start_time > 0);
+        assert!(!info.parents.is_empty());
+        assert!(info.parents.iter().all(|p| p.pid != info.pid));
+    }
+}
@@ -0,0 +1,361 @@
+//! 应用资源占用采样
+//!
+//! 每次前台采样时统计前台进程树（进程及其所有子进程）的 CPU、常驻内存、磁盘读写和线程数，
+//! 同时采样配置的后台应用；结果累计到当前会话中，用来找出拖慢机器的工具。
+
+use std::collections::{HashMap, HashSet};
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System};
+
+use crate::spy::session::Session;
+
+/// 单次采样结果
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct ResourceUsage {
+    pub cpu: f32,        // CPU 占用百分比，多核时可超过 100
+    pub memory: u64,     // 常驻内存（字节）
+    pub disk_read: u64,  // 距上次采样读取的字节数
+    pub disk_write: u64, // 距上次采样写入的字节数
+    pub threads: u32,    // 线程数
+}
+
+/// 会话内多次采样的累计
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ResourceStats {
+    pub samples: u32,
+    pub cpu_avg: f32,
+    pub cpu_max: f32,
+    pub memory_avg: u64,
+    pub memory_max: u64,
+    pub disk_read: u64,
+    pub disk_write: u64,
+    pub threads_max: u32,
+}
+
+impl ResourceStats {
+    pub fn add(&mut self, usage: &ResourceUsage) {
+        self.merge(&ResourceStats {
+            samples: 1,
+            cpu_avg: usage.cpu,
+            cpu_max: usage.cpu,
+            memory_avg: usage.memory,
+            memory_max: usage.memory,
+            disk_read: usage.disk_read,
+            disk_write: usage.disk_write,
+            threads_max: usage.threads,
+        });
+    }
+
+    /// 合并另一段累计，平均值按采样次数加权
+    pub fn merge(&mut self, other: &ResourceStats) {
+        let total = self.samples + other.samples;
+        if total == 0 {
+            return;
+        }
+        let weight =
+            |a: f64, na: u32, b: f64, nb: u32| (a * na as f64 + b * nb as f64) / total as f64;
+        self.cpu_avg = weight(
+            self.cpu_avg as f64,
+            self.samples,
+            other.cpu_avg as f64,
+            other.samples,
+        ) as f32;
+        self.memory_avg = weight(
+            self.memory_avg as f64,
+            self.samples,
+            other.memory_avg as f64,
+            other.samples,
+        ) as u64;
+        self.samples = total;
+        self.cpu_max = self.cpu_max.max(other.cpu_max);
+        self.memory_max = self.memory_max.max(other.memory_max);
+        self.disk_read += other.disk_read;
+        self.disk_write += other.disk_write;
+        self.threads_max = self.threads_max.max(other.threads_max);
+    }
+}
+
+/// 一个应用在会话内的后台资源占用
+#[derive(Debug, Clone, PartialEq)]
+pub struct BackgroundUsage {
+    pub name: String,
+    pub stats: ResourceStats,
+}
+
+/// 按应用汇总的资源占用
+#[derive(Debug, Clone, PartialEq)]
+pub struct AppResourceUsage {
+    pub name: String,
+    pub foreground: ResourceStats, // 处于前台时
+    pub background: ResourceStats, // 作为后台应用被采样时
+}
+
+#[derive(Default)]
+pub struct Sampler {
+    system: Option<System>,
+}
+
+impl Sampler {
+    /// 刷新所有进程，CPU 占用和磁盘读写都是两次刷新之间的值
+    pub fn refresh(&mut self) {
+        let refresh = |system: &mut System| {
+            system.refresh_processes_specifics(
+                ProcessesToUpdate::All,
+                true,
+                ProcessRefreshKind::nothing()
+                    .with_cpu()
+                    .with_memory()
+                    .with_disk_usage()
+                    .with_tasks(),
+            );
+        };
+        // 第一次刷新得到的读写量是进程启动以来的累计，先刷新一次作为基准
+        let system = self.system.get_or_insert_with(|| {
+            let mut system = System::new();
+            refresh(&mut system);
+            system
+        });
+        refresh(system);
+    }
+
+    /// 进程及其所有子进程
+    fn tree(system: &System, root: Pid) -> Vec<Pid> {
+        let mut children: HashMap<Pid, Vec<Pid>> = HashMap::new();
+        for (pid, process) in system.processes() {
+            // 线程在 Linux 上也会出现在进程列表中
+            if process.thread_kind().is_some() {
+                continue;
+            }
+            if let Some(parent) = process.parent() {
+                children.entry(parent).or_default().push(*pid);
+            }
+        }
+        let mut tree = vec![root];
+        let mut seen = HashSet::from([root]);
+        let mut i = 0;
+        while i < tree.len() {
+            for child in children.get(&tree[i]).into_iter().flatten() {
+                if seen.insert(*child) {
+                    tree.push(*child);
+                }
+            }
+            i += 1;
+        }
+        tree
+    }
+
+    fn usage(&self, pids: &[Pid]) -> Option<ResourceUsage> {
+        let system = self.system.as_ref()?;
+        let mut usage = ResourceUsage::default();
+        let mut found = false;
+        for pid in pids {
+            let Some(process) = system.process(*pid) else {
+                continue;
+            };
+            found = true;
+            usage.cpu += process.cpu_usage();
+            usage.memory += process.memory();
+            usage.threads += process.tasks().map(|t| t.len() as u32).unwrap_or(1).max(1);
+            let disk = process.disk_usage();
+            usage.disk_read += disk.read_bytes;
+            usage.disk_write += disk.written_bytes;
+        }
+        found.then_some(usage)
+    }
+
+    /// 采样 pid 所在的进程树
+    pub fn sample_tree(&mut self, pid: u32) -> Option<ResourceUsage> {
+        let pids = Self::tree(self.system.as_ref()?, Pid::from_u32(pid));
+        self.usage(&pids)
+    }
+
+    /// 采样进程名或可执行文件名匹配 name 的所有进程（及其子进程）
+    pub fn sample_app(&mut self, name: &str) -> Option<ResourceUsage> {
+        let system = self.system.as_ref()?;
+        let name = name.to_lowercase();
+        let roots: Vec<Pid> = system
+            .processes()
+            .iter()
+            .filter(|(_, p)| p.thread_kind().is_none())
+            .filter(|(_, p)| {
+                let process_name = p.name().to_string_lossy().to_lowercase();
+                let exe_name = p
+                    .exe()
+                    .and_then(|e| e.file_stem())
+                    .map(|s| s.to_string_lossy().to_lowercase());
+                process_name == name
+                    || process_name.strip_suffix(".exe") == Some(&name)
+                    || exe_name.as_deref() == Some(&name)
+            })
+            .map(|(pid, _)| *pid)
+            .collect();
+        let mut pids = Vec::new();
+        let mut seen = HashSet::new();
+        for root in roots {
+            for pid in Self::tree(system, root) {
+                if seen.insert(pid) {
+                    pids.push(pid);
+                }
+            }
+        }
+        self.usage(&pids)
+    }
+}
+
+pub static SAMPLER: Lazy<Mutex<Sampler>> = Lazy::new(|| Mutex::new(Sampler::default()));
+
+/// 需要在后台持续采样的应用（进程名或可执行文件名）
+pub static BACKGROUND_APPS: Lazy<Mutex<Vec<String>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+/// 采样前台进程树和后台应用，返回 (前台占用, 后台占用)
+pub fn sample(foreground: Option<u32>) -> (Option<ResourceUsage>, Vec<(String, ResourceUsage)>) {
+    let apps = BACKGROUND_APPS.lock().unwrap().clone();
+    let mut sampler = SAMPLER.lock().unwrap();
+    sampler.refresh();
+    let usage = foreground.and_then(|pid| sampler.sample_tree(pid));
+    let background = apps
+        .into_iter()
+        .filter_map(|name| {
+            let usage = sampler.sample_app(&name)?;
+            Some((name, usage))
+        })
+        .collect();
+    (usage, background)
+}
+
+pub fn set_background_apps(apps: Vec<String>) {
+    *BACKGROUND_APPS.lock().unwrap() = apps;
+}
+
+/// 按应用汇总 [start, end] 内会话的资源占用，按前台平均 CPU 降序
+pub fn resource_usage(sessions: &[Session], start: i64, end: i64) -> Vec<AppResourceUsage> {
+    fn entry<'a>(
+        apps: &'a mut HashMap<String, AppResourceUsage>,
+        name: &str,
+    ) -> &'a mut AppResourceUsage {
+        apps.entry(name.to_string())
+            .or_insert_with(|| AppResourceUsage {
+                name: name.to_string(),
+                foreground: ResourceStats::default(),
+                background: ResourceStats::default(),
+            })
+    }
+
+    let mut apps = HashMap::new();
+    for session in sessions.iter().filter(|s| s.end >= start && s.start <= end) {
+        entry(&mut apps, &session.name)
+            .foreground
+            .merge(&session.usage);
+        for bg in &session.background {
+            entry(&mut apps, &bg.name).background.merge(&bg.stats);
+        }
+    }
+    let mut result: Vec<AppResourceUsage> = apps
+        .into_values()
+        .filter(|a| a.foreground.samples > 0 || a.background.samples > 0)
+        .collect();
+    result.sort_by(|a, b| {
+        b.foreground
+            .cpu_avg
+            .total_cmp(&a.foreground.cpu_avg)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    result
+}
+
+pub fn get_resource_usage(start: i64, end: i64) -> Vec<AppResourceUsage> {
+    let sessions = crate::spy::session::sessions_between(start, end);
+    resource_usage(&sessions, start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn usage(cpu: f32, memory: u64, disk_read: u64) -> ResourceUsage {
+        ResourceUsage {
+            cpu,
+            memory,
+            disk_read,
+            disk_write: 0,
+            threads: 4,
+        }
+    }
+
+    #[test]
+    fn test_stats() {
+        let mut stats = ResourceStats::default();
+        stats.add(&usage(10.0, 100, 5));
+        stats.add(&usage(30.0, 300, 7));
+        assert_eq!(stats.samples, 2);
+        assert_eq!(stats.cpu_avg, 20.0);
+        assert_eq!(stats.cpu_max, 30.0);
+        assert_eq!(stats.memory_avg, 200);
+        assert_eq!(stats.memory_max, 300);
+        assert_eq!(stats.disk_read, 12);
+
+        let mut other = ResourceStats::default();
+        other.add(&usage(80.0, 400, 0));
+        stats.merge(&other);
+        assert_eq!(stats.samples, 3);
+        assert!((stats.cpu_avg - 40.0).abs() < 1e-4);
+        assert_eq!(stats.memory_max, 400);
+
+        stats.merge(&ResourceStats::default());
+        assert_eq!(stats.samples, 3);
+    }
+
+    #[test]
+    fn test_sample_current_tree() {
+        // 创建采样器之前的写入不计入第一次采样
+        let path = std::env::temp_dir().join(format!("spy-resource-{}", std::process::id()));
+        let mut file = std::fs::File::create(&path).unwrap();
+        std::io::Write::write_all(&mut file, &vec![1u8; 1 << 20]).unwrap();
+        file.sync_all().unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        let mut sampler = Sampler::default();
+        sampler.refresh();
+        let first = sampler.sample_tree(std::process::id()).unwrap();
+        assert!(first.memory > 0);
+        assert!(first.threads >= 1);
+        assert!(first.disk_write < 1 << 20);
+
+        // 第二次采样起才有 CPU 和读写的增量
+        sampler.refresh();
+        assert!(sampler.sample_tree(std::process::id()).is_some());
+        assert!(sampler.sample_tree(u32::MAX - 1).is_none());
+    }
+
+    #[test]
+    fn test_resource_usage_by_app() {
+        let mut store = crate::spy::session::SessionStore::default();
+        let mut app = crate::spy::model::Application {
+            name: "code".to_string(),
+            ..Default::default()
+        };
+        store.record(&app, 1_000);
+        store.record_usage(
+            Some(&usage(50.0, 100, 0)),
+            &[("docker".to_string(), usage(5.0, 10, 0))],
+        );
+        app.name = "firefox".to_string();
+        store.record(&app, 2_000);
+        store.record_usage(
+            Some(&usage(10.0, 100, 0)),
+            &[("docker".to_string(), usage(15.0, 10, 0))],
+        );
+
+        let sessions = store.between(0, 3_000);
+        let apps = resource_usage(&sessions, 0, 3_000);
+        let names: Vec<&str> = apps.iter().map(|a| a.name.as_str()).collect();
+        assert_eq!(names, ["code", "firefox", "docker"]);
+        let docker = &apps[2];
+        assert_eq!(docker.foreground.samples, 0);
+        assert_eq!(docker.background.samples, 2);
+        assert_eq!(docker.background.cpu_avg, 10.0);
+    }
+}
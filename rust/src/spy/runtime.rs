@@ -0,0 +1,456 @@
+//! 解释器与运行时拆包
+//!
+//! `java`、`python`、`node`、`electron`、`dotnet` 运行的程序，可执行文件都是运行时本身，
+//! 需要从命令行参数和环境变量中找出真正的应用（jar、脚本、模块、app 目录等）。
+
+use std::path::Path;
+
+/// 识别出的运行时程序
+#[derive(Debug, Clone, PartialEq)]
+pub struct RuntimeApp {
+    pub runtime: String,        // 运行时名称，例如 java、python
+    pub name: String,           // 真正的应用名称
+    pub target: Option<String>, // 被运行的文件（jar、脚本、dll、app 目录），可能为相对路径
+}
+
+/// 按可执行文件名识别运行时
+pub fn runtime_kind(exe_path: &str) -> Option<&'static str> {
+    // 同时兼容 Windows 路径分隔符
+    let file = exe_path.rsplit(['/', '\\']).next()?.to_lowercase();
+    let stem = file.strip_suffix(".exe").unwrap_or(&file);
+    let versioned = |prefix: &str| {
+        stem.strip_prefix(prefix)
+            .is_some_and(|rest| rest.chars().all(|c| c.is_ascii_digit() || c == '.'))
+    };
+
+    if matches!(stem, "java" | "javaw") {
+        Some("java")
+    } else if versioned("python") || versioned("pythonw") || versioned("pypy") {
+        Some("python")
+    } else if matches!(stem, "node" | "nodejs") {
+        Some("node")
+    } else if versioned("electron") {
+        Some("electron")
+    } else if stem == "dotnet" {
+        Some("dotnet")
+    } else {
+        None
+    }
+}
+
+/// 从命令行和环境变量解析真正的应用，`cmdline[0]` 为程序本身
+pub fn unwrap(
+    exe_path: &str,
+    pid: u32,
+    cmdline: &[String],
+    environ: &[(String, String)],
+) -> Option<RuntimeApp> {
+    let runtime = runtime_kind(exe_path)?;
+    let args = cmdline.get(1..).unwrap_or_default();
+    let env = |key: &str| {
+        environ
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, v)| v.as_str())
+    };
+
+    let (name, target) = match runtime {
+        "java" => java(args).or_else(|| {
+            // macOS 的 java 启动器会设置 JAVA_MAIN_CLASS_<pid>
+            let class = env(&format!("JAVA_MAIN_CLASS_{}", pid))?;
+            Some((simple_class_name(class), None))
+        })?,
+        "python" => python(args)?,
+        "node" => node(args)?,
+        "electron" => electron(args, env("CHROME_DESKTOP"))?,
+        "dotnet" => dotnet(args)?,
+        _ => return None,
+    };
+
+    Some(RuntimeApp {
+        runtime: runtime.to_string(),
+        name,
+        target,
+    })
+}
+
+fn file_stem(path: &str) -> String {
+    Path::new(path)
+        .file_stem()
+        .map(|s| s.to_string_lossy().into_owned())
+        .unwrap_or_else(|| path.to_string())
+}
+
+/// 跳过选项，返回第一个位置参数的下标；`with_value` 中的选项会额外消耗一个参数
+fn first_positional(args: &[String], with_value: &[&str]) -> Option<usize> {
+    let mut i = 0;
+    while i < args.len() {
+        let arg = args[i].as_str();
+        if arg == "--" {
+            return (i + 1 < args.len()).then_some(i + 1);
+        }
+        if !arg.starts_with('-') {
+            return Some(i);
+        }
+        if with_value.contains(&arg) {
+            i += 1;
+        }
+        i += 1;
+    }
+    None
+}
+
+fn simple_class_name(class: &str) -> String {
+    class.rsplit(['.', '/']).next().unwrap_or(class).to_string()
+}
+
+/// 去掉 jar 文件名中的版本号，例如 `app-1.2.3` → `app`
+fn strip_version(stem: &str) -> String {
+    match stem.rsplit_once('-') {
+        Some((name, version))
+            if !name.is_empty() && version.starts_with(|c: char| c.is_ascii_digit()) =>
+        {
+            name.to_string()
+        }
+        _ => stem.to_string(),
+    }
+}
+
+/// java [options] -jar app.jar | java [options] -m module/Main | java [options] com.example.Main
+fn java(args: &[String]) -> Option<(String, Option<String>)> {
+    if let Some(i) = args.iter().position(|a| a == "-jar") {
+        let jar = args.get(i + 1)?;
+        return Some((strip_version(&file_stem(jar)), Some(jar.clone())));
+    }
+    if let Some(i) = args.iter().position(|a| a == "-m" || a == "--module") {
+        let module = args.get(i + 1)?;
+        let (module, class) = module.split_once('/').unwrap_or((module, module));
+        return Some((simple_class_name(class), Some(module.to_string())));
+    }
+    let i = first_positional(
+        args,
+        &[
+            "-cp",
+            "-classpath",
+            "--class-path",
+            "-p",
+            "--module-path",
+            "--add-opens",
+            "--add-exports",
+        ],
+    )?;
+    Some((simple_class_name(&args[i]), None))
+}
+
+/// python [options] script.py | python -m module
+fn python(args: &[String]) -> Option<(String, Option<String>)> {
+    let mut i = 0;
+    while i < args.len() {
+        match args[i].as_str() {
+            "-m" => {
+                let module = args.get(i + 1)?;
+                // 包的入口模块，例如 `-m pkg.__main__`
+                let module = module.strip_suffix(".__main__").unwrap_or(module);
+                return Some((module.to_string(), None));
+            }
+            "-c" => return None,
+            "-W" | "-X" | "-Q" => i += 2,
+            arg if arg.starts_with('-') => i += 1,
+            script => return Some((script_name(script), Some(script.to_string()))),
+        }
+    }
+    None
+}
+
+/// node [options] script.js，`node_modules/<pkg>/...` 下的脚本使用包名
+fn node(args: &[String]) -> Option<(String, Option<String>)> {
+    let i = first_positional(
+        args,
+        &["-r", "--require", "--import", "--loader", "-e", "--eval"],
+    )?;
+    if args[..i]
+        .iter()
+        .any(|a| matches!(a.as_str(), "-e" | "--eval" | "-p" | "--print"))
+    {
+        return None;
+    }
+    let script = &args[i];
+
+    let parts: Vec<&str> = script.split(['/', '\\']).collect();
+    if let Some(pos) = parts.iter().rposition(|p| *p == "node_modules") {
+        let package = match parts.get(pos + 1) {
+            // 带 scope 的包，例如 @angular/cli
+            Some(scope) if scope.starts_with('@') => {
+                parts.get(pos + 2).map(|p| format!("{}/{}", scope, p))
+            }
+            Some(p) => Some(p.to_string()),
+            None => None,
+        };
+        if let Some(package) = package {
+            return Some((package, Some(script.clone())));
+        }
+    }
+    Some((script_name(script), Some(script.clone())))
+}
+
+/// electron [flags] <app 目录或 .asar>，优先使用 CHROME_DESKTOP 环境变量
+fn electron(args: &[String], chrome_desktop: Option<&str>) -> Option<(String, Option<String>)> {
+    let target = first_positional(args, &[]).map(|i| args[i].clone());
+    if let Some(desktop) = chrome_desktop.filter(|d| !d.is_empty()) {
+        let name = desktop.strip_suffix(".desktop").unwrap_or(desktop);
+        return Some((name.to_string(), target));
+    }
+
+    let target = target?;
+    let path = Path::new(&target);
+    // /usr/lib/code/resources/app.asar → code
+    let name = if path.file_stem().is_some_and(|s| s == "app") {
+        path.ancestors()
+            .skip(1)
+            .find(|p| p.file_name().is_some_and(|n| n != "resources"))
+            .and_then(|p| p.file_name())
+            .map(|n| n.to_string_lossy().into_owned())
+            .unwrap_or_else(|| file_stem(&target))
+    } else {
+        file_stem(&target)
+    };
+    Some((name, Some(target)))
+}
+
+/// dotnet [exec] app.dll
+fn dotnet(args: &[String]) -> Option<(String, Option<String>)> {
+    let dll = args.iter().find(|a| a.to_lowercase().ends_with(".dll"))?;
+    Some((file_stem(dll), Some(dll.clone())))
+}
+
+/// 脚本名称；入口文件名过于通用时使用所在目录名
+fn script_name(script: &str) -> String {
+    let path = Path::new(script);
+    let stem = file_stem(script);
+    if matches!(
+        stem.as_str(),
+        "index" | "main" | "cli" | "app" | "__main__" | "server"
+    ) {
+        if let Some(dir) = path.parent().and_then(|p| p.file_name()) {
+            let dir = dir.to_string_lossy();
+            // 跳过 bin、dist、src 等通用目录
+            if !matches!(
+                dir.as_ref(),
+                "bin" | "dist" | "src" | "lib" | "build" | "out"
+            ) {
+                return dir.into_owned();
+            }
+            if let Some(grand) = path
+                .parent()
+                .and_then(|p| p.parent())
+                .and_then(|p| p.file_name())
+            {
+                return grand.to_string_lossy().into_owned();
+            }
+        }
+    }
+    stem
+}
+
+/// 进程的命令行参数和环境变量
+pub type ProcessCommand = (Vec<String>, Vec<(String, String)>);
+
+/// 读取进程的命令行和环境变量
+#[cfg(target_os = "linux")]
+pub fn process_command(pid: u32) -> Option<ProcessCommand> {
+    let split = |data: Vec<u8>| -> Vec<String> {
+        data.split(|b| *b == 0)
+            .filter(|s| !s.is_empty())
+            .map(|s| String::from_utf8_lossy(s).into_owned())
+            .collect()
+    };
+    let cmdline = split(std::fs::read(format!("/proc/{}/cmdline", pid)).ok()?);
+    // 其他用户的进程可能无法读取环境变量
+    let environ = std::fs::read(format!("/proc/{}/environ", pid))
+        .map(split)
+        .unwrap_or_default();
+    Some((cmdline, parse_environ(environ)))
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn process_command(pid: u32) -> Option<ProcessCommand> {
+    use sysinfo::{Pid, ProcessRefreshKind, ProcessesToUpdate, System, UpdateKind};
+
+    let pid = Pid::from_u32(pid);
+    let mut sys = System::new();
+    sys.refresh_processes_specifics(
+        ProcessesToUpdate::Some(&[pid]),
+        true,
+        ProcessRefreshKind::nothing()
+            .with_cmd(UpdateKind::Always)
+            .with_environ(UpdateKind::Always),
+    );
+    let process = sys.process(pid)?;
+    let to_strings = |v: &[std::ffi::OsString]| -> Vec<String> {
+        v.iter().map(|s| s.to_string_lossy().into_owned()).collect()
+    };
+    Some((
+        to_strings(process.cmd()),
+        parse_environ(to_strings(process.environ())),
+    ))
+}
+
+fn parse_environ(environ: Vec<String>) -> Vec<(String, String)> {
+    environ
+        .into_iter()
+        .filter_map(|e| {
+            e.split_once('=')
+                .map(|(k, v)| (k.to_string(), v.to_string()))
+        })
+        .collect()
+}
+
+/// 对前台进程做运行时拆包，不是运行时或无法识别时返回 None
+///
+/// 相对路径的 target 会按进程工作目录转换为绝对路径（仅 Linux 可读取工作目录）。
+pub fn resolve(exe_path: &str, pid: Option<u32>) -> Option<RuntimeApp> {
+    runtime_kind(exe_path)?;
+    let pid = pid?;
+    let (cmdline, environ) = process_command(pid)?;
+    let app = unwrap(exe_path, pid, &cmdline, &environ)?;
+    Some(RuntimeApp {
+        target: app.target.map(|t| absolute_target(t, pid)),
+        ..app
+    })
+}
+
+#[cfg(target_os = "linux")]
+fn absolute_target(target: String, pid: u32) -> String {
+    if Path::new(&target).is_relative() {
+        if let Ok(cwd) = std::fs::read_link(format!("/proc/{}/cwd", pid)) {
+            return cwd.join(&target).to_string_lossy().into_owned();
+        }
+    }
+    target
+}
+
+#[cfg(not(target_os = "linux"))]
+fn absolute_target(target: String, _pid: u32) -> String {
+    target
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn run(exe: &str, cmdline: &str) -> Option<(String, Option<String>)> {
+        let cmdline: Vec<String> = cmdline.split_whitespace().map(String::from).collect();
+        unwrap(exe, 1, &cmdline, &[]).map(|a| (a.name, a.target))
+    }
+
+    fn name(exe: &str, cmdline: &str) -> Option<String> {
+        run(exe, cmdline).map(|(name, _)| name)
+    }
+
+    #[test]
+    fn test_runtime_kind() {
+        assert_eq!(runtime_kind("/usr/bin/python3.11"), Some("python"));
+        assert_eq!(
+            runtime_kind(r"C:\Program Files\Java\bin\javaw.exe"),
+            Some("java")
+        );
+        assert_eq!(
+            runtime_kind("/usr/lib/electron28/electron"),
+            Some("electron")
+        );
+        assert_eq!(runtime_kind("/usr/bin/pythonista"), None);
+        assert_eq!(runtime_kind("/usr/bin/firefox"), None);
+    }
+
+    #[test]
+    fn test_java() {
+        assert_eq!(
+            run(
+                "/usr/bin/java",
+                "java -Xmx2g -jar /opt/tools/dbeaver-23.1.0.jar"
+            ),
+            Some((
+                "dbeaver".into(),
+                Some("/opt/tools/dbeaver-23.1.0.jar".into())
+            ))
+        );
+        assert_eq!(
+            name(
+                "java",
+                "java -cp lib/*:idea.jar -Dfoo=bar com.intellij.idea.Main"
+            ),
+            Some("Main".into())
+        );
+        assert_eq!(
+            name("java", "java -m org.jabref/org.jabref.Launcher"),
+            Some("Launcher".into())
+        );
+
+        let environ = vec![(
+            "JAVA_MAIN_CLASS_42".to_string(),
+            "org.gradle.launcher.GradleMain".to_string(),
+        )];
+        let app = unwrap("java", 42, &["java".to_string()], &environ).unwrap();
+        assert_eq!(app.name, "GradleMain");
+    }
+
+    #[test]
+    fn test_python_and_node() {
+        assert_eq!(
+            name("python3", "python3 -u /usr/bin/meld file.txt"),
+            Some("meld".into())
+        );
+        assert_eq!(
+            name("python3", "python3 -m http.server 8000"),
+            Some("http.server".into())
+        );
+        assert_eq!(name("python3", "python3 -c print(1)"), None);
+        assert_eq!(
+            name("python", "python /home/u/proj/bot/main.py"),
+            Some("bot".into())
+        );
+
+        assert_eq!(
+            name(
+                "node",
+                "node --max-old-space-size=4096 /usr/lib/node_modules/@angular/cli/bin/ng.js serve"
+            ),
+            Some("@angular/cli".into())
+        );
+        assert_eq!(
+            name("node", "node -r ts-node/register /home/u/api/src/index.ts"),
+            Some("api".into())
+        );
+        assert_eq!(
+            name("node", "node /srv/chat-bot/dist/index.js"),
+            Some("chat-bot".into())
+        );
+        assert_eq!(name("node", "node -e console.log(1)"), None);
+    }
+
+    #[test]
+    fn test_electron_and_dotnet() {
+        assert_eq!(
+            name(
+                "/usr/lib/electron/electron",
+                "electron --enable-features=x /usr/lib/code/resources/app.asar --no-sandbox"
+            ),
+            Some("code".into())
+        );
+        let environ = vec![("CHROME_DESKTOP".to_string(), "obsidian.desktop".to_string())];
+        let cmdline = vec![
+            "electron".to_string(),
+            "/usr/lib/obsidian/app.asar".to_string(),
+        ];
+        assert_eq!(
+            unwrap("electron", 1, &cmdline, &environ).unwrap().name,
+            "obsidian"
+        );
+
+        assert_eq!(
+            name("dotnet", "dotnet exec /opt/app/Worker.dll --urls x"),
+            Some("Worker".into())
+        );
+        assert_eq!(name("dotnet", "dotnet build"), None);
+    }
+}
@@ -0,0 +1,330 @@
+use std::collections::VecDeque;
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+
+use crate::spy::catalog::AppCategory;
+use crate::spy::input::{ActivityLevel, InputCounts};
+use crate::spy::journal::JournalEntry;
+use crate::spy::model::Application;
+use crate::spy::resource::{BackgroundUsage, ResourceStats, ResourceUsage};
+
+/// 内存中最多保留的会话数量
+const MAX_SESSIONS: usize = 2048;
+
+/// 前台会话：同一应用连续处于前台的一段时间
+#[derive(Debug, Clone, Default)]
+pub struct Session {
+    pub id: u64,
+    pub name: String,     // 应用名称
+    pub title: String,    // 会话内最后一次采样到的窗口标题
+    pub path: String,     // 可执行文件完整路径
+    pub pid: Option<u32>, // 前台窗口所属进程
+    pub repo: Option<String>,
+    pub branch: Option<String>,
+    pub issue: Option<String>,            // 从分支名或标题中提取的工单号
+    pub workspace: Option<String>,        // 虚拟桌面名称
+    pub project: Option<String>,          // 工作区规则归属的项目
+    pub category: Option<AppCategory>,    // 工作区规则给出的分类，优先于应用分类
+    pub fullscreen: bool,                 // 前台窗口是否全屏
+    pub meeting: Option<u64>,             // 与会话重叠的会议
+    pub start: i64,                       // 开始时间（unix 毫秒）
+    pub end: i64,                         // 结束时间（unix 毫秒）
+    pub usage: ResourceStats,             // 前台进程树的资源占用
+    pub background: Vec<BackgroundUsage>, // 会话期间后台应用的资源占用
+    pub input: InputCounts,               // 会话期间的输入次数，会话结束时计算
+    pub activity: Option<ActivityLevel>,  // 会话的活跃程度，没有输入监控时为空
+}
+
+impl Session {
+    /// 判断时间点（unix 毫秒）是否落在会话内
+    pub fn contains(&self, ts: i64) -> bool {
+        ts >= self.start && ts <= self.end
+    }
+}
+
+#[derive(Default)]
+pub struct SessionStore {
+    next_id: u64,
+    sessions: VecDeque<Session>,
+    closed: bool, // 用户离开后结束当前会话，下一次采样开启新会话
+}
+
+impl SessionStore {
+    /// 记录一次前台采样，应用、仓库、分支、工单、工作区或全屏状态变化时开启新会话
+    pub fn record(&mut self, app: &Application, now: i64) -> &Session {
+        let issue = crate::spy::issue::issue_for(&app.title, app.branch.as_deref());
+        let workspace = app.workspace.as_ref().map(|w| w.name.clone());
+        let fullscreen = app.state.is_some_and(|s| s.fullscreen);
+        let pid = app.process.as_ref().map(|p| p.pid);
+        // 终端按进程区分会话，shell 事件按所属的终端进程统计
+        let terminal = crate::spy::shell::is_terminal_app(&app.name, &app.path);
+        let same_app = self
+            .sessions
+            .back()
+            .map(|s| {
+                s.name == app.name
+                    && s.repo == app.repo
+                    && s.branch == app.branch
+                    && s.issue == issue
+                    && s.workspace == workspace
+                    && s.fullscreen == fullscreen
+                    && (!terminal || s.pid == pid)
+            })
+            .unwrap_or(false);
+
+        if same_app && !self.closed {
+            let current = self.sessions.back_mut().unwrap();
+            current.end = now;
+            current.title = app.title.clone();
+        } else {
+            // 上一个会话在新会话开始时结束，离开期间不计入
+            if let Some(last) = self.sessions.back_mut().filter(|_| !self.closed) {
+                last.end = last.end.max(now);
+            }
+            self.closed = false;
+            self.next_id += 1;
+            self.sessions.push_back(Session {
+                id: self.next_id,
+                name: app.name.clone(),
+                title: app.title.clone(),
+                path: app.path.clone(),
+                pid,
+                repo: app.repo.clone(),
+                branch: app.branch.clone(),
+                issue,
+                project: app.workspace.as_ref().and_then(|w| w.project.clone()),
+                category: app.workspace.as_ref().and_then(|w| w.category),
+                workspace,
+                fullscreen,
+                meeting: None,
+                start: now,
+                end: now,
+                usage: ResourceStats::default(),
+                background: Vec::new(),
+                input: InputCounts::default(),
+                activity: None,
+            });
+            if self.sessions.len() > MAX_SESSIONS {
+                self.sessions.pop_front();
+            }
+        }
+
+        self.sessions.back().unwrap()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.sessions.is_empty()
+    }
+
+    /// 最早保留的会话的开始时间，之前的日志记录已经不再需要
+    pub fn oldest_start(&self) -> Option<i64> {
+        self.sessions.front().map(|s| s.start)
+    }
+
+    /// 还没有结束的当前会话
+    pub fn current(&self) -> Option<&Session> {
+        self.sessions.back().filter(|_| !self.closed)
+    }
+
+    /// 用户离开：当前会话停在最后一次活跃的采样，返回结束的会话
+    pub fn close(&mut self) -> Option<Session> {
+        let ended = self.current().cloned();
+        self.closed = true;
+        ended
+    }
+
+    /// 空闲超过阈值才判定离开，此前的空闲采样已经延长了会话，结束时截到最后一次输入
+    pub fn close_at(&mut self, last_active: i64) -> Option<Session> {
+        if let Some(current) = self.sessions.back_mut().filter(|_| !self.closed) {
+            current.end = current.end.min(last_active).max(current.start);
+        }
+        self.close()
+    }
+
+    /// 保存结束会话的输入次数和活跃程度
+    pub fn set_activity(&mut self, id: u64, input: InputCounts, activity: ActivityLevel) {
+        if let Some(session) = self.sessions.iter_mut().rev().find(|s| s.id == id) {
+            session.input = input;
+            session.activity = Some(activity);
+        }
+    }
+
+    /// 把一次资源采样累计到当前会话
+    pub fn record_usage(
+        &mut self,
+        usage: Option<&ResourceUsage>,
+        background: &[(String, ResourceUsage)],
+    ) {
+        let Some(current) = self.sessions.back_mut() else {
+            return;
+        };
+        if let Some(usage) = usage {
+            current.usage.add(usage);
+        }
+        for (name, usage) in background {
+            match current.background.iter_mut().find(|b| &b.name == name) {
+                Some(b) => b.stats.add(usage),
+                None => {
+                    let mut stats = ResourceStats::default();
+                    stats.add(usage);
+                    current.background.push(BackgroundUsage {
+                        name: name.clone(),
+                        stats,
+                    });
+                }
+            }
+        }
+    }
+
+    /// 按会议时间切分重叠的会话，会议内的部分标记为会议，会议前后的部分保持原样；
+    /// 会议进行中时不在结束处切分，避免每次检测都切出一小段
+    pub fn tag_meeting(&mut self, id: u64, start: i64, end: i64, ongoing: bool) {
+        let end = if ongoing { i64::MAX } else { end };
+        let mut index = self.sessions.len();
+        while index > 0 {
+            index -= 1;
+            let session = &self.sessions[index];
+            if session.end < start {
+                break;
+            }
+            if (session.start < start && session.end <= start)
+                || (session.start >= end && session.end > end)
+            {
+                continue;
+            }
+            if session.start < end && session.end > end {
+                self.split(index, end);
+                // 会议进行中已标记过的部分，结束之后的那段不算会议
+                let after = &mut self.sessions[index + 1];
+                if after.meeting == Some(id) {
+                    after.meeting = None;
+                }
+            }
+            if self.sessions[index].start < start {
+                self.split(index, start);
+                self.sessions[index + 1].meeting = Some(id);
+            } else {
+                self.sessions[index].meeting = Some(id);
+            }
+        }
+        while self.sessions.len() > MAX_SESSIONS {
+            self.sessions.pop_front();
+        }
+    }
+
+    /// 在 at 处把会话一分为二，后一段使用新 id；资源累计留在后一段，之后的采样继续累计到这里
+    fn split(&mut self, index: usize, at: i64) {
+        self.next_id += 1;
+        let first = &mut self.sessions[index];
+        let second = Session {
+            id: self.next_id,
+            start: at,
+            ..first.clone()
+        };
+        first.end = at;
+        first.usage = ResourceStats::default();
+        first.background = Vec::new();
+        self.sessions.insert(index + 1, second);
+    }
+
+    /// 返回与 [start, end] 有交集的会话
+    pub fn between(&self, start: i64, end: i64) -> Vec<Session> {
+        self.sessions
+            .iter()
+            .filter(|s| s.end >= start && s.start <= end)
+            .cloned()
+            .collect()
+    }
+}
+
+pub static SESSIONS: Lazy<Mutex<SessionStore>> = Lazy::new(|| Mutex::new(SessionStore::default()));
+
+/// 记录一次前台采样并写入日志，开启新会话时返回上一个结束的会话
+pub fn record_sample(app: &Application, now: i64) -> Option<Session> {
+    // 采样所有进程较慢，不持有会话锁
+    let (usage, background) = crate::spy::resource::sample(app.process.as_ref().map(|p| p.pid));
+    crate::spy::journal::append(&JournalEntry::Sample {
+        at: now,
+        app: Box::new(app.clone()),
+        usage: usage.clone(),
+        background: background.clone(),
+    });
+    let mut sessions = SESSIONS.lock().unwrap();
+    let previous = sessions.current().cloned();
+    let current = sessions.record(app, now).id;
+    sessions.record_usage(usage.as_ref(), &background);
+    drop(sessions);
+    let mut ended = previous.filter(|p| p.id != current)?;
+    ended.end = ended.end.max(now);
+    Some(finish(ended))
+}
+
+/// 用户离开，结束当前会话，会话截到最后一次输入的时间
+pub fn close(last_active: i64) -> Option<Session> {
+    let ended = SESSIONS.lock().unwrap().close_at(last_active)?;
+    Some(finish(ended))
+}
+
+/// 按输入监控计算结束会话的活跃程度，保存到会话上
+fn finish(mut ended: Session) -> Session {
+    if let Some(metrics) = crate::spy::input::session_activity(&ended) {
+        ended.input = metrics.input;
+        ended.activity = Some(metrics.level);
+        SESSIONS
+            .lock()
+            .unwrap()
+            .set_activity(ended.id, metrics.input, metrics.level);
+    }
+    ended
+}
+
+pub fn sessions_between(start: i64, end: i64) -> Vec<Session> {
+    SESSIONS.lock().unwrap().between(start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_split_sessions_at_meeting() {
+        let app = |name: &str| Application {
+            name: name.to_string(),
+            ..Default::default()
+        };
+        let mut store = SessionStore::default();
+        store.record(&app("code"), 0);
+        store.record(&app("code"), 60_000);
+        store.record(&app("zoom"), 60_000);
+        store.record(&app("zoom"), 90_000);
+
+        // 会议进行中：会议开始之后的部分都算会议
+        store.tag_meeting(7, 30_000, 80_000, true);
+        store.record(&app("zoom"), 150_000);
+        // 会议结束：会议之后的部分恢复为普通时间
+        store.tag_meeting(7, 30_000, 120_000, false);
+
+        let spans: Vec<(&str, i64, i64, Option<u64>)> = store
+            .sessions
+            .iter()
+            .map(|s| (s.name.as_str(), s.start, s.end, s.meeting))
+            .collect();
+        assert_eq!(
+            spans,
+            [
+                ("code", 0, 30_000, None),
+                ("code", 30_000, 60_000, Some(7)),
+                ("zoom", 60_000, 120_000, Some(7)),
+                ("zoom", 120_000, 150_000, None),
+            ]
+        );
+        assert_eq!(store.current().unwrap().start, 120_000);
+
+        // 离开时会话截到最后一次输入，不包括判定离开前的空闲采样
+        store.record(&app("zoom"), 450_000);
+        let ended = store.close_at(200_000).unwrap();
+        assert_eq!((ended.start, ended.end), (120_000, 200_000));
+        assert!(store.current().is_none());
+    }
+}
@@ -0,0 +1,481 @@
+//! 终端 shell 集成：接收 shell 钩子上报的命令开始/结束事件，
+//! 把终端会话按命令和工作目录拆分统计。
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+
+use crate::spy::session::Session;
+use crate::spy::shell_protocol::ShellMessage;
+
+/// 内存中最多保留的 shell 事件数量
+const MAX_SHELL_EVENTS: usize = 20_000;
+
+/// 常见终端模拟器的应用名称（小写，不含扩展名）
+const TERMINAL_APPS: &[&str] = &[
+    "gnome-terminal",
+    "gnome-terminal-server",
+    "kgx",
+    "ptyxis",
+    "konsole",
+    "xterm",
+    "uxterm",
+    "urxvt",
+    "alacritty",
+    "kitty",
+    "wezterm",
+    "wezterm-gui",
+    "foot",
+    "tilix",
+    "terminator",
+    "xfce4-terminal",
+    "lxterminal",
+    "mate-terminal",
+    "qterminal",
+    "st",
+    "windowsterminal",
+    "terminal",
+    "iterm2",
+    "warp",
+    "ghostty",
+];
+
+/// 终端会话按命令和目录拆分后的时间
+#[derive(Debug, Clone)]
+pub struct TerminalActivity {
+    pub app_name: String,        // 终端应用
+    pub command: Option<String>, // 正在执行的程序，空表示停留在提示符
+    pub cwd: String,             // 工作目录
+    pub seconds: f64,
+}
+
+pub static SHELL_EVENTS: Lazy<Mutex<VecDeque<ShellMessage>>> =
+    Lazy::new(|| Mutex::new(VecDeque::new()));
+
+/// shell pid → 收到事件时的祖先进程 pid，用来判断 shell 属于哪个终端
+static SHELL_ANCESTORS: Lazy<Mutex<HashMap<u32, Vec<u32>>>> =
+    Lazy::new(|| Mutex::new(HashMap::new()));
+
+/// 按应用名称或可执行文件名判断是否为终端
+pub fn is_terminal_app(name: &str, path: &str) -> bool {
+    let stem = std::path::Path::new(path)
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    TERMINAL_APPS.contains(&name.to_lowercase().as_str()) || TERMINAL_APPS.contains(&stem.as_str())
+}
+
+/// 各 shell 的钩子脚本
+pub fn hook_script(shell: &str) -> Option<&'static str> {
+    match shell {
+        "bash" => Some(include_str!("../../shell/spy.bash")),
+        "zsh" => Some(include_str!("../../shell/spy.zsh")),
+        "fish" => Some(include_str!("../../shell/spy.fish")),
+        _ => None,
+    }
+}
+
+pub fn store_event(message: ShellMessage) {
+    let pid = message.pid();
+    // shell 退出后就查不到父进程了，第一次收到事件时记下
+    let known = SHELL_ANCESTORS.lock().unwrap().contains_key(&pid);
+    if !known {
+        let ancestors = crate::spy::process::process_info(pid)
+            .map(|p| p.parents.iter().map(|parent| parent.pid).collect())
+            .unwrap_or_default();
+        SHELL_ANCESTORS.lock().unwrap().insert(pid, ancestors);
+    }
+
+    let mut events = SHELL_EVENTS.lock().unwrap();
+    events.push_back(message);
+    if events.len() > MAX_SHELL_EVENTS {
+        if let Some(dropped) = events.pop_front() {
+            if !events.iter().any(|e| e.pid() == dropped.pid()) {
+                SHELL_ANCESTORS.lock().unwrap().remove(&dropped.pid());
+            }
+        }
+    }
+}
+
+/// 最近一次 shell 事件的工作目录
+pub fn latest_cwd() -> Option<String> {
+    let events = SHELL_EVENTS.lock().unwrap();
+    events.iter().max_by_key(|e| e.time()).map(|e| match e {
+        ShellMessage::Start { cwd, .. } | ShellMessage::End { cwd, .. } => cwd.clone(),
+    })
+}
+
+/// 从命令行中提取程序名，跳过环境变量赋值和 sudo 等前缀
+pub fn command_program(command: &str) -> Option<String> {
+    command
+        .split_whitespace()
+        .find(|w| !w.contains('=') && !matches!(*w, "sudo" | "doas" | "env" | "time" | "nohup"))
+        .map(|w| {
+            std::path::Path::new(w)
+                .file_name()
+                .map(|f| f.to_string_lossy().into_owned())
+                .unwrap_or_else(|| w.to_string())
+        })
+}
+
+/// 把终端会话按 shell 事件切分，统计各命令、各目录的停留时间
+///
+/// 只统计前台终端进程的子孙 shell 的事件（ancestors 为各 shell 的祖先 pid），
+/// 同一终端内只认为最近一次有事件的 shell 处于活动状态。
+pub fn terminal_activity(
+    events: &[ShellMessage],
+    ancestors: &HashMap<u32, Vec<u32>>,
+    sessions: &[Session],
+    start: i64,
+    end: i64,
+) -> Vec<TerminalActivity> {
+    let mut sorted: Vec<&ShellMessage> = events.iter().collect();
+    sorted.sort_by_key(|e| e.time());
+
+    let mut totals: HashMap<(String, Option<String>, String), f64> = HashMap::new();
+
+    for session in sessions
+        .iter()
+        .filter(|s| is_terminal_app(&s.name, &s.path))
+    {
+        let from = session.start.max(start);
+        let until = session.end.min(end);
+        // 不知道终端进程时无法归属任何 shell
+        let Some(terminal) = session.pid.filter(|_| until > from) else {
+            continue;
+        };
+        let sorted: Vec<&ShellMessage> = sorted
+            .iter()
+            .filter(|e| {
+                ancestors
+                    .get(&e.pid())
+                    .is_some_and(|a| a.contains(&terminal))
+            })
+            .copied()
+            .collect();
+
+        // 会话开始前的最后一个事件决定初始状态
+        let mut state = sorted.iter().rev().find(|e| e.time() <= from).copied();
+        let mut cursor = from;
+        let inside = sorted
+            .iter()
+            .filter(|e| e.time() > from && e.time() < until)
+            .copied();
+
+        for event in inside.chain(std::iter::once(&ShellMessage::End {
+            pid: 0,
+            cwd: String::new(),
+            exit_code: 0,
+            time: until,
+        })) {
+            if let Some(current) = state {
+                let (command, cwd) = match current {
+                    ShellMessage::Start { command, cwd, .. } => (command_program(command), cwd),
+                    ShellMessage::End { cwd, .. } => (None, cwd),
+                };
+                *totals
+                    .entry((session.name.clone(), command, cwd.clone()))
+                    .or_default() += (event.time() - cursor) as f64 / 1000.0;
+            }
+            cursor = event.time();
+            state = Some(event);
+        }
+    }
+
+    let mut result: Vec<TerminalActivity> = totals
+        .into_iter()
+        .filter(|(_, seconds)| *seconds > 0.0)
+        .map(|((app_name, command, cwd), seconds)| TerminalActivity {
+            app_name,
+            command,
+            cwd,
+            seconds,
+        })
+        .collect();
+    result.sort_by(|a, b| b.seconds.total_cmp(&a.seconds));
+    result
+}
+
+pub fn get_terminal_activity(start: i64, end: i64) -> Vec<TerminalActivity> {
+    let events: Vec<ShellMessage> = SHELL_EVENTS.lock().unwrap().iter().cloned().collect();
+    let ancestors = SHELL_ANCESTORS.lock().unwrap().clone();
+    let sessions = crate::spy::session::sessions_between(start, end);
+    terminal_activity(&events, &ancestors, &sessions, start, end)
+}
+
+#[cfg(unix)]
+mod listener {
+    use std::io::{BufRead, BufReader};
+    use std::os::unix::fs::{DirBuilderExt, PermissionsExt};
+    use std::os::unix::net::{UnixListener, UnixStream};
+    use std::path::PathBuf;
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Arc, Mutex};
+
+    use once_cell::sync::Lazy;
+
+    use crate::spy::shell_protocol::{check_socket_dir, ShellMessage};
+
+    static LISTENER: Lazy<Mutex<Option<ListenerHandle>>> = Lazy::new(|| Mutex::new(None));
+
+    struct ListenerHandle {
+        path: PathBuf,
+        stop: Arc<AtomicBool>,
+    }
+
+    pub fn start(path: PathBuf) -> anyhow::Result<PathBuf> {
+        let mut handle = LISTENER.lock().unwrap();
+        if let Some(h) = &*handle {
+            anyhow::bail!("shell listener already running at {}", h.path.display());
+        }
+
+        // 只在目录不存在时创建为仅自己可访问，不修改已有目录（例如 /tmp）的权限；
+        // 已有的目录不是自己所有或权限不是 0700 时拒绝监听
+        if let Some(dir) = path.parent() {
+            if std::fs::symlink_metadata(dir).is_err() {
+                std::fs::DirBuilder::new()
+                    .recursive(true)
+                    .mode(0o700)
+                    .create(dir)?;
+            }
+            check_socket_dir(dir)?;
+        }
+        // 清理上次异常退出遗留的 socket 文件
+        if path.exists() {
+            std::fs::remove_file(&path)?;
+        }
+        let listener = UnixListener::bind(&path)?;
+        std::fs::set_permissions(&path, std::fs::Permissions::from_mode(0o600))?;
+
+        let stop = Arc::new(AtomicBool::new(false));
+        let stop_flag = stop.clone();
+        std::thread::spawn(move || {
+            for stream in listener.incoming() {
+                if stop_flag.load(Ordering::SeqCst) {
+                    break;
+                }
+                match stream {
+                    Ok(stream) => {
+                        std::thread::spawn(move || handle_connection(stream));
+                    }
+                    Err(e) => println!("[shell] accept error: {}", e),
+                }
+            }
+        });
+
+        println!("[rust] shell listener at {}", path.display());
+        *handle = Some(ListenerHandle {
+            path: path.clone(),
+            stop,
+        });
+        Ok(path)
+    }
+
+    pub fn stop() {
+        if let Some(h) = LISTENER.lock().unwrap().take() {
+            h.stop.store(true, Ordering::SeqCst);
+            // 连接一次以唤醒阻塞中的 accept
+            let _ = UnixStream::connect(&h.path);
+            let _ = std::fs::remove_file(&h.path);
+        }
+    }
+
+    fn handle_connection(stream: UnixStream) {
+        for line in BufReader::new(stream).lines() {
+            let Ok(line) = line else {
+                break;
+            };
+            match serde_json::from_str::<ShellMessage>(&line) {
+                Ok(message) => super::store_event(message),
+                Err(e) => println!("[shell] invalid message: {}", e),
+            }
+        }
+    }
+}
+
+/// 启动 shell 事件监听，返回 socket 路径
+#[cfg(unix)]
+pub fn start_listener(path: Option<String>) -> anyhow::Result<String> {
+    let path = path
+        .map(std::path::PathBuf::from)
+        .unwrap_or_else(crate::spy::shell_protocol::default_socket_path);
+    listener::start(path).map(|p| p.to_string_lossy().into_owned())
+}
+
+#[cfg(not(unix))]
+pub fn start_listener(_path: Option<String>) -> anyhow::Result<String> {
+    anyhow::bail!("shell integration is only supported on unix platforms")
+}
+
+pub fn stop_listener() {
+    #[cfg(unix)]
+    listener::stop();
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn start(pid: u32, cwd: &str, command: &str, time: i64) -> ShellMessage {
+        ShellMessage::Start {
+            pid,
+            cwd: cwd.to_string(),
+            command: command.to_string(),
+            time,
+        }
+    }
+
+    fn end(pid: u32, cwd: &str, time: i64) -> ShellMessage {
+        ShellMessage::End {
+            pid,
+            cwd: cwd.to_string(),
+            exit_code: 0,
+            time,
+        }
+    }
+
+    #[test]
+    fn test_command_program() {
+        assert_eq!(
+            command_program("cargo build --release").as_deref(),
+            Some("cargo")
+        );
+        assert_eq!(
+            command_program("RUST_LOG=debug sudo /usr/bin/make -j8").as_deref(),
+            Some("make")
+        );
+        assert_eq!(command_program("   "), None);
+    }
+
+    #[test]
+    fn test_terminal_activity_by_command_and_cwd() {
+        let events = vec![
+            end(1, "/home/u/spy", 0),
+            start(1, "/home/u/spy", "cargo test", 10_000),
+            end(1, "/home/u/spy", 40_000),
+            start(1, "/home/u/spy", "cd ../other", 50_000),
+            end(1, "/home/u/other", 50_000),
+            // 另一个终端里的 shell 不计入前台终端
+            start(2, "/home/u/build", "make", 20_000),
+        ];
+        let ancestors = HashMap::from([(1, vec![100, 1]), (2, vec![200, 1])]);
+        let sessions = vec![
+            Session {
+                id: 1,
+                name: "gnome-terminal-server".to_string(),
+                pid: Some(100),
+                start: 5_000,
+                end: 60_000,
+                ..Default::default()
+            },
+            Session {
+                id: 2,
+                name: "firefox".to_string(),
+                start: 60_000,
+                end: 90_000,
+                ..Default::default()
+            },
+        ];
+
+        let activity = terminal_activity(&events, &ancestors, &sessions, 0, 100_000);
+        let find = |command: Option<&str>, cwd: &str| {
+            activity
+                .iter()
+                .find(|a| a.command.as_deref() == command && a.cwd == cwd)
+                .map(|a| a.seconds)
+        };
+        assert_eq!(find(Some("cargo"), "/home/u/spy"), Some(30.0));
+        assert_eq!(find(None, "/home/u/spy"), Some(15.0));
+        assert_eq!(find(None, "/home/u/other"), Some(10.0));
+        assert!(activity
+            .iter()
+            .all(|a| a.app_name == "gnome-terminal-server"));
+        assert_eq!(find(Some("make"), "/home/u/build"), None);
+
+        // 没有进程信息的终端会话不归属任何 shell
+        let sessions = vec![Session {
+            pid: None,
+            ..sessions[0].clone()
+        }];
+        assert!(terminal_activity(&events, &ancestors, &sessions, 0, 100_000).is_empty());
+    }
+
+    #[test]
+    fn test_hook_scripts() {
+        for shell in ["bash", "zsh", "fish"] {
+            assert!(hook_script(shell).unwrap().contains("spy-shell-hook"));
+        }
+        assert!(hook_script("tcsh").is_none());
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_listener_receives_messages() {
+        use std::io::Write;
+
+        use std::os::unix::fs::PermissionsExt;
+
+        // 在新建的子目录中监听，不改动系统临时目录的权限
+        let temp = std::env::temp_dir();
+        let temp_mode = std::fs::metadata(&temp).unwrap().permissions().mode();
+        let dir = temp.join(format!("spy-shell-test-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        let path = dir.join("shell.sock");
+        start_listener(Some(path.to_string_lossy().into_owned())).unwrap();
+        let dir_mode = std::fs::metadata(&dir).unwrap().permissions().mode();
+
+        let message = start(4242, "/tmp", "make -j8", 1_000);
+        let mut stream = std::os::unix::net::UnixStream::connect(&path).unwrap();
+        writeln!(stream, "{}", serde_json::to_string(&message).unwrap()).unwrap();
+        drop(stream);
+
+        let received = (0..50).any(|_| {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+            SHELL_EVENTS.lock().unwrap().iter().any(|e| e.pid() == 4242)
+        });
+        stop_listener();
+        let _ = std::fs::remove_dir_all(&dir);
+        assert!(received);
+        assert!(!path.exists());
+        assert_eq!(dir_mode & 0o777, 0o700);
+        assert_eq!(
+            std::fs::metadata(&temp).unwrap().permissions().mode(),
+            temp_mode
+        );
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn test_refuse_unsafe_socket_dir() {
+        use std::os::unix::fs::PermissionsExt;
+
+        use crate::spy::shell_protocol::check_socket_dir;
+
+        let dir = std::env::temp_dir().join(format!("spy-shell-dir-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&dir);
+        std::fs::create_dir(&dir).unwrap();
+        let link = dir.with_extension("link");
+        let _ = std::fs::remove_file(&link);
+        std::os::unix::fs::symlink(&dir, &link).unwrap();
+
+        // 其他用户也能进入的目录
+        std::fs::set_permissions(&dir, std::fs::Permissions::from_mode(0o755)).unwrap();
+        let open = check_socket_dir(&dir);
+        std::fs::set_permissions(&dir, std::fs::Permissions::from_mode(0o700)).unwrap();
+        let private = check_socket_dir(&dir);
+        // 指向安全目录的符号链接也拒绝
+        let linked = check_socket_dir(&link);
+        // 其他用户所有、权限为 0700 的目录（需要 root 才能修改所有者，否则跳过）
+        let foreign = std::os::unix::fs::chown(&dir, Some(65534), None)
+            .ok()
+            .map(|_| check_socket_dir(&dir));
+
+        let _ = std::fs::remove_file(&link);
+        let _ = std::fs::remove_dir_all(&dir);
+        assert!(open.is_err());
+        assert!(private.is_ok());
+        assert!(linked.is_err());
+        assert!(foreign.is_none_or(|r| r.is_err()));
+    }
+}
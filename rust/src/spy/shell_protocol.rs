@@ -0,0 +1,89 @@
+//! Shell 钩子与追踪器之间的消息格式
+//!
+//! 本文件同时被 `src/bin/spy-shell-hook.rs` 以 `#[path]` 方式引用，只能依赖 std 和 serde。
+
+use std::path::PathBuf;
+
+use serde::{Deserialize, Serialize};
+
+/// 覆盖默认 socket 路径的环境变量
+pub const SOCKET_ENV: &str = "SPY_SHELL_SOCKET";
+
+/// 每行一个 JSON 消息
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "event", rename_all = "snake_case")]
+pub enum ShellMessage {
+    /// 命令开始执行
+    Start {
+        pid: u32,
+        cwd: String,
+        command: String,
+        time: i64, // unix 毫秒
+    },
+    /// 命令结束，回到提示符
+    End {
+        pid: u32,
+        cwd: String,
+        exit_code: i32,
+        time: i64,
+    },
+}
+
+impl ShellMessage {
+    pub fn pid(&self) -> u32 {
+        match self {
+            ShellMessage::Start { pid, .. } | ShellMessage::End { pid, .. } => *pid,
+        }
+    }
+
+    pub fn time(&self) -> i64 {
+        match self {
+            ShellMessage::Start { time, .. } | ShellMessage::End { time, .. } => *time,
+        }
+    }
+}
+
+#[cfg(unix)]
+extern "C" {
+    fn getuid() -> u32;
+}
+
+/// 检查 socket 所在目录：必须是当前用户所有、权限为 0700 的真实目录（不是符号链接）。
+/// 没有 XDG_RUNTIME_DIR 时目录位于共享的临时目录，其他用户可能抢先创建目录并监听 socket，
+/// 接收所有命令行和工作目录，所以监听和连接之前都要检查，不满足时拒绝
+#[cfg(unix)]
+pub fn check_socket_dir(dir: &std::path::Path) -> std::io::Result<()> {
+    use std::os::unix::fs::{MetadataExt, PermissionsExt};
+
+    let meta = std::fs::symlink_metadata(dir)?;
+    // SAFETY: getuid 没有参数，总是成功
+    let uid = unsafe { getuid() };
+    if !meta.file_type().is_dir() || meta.uid() != uid || meta.permissions().mode() & 0o777 != 0o700
+    {
+        return Err(std::io::Error::new(
+            std::io::ErrorKind::PermissionDenied,
+            format!(
+                "unsafe socket dir {}: must be a directory owned by uid {} with mode 0700",
+                dir.display(),
+                uid
+            ),
+        ));
+    }
+    Ok(())
+}
+
+/// 默认 socket 路径：`$XDG_RUNTIME_DIR/spy_on_your_work/shell.sock`，
+/// 没有 XDG_RUNTIME_DIR 时退回到临时目录下按用户区分的目录
+pub fn default_socket_path() -> PathBuf {
+    if let Some(p) = std::env::var_os(SOCKET_ENV) {
+        return PathBuf::from(p);
+    }
+    let dir = match std::env::var_os("XDG_RUNTIME_DIR") {
+        Some(runtime) => PathBuf::from(runtime).join("spy_on_your_work"),
+        None => {
+            let user = std::env::var("USER").unwrap_or_else(|_| "default".to_string());
+            std::env::temp_dir().join(format!("spy_on_your_work-{}", user))
+        }
+    };
+    dir.join("shell.sock")
+}
@@ -0,0 +1,321 @@
+//! 可见但未获得焦点的窗口时间
+//!
+//! 副屏上播放的视频、编辑器旁边的参考文档不在前台，前台采样统计不到。开启后每次采样时
+//! 按窗口叠放顺序和位置计算各窗口未被遮挡的比例，把可见的非前台窗口单独记为"可见时间"。
+
+use std::collections::{HashMap, VecDeque};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use serde::{Deserialize, Serialize};
+
+use crate::spy::session::Session;
+
+/// 未被遮挡的面积达到窗口面积的这个比例才算可见
+const MIN_VISIBLE_RATIO: f32 = 0.2;
+
+/// 内存中最多保留的可见区间数量
+const MAX_INTERVALS: usize = 4096;
+
+/// 屏幕坐标系中的矩形
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Rect {
+    pub x: i32,
+    pub y: i32,
+    pub width: i32,
+    pub height: i32,
+}
+
+impl Rect {
+    pub fn area(&self) -> i64 {
+        self.width.max(0) as i64 * self.height.max(0) as i64
+    }
+
+    pub fn intersect(&self, other: &Rect) -> Option<Rect> {
+        let x = self.x.max(other.x);
+        let y = self.y.max(other.y);
+        let right = (self.x + self.width).min(other.x + other.width);
+        let bottom = (self.y + self.height).min(other.y + other.height);
+        (right > x && bottom > y).then_some(Rect {
+            x,
+            y,
+            width: right - x,
+            height: bottom - y,
+        })
+    }
+
+    /// self 减去 other，结果最多为 4 个不相交的矩形
+    pub fn subtract(&self, other: &Rect) -> Vec<Rect> {
+        let Some(cut) = self.intersect(other) else {
+            return vec![*self];
+        };
+        let mut parts = Vec::new();
+        let right = self.x + self.width;
+        let bottom = self.y + self.height;
+        // 上、下两条整宽，左、右两块夹在中间
+        if cut.y > self.y {
+            parts.push(Rect {
+                x: self.x,
+                y: self.y,
+                width: self.width,
+                height: cut.y - self.y,
+            });
+        }
+        if cut.y + cut.height < bottom {
+            parts.push(Rect {
+                x: self.x,
+                y: cut.y + cut.height,
+                width: self.width,
+                height: bottom - cut.y - cut.height,
+            });
+        }
+        if cut.x > self.x {
+            parts.push(Rect {
+                x: self.x,
+                y: cut.y,
+                width: cut.x - self.x,
+                height: cut.height,
+            });
+        }
+        if cut.x + cut.width < right {
+            parts.push(Rect {
+                x: cut.x + cut.width,
+                y: cut.y,
+                width: right - cut.x - cut.width,
+                height: cut.height,
+            });
+        }
+        parts
+    }
+}
+
+/// 计算每个窗口在屏幕内未被上层窗口遮挡的面积比例，windows 按叠放顺序从下到上排列；
+/// screens 为各显示器区域，显示器之间的空隙不算可见，镜像显示器重叠的部分只算一次
+pub fn visible_ratios(windows: &[Rect], screens: &[Rect]) -> Vec<f32> {
+    let mut disjoint: Vec<Rect> = Vec::new();
+    for screen in screens {
+        let mut parts = vec![*screen];
+        for seen in &disjoint {
+            parts = parts.iter().flat_map(|p| p.subtract(seen)).collect();
+        }
+        disjoint.extend(parts);
+    }
+    let screens = disjoint;
+    windows
+        .iter()
+        .enumerate()
+        .map(|(i, rect)| {
+            if rect.area() == 0 {
+                return 0.0;
+            }
+            let mut visible: Vec<Rect> = screens.iter().filter_map(|s| rect.intersect(s)).collect();
+            for above in &windows[i + 1..] {
+                visible = visible.iter().flat_map(|r| r.subtract(above)).collect();
+                if visible.is_empty() {
+                    break;
+                }
+            }
+            let area: i64 = visible.iter().map(|r| r.area()).sum();
+            area as f32 / rect.area() as f32
+        })
+        .collect()
+}
+
+pub fn is_visible(ratio: f32) -> bool {
+    ratio >= MIN_VISIBLE_RATIO
+}
+
+/// 应用的一段可见区间
+#[derive(Debug, Clone, PartialEq)]
+struct VisibleInterval {
+    name: String,
+    start: i64,
+    end: i64,
+}
+
+/// 应用的可见时间和前台时间
+#[derive(Debug, Clone, PartialEq)]
+pub struct VisibleTime {
+    pub name: String,
+    pub visible_seconds: i64, // 可见但不在前台
+    pub focused_seconds: i64,
+}
+
+#[derive(Default)]
+pub struct VisibilityStore {
+    intervals: VecDeque<VisibleInterval>,   // 已结束的区间
+    open: HashMap<String, VisibleInterval>, // 上次采样时仍可见的应用
+}
+
+impl VisibilityStore {
+    /// 记录一次采样时可见的非前台应用：连续可见时延长区间，不再可见时在本次采样时结束
+    pub fn record(&mut self, names: &[String], now: i64) {
+        let mut open = HashMap::new();
+        for name in names {
+            if open.contains_key(name) {
+                continue;
+            }
+            let mut interval = self.open.remove(name).unwrap_or(VisibleInterval {
+                name: name.clone(),
+                start: now,
+                end: now,
+            });
+            interval.end = now;
+            open.insert(name.clone(), interval);
+        }
+        for (_, mut interval) in std::mem::replace(&mut self.open, open) {
+            interval.end = now;
+            self.intervals.push_back(interval);
+        }
+        while self.intervals.len() > MAX_INTERVALS {
+            self.intervals.pop_front();
+        }
+    }
+
+    fn visible_millis(&self, start: i64, end: i64) -> HashMap<String, i64> {
+        let mut result: HashMap<String, i64> = HashMap::new();
+        for interval in self.intervals.iter().chain(self.open.values()) {
+            let from = interval.start.max(start);
+            let to = interval.end.min(end);
+            if to > from {
+                *result.entry(interval.name.clone()).or_default() += to - from;
+            }
+        }
+        result
+    }
+}
+
+/// 合并可见时间与前台时间，按可见时间降序
+pub fn visible_time(
+    store: &VisibilityStore,
+    sessions: &[Session],
+    start: i64,
+    end: i64,
+) -> Vec<VisibleTime> {
+    let visible = store.visible_millis(start, end);
+    let mut focused: HashMap<String, i64> = HashMap::new();
+    for session in sessions {
+        let from = session.start.max(start);
+        let to = session.end.min(end);
+        if to > from {
+            *focused.entry(session.name.clone()).or_default() += to - from;
+        }
+    }
+    let mut result: Vec<VisibleTime> = visible
+        .iter()
+        .map(|(name, millis)| VisibleTime {
+            name: name.clone(),
+            visible_seconds: millis / 1000,
+            focused_seconds: focused.get(name).copied().unwrap_or(0) / 1000,
+        })
+        .collect();
+    result.sort_by(|a, b| {
+        b.visible_seconds
+            .cmp(&a.visible_seconds)
+            .then_with(|| a.name.cmp(&b.name))
+    });
+    result
+}
+
+/// 是否统计可见时间，默认关闭
+pub static VISIBLE_TRACKING: AtomicBool = AtomicBool::new(false);
+
+pub static VISIBILITY: Lazy<Mutex<VisibilityStore>> =
+    Lazy::new(|| Mutex::new(VisibilityStore::default()));
+
+pub fn set_tracking(enabled: bool) {
+    VISIBLE_TRACKING.store(enabled, Ordering::SeqCst);
+}
+
+/// 采样可见的非前台窗口，focused 为前台应用名称
+pub fn record_sample(focused: &str) {
+    if !VISIBLE_TRACKING.load(Ordering::SeqCst) {
+        return;
+    }
+    let now = chrono::Local::now().timestamp_millis();
+    let names: Vec<String> = crate::spy::platform::visible_windows()
+        .into_iter()
+        .filter(|w| !w.focused && w.app.name != focused)
+        .map(|w| w.app.name)
+        .collect();
+    VISIBILITY.lock().unwrap().record(&names, now);
+}
+
+pub fn get_visible_time(start: i64, end: i64) -> Vec<VisibleTime> {
+    let sessions = crate::spy::session::sessions_between(start, end);
+    visible_time(&VISIBILITY.lock().unwrap(), &sessions, start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn rect(x: i32, y: i32, width: i32, height: i32) -> Rect {
+        Rect {
+            x,
+            y,
+            width,
+            height,
+        }
+    }
+
+    #[test]
+    fn test_visible_ratios() {
+        let screen = [rect(0, 0, 1000, 1000)];
+        let windows = [
+            rect(0, 0, 1000, 1000),  // 被完全遮挡的全屏窗口
+            rect(0, 0, 500, 1000),   // 左半边
+            rect(250, 0, 750, 1000), // 盖住左半边的右半部分
+            rect(500, 0, 500, 1000), // 最上层
+        ];
+        let ratios = visible_ratios(&windows, &screen);
+        let expected = [0.0, 0.5, 1.0 / 3.0, 1.0];
+        assert!(ratios
+            .iter()
+            .zip(expected)
+            .all(|(a, b)| (a - b).abs() < 1e-6));
+
+        // 屏幕外的部分不算可见
+        let ratios = visible_ratios(&[rect(800, 0, 400, 100)], &screen);
+        assert_eq!(ratios, [0.5]);
+
+        // 高度不同的两块显示器之间的空隙不算可见，镜像显示器不重复计算
+        let monitors = [
+            rect(0, 0, 1000, 1000),
+            rect(1000, 0, 1000, 500),
+            rect(0, 0, 1000, 1000),
+        ];
+        let ratios = visible_ratios(&[rect(500, 0, 1000, 1000)], &monitors);
+        assert_eq!(ratios, [0.75]);
+        assert!(is_visible(0.5) && !is_visible(0.1));
+    }
+
+    #[test]
+    fn test_subtract() {
+        let parts = rect(0, 0, 10, 10).subtract(&rect(3, 3, 4, 4));
+        assert_eq!(parts.len(), 4);
+        assert_eq!(parts.iter().map(|r| r.area()).sum::<i64>(), 100 - 16);
+        assert_eq!(rect(0, 0, 10, 10).subtract(&rect(20, 20, 5, 5)).len(), 1);
+        assert!(rect(2, 2, 3, 3).subtract(&rect(0, 0, 10, 10)).is_empty());
+    }
+
+    #[test]
+    fn test_record_intervals() {
+        let mut store = VisibilityStore::default();
+        let names = |v: &[&str]| v.iter().map(|s| s.to_string()).collect::<Vec<_>>();
+        store.record(&names(&["mpv", "evince"]), 0);
+        store.record(&names(&["mpv", "evince"]), 60_000);
+        store.record(&names(&["mpv"]), 120_000);
+        store.record(&names(&[]), 180_000);
+        store.record(&names(&["evince"]), 240_000);
+        store.record(&names(&["evince"]), 300_000);
+
+        let result = visible_time(&store, &[], 0, 1_000_000);
+        let seconds: Vec<(&str, i64)> = result
+            .iter()
+            .map(|v| (v.name.as_str(), v.visible_seconds))
+            .collect();
+        assert_eq!(seconds, [("evince", 180), ("mpv", 180)]);
+    }
+}
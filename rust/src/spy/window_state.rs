@@ -0,0 +1,138 @@
+//! 窗口状态与全屏时间
+//!
+//! 每次采样记录前台窗口是否全屏、最大化、最小化、置顶以及窗口位置。全屏时间作为单独的
+//! 统计维度，全屏占比高的应用多为游戏或视频播放，可作为分类依据。
+
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use crate::spy::monitor::Monitor;
+use crate::spy::session::Session;
+use crate::spy::visibility::Rect;
+
+/// 全屏时间占前台时间达到这个比例时视为游戏 / 视频类应用
+const IMMERSIVE_RATIO: f64 = 0.5;
+
+/// 窗口状态
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct WindowState {
+    pub fullscreen: bool,
+    pub maximized: bool, // 水平和垂直方向都最大化
+    pub minimized: bool,
+    pub above: bool, // 置顶
+}
+
+/// 按应用统计的全屏时间
+#[derive(Debug, Clone)]
+pub struct FullscreenTime {
+    pub name: String,
+    pub fullscreen_seconds: f64,
+    pub seconds: f64,    // 前台总时间
+    pub immersive: bool, // 全屏占比高，可能是游戏或视频
+}
+
+/// 窗口管理器没有标记全屏时，窗口覆盖整个显示器也视为全屏（无边框全屏游戏、浏览器视频）
+pub fn covers_monitor(window: &Rect, monitors: &[Monitor]) -> bool {
+    monitors.iter().any(|m| {
+        let screen = m.rect();
+        window.intersect(&screen) == Some(screen)
+    })
+}
+
+/// 统计 [start, end]（unix 毫秒）内各应用的全屏时间，按全屏时间降序，不含从未全屏的应用
+pub fn fullscreen_time(sessions: &[Session], start: i64, end: i64) -> Vec<FullscreenTime> {
+    let mut totals: HashMap<String, (i64, i64)> = HashMap::new();
+    for s in sessions {
+        let ms = s.end.min(end) - s.start.max(start);
+        if ms <= 0 {
+            continue;
+        }
+        let entry = totals.entry(s.name.clone()).or_default();
+        entry.1 += ms;
+        if s.fullscreen {
+            entry.0 += ms;
+        }
+    }
+
+    let mut result: Vec<FullscreenTime> = totals
+        .into_iter()
+        .filter(|(_, (fullscreen, _))| *fullscreen > 0)
+        .map(|(name, (fullscreen, total))| FullscreenTime {
+            name,
+            fullscreen_seconds: fullscreen as f64 / 1000.0,
+            seconds: total as f64 / 1000.0,
+            immersive: fullscreen as f64 >= total as f64 * IMMERSIVE_RATIO,
+        })
+        .collect();
+    result.sort_by(|a, b| b.fullscreen_seconds.total_cmp(&a.fullscreen_seconds));
+    result
+}
+
+pub fn get_fullscreen_time(start: i64, end: i64) -> Vec<FullscreenTime> {
+    let sessions = crate::spy::session::sessions_between(start, end);
+    fullscreen_time(&sessions, start, end)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(name: &str, fullscreen: bool, start: i64, end: i64) -> Session {
+        Session {
+            name: name.to_string(),
+            fullscreen,
+            start,
+            end,
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_fullscreen_time() {
+        let sessions = [
+            session("mpv", false, 0, 60_000),
+            session("mpv", true, 60_000, 300_000),
+            session("firefox", false, 300_000, 400_000),
+            session("firefox", true, 400_000, 420_000),
+            session("code", false, 420_000, 600_000),
+        ];
+        let result = fullscreen_time(&sessions, 0, 600_000);
+        let summary: Vec<(&str, f64, f64, bool)> = result
+            .iter()
+            .map(|f| {
+                (
+                    f.name.as_str(),
+                    f.fullscreen_seconds,
+                    f.seconds,
+                    f.immersive,
+                )
+            })
+            .collect();
+        assert_eq!(
+            summary,
+            [("mpv", 240.0, 300.0, true), ("firefox", 20.0, 120.0, false)]
+        );
+    }
+
+    #[test]
+    fn test_covers_monitor() {
+        let monitors = [Monitor {
+            name: "DP-1".to_string(),
+            x: 1920,
+            y: 0,
+            width: 1920,
+            height: 1080,
+            primary: false,
+        }];
+        let rect = |x, width, height| Rect {
+            x,
+            y: 0,
+            width,
+            height,
+        };
+        assert!(covers_monitor(&rect(1920, 1920, 1080), &monitors));
+        assert!(!covers_monitor(&rect(1920, 1920, 1040), &monitors));
+        assert!(!covers_monitor(&rect(0, 1920, 1080), &monitors));
+    }
+}
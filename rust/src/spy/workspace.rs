@@ -0,0 +1,250 @@
+//! 虚拟桌面（工作区）
+//!
+//! 每次采样记录当前工作区：X11 读取 `_NET_CURRENT_DESKTOP` 和 `_NET_DESKTOP_NAMES`，
+//! Wayland 下通过 sway / Hyprland 的 IPC 获取。按工作区名称匹配规则得到分类和项目，
+//! 例如 "mail" 工作区归为 work，"proj-spy" 工作区归属到 spy 项目。
+
+use std::collections::HashMap;
+use std::sync::Mutex;
+
+use once_cell::sync::Lazy;
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use crate::spy::catalog::AppCategory;
+use crate::spy::session::Session;
+
+/// 当前虚拟桌面
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Workspace {
+    pub index: u32,   // 桌面序号，X11 从 0 开始，sway / Hyprland 为工作区编号
+    pub name: String, // 桌面名称，未命名时为序号加 1
+    pub category: Option<AppCategory>, // 规则匹配到的分类
+    pub project: Option<String>, // 规则匹配到的项目
+}
+
+/// 工作区规则：名称匹配正则时使用对应的分类和项目
+#[derive(Debug, Clone)]
+pub struct WorkspaceRule {
+    pub pattern: String, // 正则，匹配工作区名称（不区分大小写）
+    pub category: Option<AppCategory>,
+    pub project: Option<String>, // 项目名，可使用 $1 等引用捕获组
+}
+
+/// 按工作区统计的前台时间
+#[derive(Debug, Clone)]
+pub struct WorkspaceTime {
+    pub name: String,
+    pub project: Option<String>,
+    pub seconds: f64,
+}
+
+/// 按分类统计的前台时间
+#[derive(Debug, Clone)]
+pub struct CategoryTime {
+    pub category: AppCategory,
+    pub seconds: f64,
+}
+
+struct CompiledRule {
+    regex: Regex,
+    category: Option<AppCategory>,
+    project: Option<String>,
+}
+
+static RULES: Lazy<Mutex<Vec<CompiledRule>>> = Lazy::new(|| Mutex::new(Vec::new()));
+
+/// 设置工作区规则，靠前的优先；任一正则无效时保留原有规则
+pub fn set_rules(rules: Vec<WorkspaceRule>) -> anyhow::Result<()> {
+    let compiled = rules
+        .into_iter()
+        .map(|r| {
+            Ok(CompiledRule {
+                regex: Regex::new(&format!("(?i){}", r.pattern))?,
+                category: r.category,
+                project: r.project,
+            })
+        })
+        .collect::<anyhow::Result<Vec<_>>>()?;
+    *RULES.lock().unwrap() = compiled;
+    Ok(())
+}
+
+/// 按规则为工作区名称确定 (分类, 项目)
+fn classify(rules: &[CompiledRule], name: &str) -> (Option<AppCategory>, Option<String>) {
+    let Some((rule, captures)) = rules
+        .iter()
+        .find_map(|r| Some((r, r.regex.captures(name)?)))
+    else {
+        return (None, None);
+    };
+    let project = rule.project.as_ref().map(|p| {
+        let mut project = String::new();
+        captures.expand(p, &mut project);
+        project
+    });
+    (rule.category, project.filter(|p| !p.is_empty()))
+}
+
+/// 由序号和桌面名称创建工作区，未命名的桌面以序号加 1 为名
+pub fn workspace(index: u32, name: Option<&str>) -> Workspace {
+    let name = name
+        .filter(|n| !n.is_empty())
+        .map(|n| n.to_string())
+        .unwrap_or_else(|| (index + 1).to_string());
+    let (category, project) = classify(&RULES.lock().unwrap(), &name);
+    Workspace {
+        index,
+        name,
+        category,
+        project,
+    }
+}
+
+/// 统计 [start, end]（unix 毫秒）内各工作区的前台时间
+pub fn workspace_time(sessions: &[Session], start: i64, end: i64) -> Vec<WorkspaceTime> {
+    let mut totals: HashMap<String, (Option<String>, f64)> = HashMap::new();
+    for s in sessions {
+        let Some(name) = &s.workspace else {
+            continue;
+        };
+        let ms = s.end.min(end) - s.start.max(start);
+        if ms > 0 {
+            let entry = totals.entry(name.clone()).or_default();
+            if entry.0.is_none() {
+                entry.0 = s.project.clone();
+            }
+            entry.1 += ms as f64 / 1000.0;
+        }
+    }
+
+    let mut result: Vec<WorkspaceTime> = totals
+        .into_iter()
+        .map(|(name, (project, seconds))| WorkspaceTime {
+            name,
+            project,
+            seconds,
+        })
+        .collect();
+    result.sort_by(|a, b| b.seconds.total_cmp(&a.seconds));
+    result
+}
+
+pub fn get_workspace_time(start: i64, end: i64) -> Vec<WorkspaceTime> {
+    let sessions = crate::spy::session::sessions_between(start, end);
+    workspace_time(&sessions, start, end)
+}
+
+/// 统计 [start, end]（unix 毫秒）内各分类的前台时间：工作区规则给出分类时按工作区分类，
+/// 否则按界面为应用设置的分类（按应用名称），都没有时为 Unknown
+pub fn category_time(
+    sessions: &[Session],
+    start: i64,
+    end: i64,
+    app_categories: &HashMap<String, AppCategory>,
+) -> Vec<CategoryTime> {
+    let mut result: Vec<CategoryTime> = Vec::new();
+    for s in sessions {
+        let ms = s.end.min(end) - s.start.max(start);
+        if ms <= 0 {
+            continue;
+        }
+        let category = s
+            .category
+            .or_else(|| app_categories.get(&s.name).copied())
+            .unwrap_or(AppCategory::Unknown);
+        match result.iter_mut().find(|c| c.category == category) {
+            Some(total) => total.seconds += ms as f64 / 1000.0,
+            None => result.push(CategoryTime {
+                category,
+                seconds: ms as f64 / 1000.0,
+            }),
+        }
+    }
+    result.sort_by(|a, b| b.seconds.total_cmp(&a.seconds));
+    result
+}
+
+pub fn get_category_time(
+    start: i64,
+    end: i64,
+    app_categories: HashMap<String, AppCategory>,
+) -> Vec<CategoryTime> {
+    let sessions = crate::spy::session::sessions_between(start, end);
+    category_time(&sessions, start, end, &app_categories)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn session(workspace: &str, project: Option<&str>, start: i64, end: i64) -> Session {
+        Session {
+            name: "firefox".to_string(),
+            workspace: Some(workspace.to_string()),
+            project: project.map(|p| p.to_string()),
+            start,
+            end,
+            ..Default::default()
+        }
+    }
+
+    fn rule(pattern: &str, category: Option<AppCategory>, project: Option<&str>) -> CompiledRule {
+        CompiledRule {
+            regex: Regex::new(&format!("(?i){}", pattern)).unwrap(),
+            category,
+            project: project.map(|p| p.to_string()),
+        }
+    }
+
+    #[test]
+    fn test_classify() {
+        let rules = [
+            rule("^mail$", Some(AppCategory::Work), None),
+            rule(r"^proj-(\w+)$", Some(AppCategory::Work), Some("$1")),
+            rule("game", Some(AppCategory::Joy), None),
+        ];
+        assert_eq!(classify(&rules, "Mail"), (Some(AppCategory::Work), None));
+        assert_eq!(
+            classify(&rules, "proj-spy"),
+            (Some(AppCategory::Work), Some("spy".to_string()))
+        );
+        assert_eq!(classify(&rules, "games"), (Some(AppCategory::Joy), None));
+        assert_eq!(classify(&rules, "3"), (None, None));
+    }
+
+    #[test]
+    fn test_workspace_time() {
+        let sessions = [
+            session("mail", None, 0, 60_000),
+            session("proj-spy", Some("spy"), 60_000, 240_000),
+            session("mail", None, 240_000, 300_000),
+        ];
+        let result = workspace_time(&sessions, 0, 270_000);
+        let summary: Vec<(&str, Option<&str>, f64)> = result
+            .iter()
+            .map(|w| (w.name.as_str(), w.project.as_deref(), w.seconds))
+            .collect();
+        assert_eq!(
+            summary,
+            [("proj-spy", Some("spy"), 180.0), ("mail", None, 90.0)]
+        );
+
+        // 工作区规则的分类优先于应用分类：mail 工作区里的 firefox 算 work
+        let sessions = [
+            Session {
+                category: Some(AppCategory::Work),
+                ..session("mail", None, 0, 60_000)
+            },
+            session("3", None, 60_000, 180_000),
+        ];
+        let apps = HashMap::from([("firefox".to_string(), AppCategory::Joy)]);
+        let result = category_time(&sessions, 0, 180_000, &apps);
+        let summary: Vec<(AppCategory, f64)> =
+            result.iter().map(|c| (c.category, c.seconds)).collect();
+        assert_eq!(
+            summary,
+            [(AppCategory::Joy, 120.0), (AppCategory::Work, 60.0)]
+        );
+    }
+}